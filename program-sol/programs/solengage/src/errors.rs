@@ -1,13 +1,13 @@
 //! # Error Codes
 //!
 //! All custom error codes for the Solengage program.
-//! Error codes range from 6000-6018 (Anchor custom error space).
+//! Error codes range from 6000-6108 (Anchor custom error space).
 //!
 //! ## Error Categories
 //!
-//! - **Validation Errors** (6001-6007): Input validation failures
-//! - **Authorization Errors** (6000, 6011): Access control violations
-//! - **State Errors** (6008-6010, 6018): Invalid state transitions
+//! - **Validation Errors** (6001-6007, 6019, 6025, 6027, 6029, 6031-6035, 6040-6041, 6045, 6048-6050, 6052-6054, 6056-6057, 6063, 6065, 6067-6068, 6080, 6084, 6088-6093, 6101, 6106-6108): Input validation failures
+//! - **Authorization Errors** (6000, 6011, 6020-6023, 6026, 6028, 6030, 6038-6039, 6042, 6062, 6066, 6071, 6076, 6079, 6103): Access control violations
+//! - **State Errors** (6008-6010, 6018, 6024, 6036-6037, 6043-6044, 6046-6047, 6051, 6055, 6058-6061, 6064, 6069-6070, 6072-6075, 6077-6078, 6081-6083, 6085-6087, 6094-6100, 6102, 6104-6105): Invalid state transitions
 //! - **Payment Errors** (6013-6017): Payment processing failures
 
 use anchor_lang::prelude::*;
@@ -113,4 +113,445 @@ pub enum ErrorCode {
     /// Campaign must be in Completed status to close.
     #[msg("Campaign must be in Completed or Cancelled status.")]
     CampaignNotInTerminalState, // 6018
+
+    // ============= Payout Curve Errors (6019-6020) =============
+
+    /// Piecewise payout curve is empty, too long, not monotonic,
+    /// or does not end at `(10_000, 10_000)`.
+    #[msg("Invalid payout curve.")]
+    InvalidPayoutCurve, // 6019
+
+    /// Caller is not the influencer of this campaign.
+    #[msg("You are not the influencer of this campaign.")]
+    UnauthorizedInfluencer, // 6020
+
+    // ============= Oracle Registry Errors (6021-6023) =============
+
+    /// Caller is not the oracle registry administrator.
+    ///
+    /// # Security
+    /// Only the admin can register, rotate or deactivate oracles.
+    #[msg("You are not the oracle registry admin.")]
+    UnauthorizedAdmin, // 6021
+
+    /// Oracle entry is deactivated in the registry.
+    #[msg("Oracle is not registered or has been deactivated.")]
+    OracleNotRegistered, // 6022
+
+    /// Oracle authority is the influencer or the brand of the campaign.
+    ///
+    /// # Security
+    /// Prevents a party from reporting its own campaign's metrics.
+    #[msg("Oracle cannot be a party of the campaign.")]
+    OracleConflictOfInterest, // 6023
+
+    // ============= Oracle Quorum Errors (6024-6028) =============
+
+    /// Campaign requires M-of-N reports through `submit_metric_report`.
+    #[msg("Campaign requires oracle quorum reports.")]
+    QuorumRequired, // 6024
+
+    /// Quorum has no oracles, too many oracles, duplicates,
+    /// or a threshold of zero or above the number of oracles.
+    #[msg("Invalid oracle quorum configuration.")]
+    InvalidQuorum, // 6025
+
+    /// Oracle entry is not part of the campaign's quorum.
+    ///
+    /// # Security
+    /// Only the oracles reviewed by the brand can contribute to the aggregate.
+    #[msg("Oracle is not part of this campaign's quorum.")]
+    OracleNotInQuorum, // 6026
+
+    /// Token account passed for payouts does not belong to the expected owner.
+    #[msg("Token account does not match the campaign.")]
+    InvalidTokenAccount, // 6027
+
+    /// Oracle account passed is not a registry entry owned by this program.
+    #[msg("Account is not a registered oracle entry.")]
+    InvalidOracleAccount, // 6028
+
+    // ============= Signed Report Errors (6029-6033) =============
+
+    /// The instruction before `submit_signed_metrics` is not a single-signature
+    /// Ed25519 program instruction with inline data.
+    #[msg("Missing or malformed Ed25519 signature instruction.")]
+    InvalidSignatureInstruction, // 6029
+
+    /// Ed25519 instruction was signed by another key or over another message.
+    ///
+    /// # Security
+    /// Only reports signed by the oracle's current key are accepted.
+    #[msg("Report is not signed by the campaign oracle.")]
+    InvalidReportSignature, // 6030
+
+    /// Signed report targets a different campaign.
+    #[msg("Report does not belong to this campaign.")]
+    ReportCampaignMismatch, // 6031
+
+    /// Signed report nonce is not greater than the last applied nonce.
+    ///
+    /// # Security
+    /// Prevents replaying previously relayed reports.
+    #[msg("Report nonce has already been used.")]
+    ReportNonceReused, // 6032
+
+    /// Observation timestamp is in the future.
+    #[msg("Invalid report observation time.")]
+    InvalidObservationTime, // 6033
+
+    // ============= Metric Guard Errors (6034-6038) =============
+
+    /// A reported counter is lower than its current value.
+    ///
+    /// # Security
+    /// Prevents buggy or malicious reports from moving metrics backwards.
+    #[msg("Metrics cannot decrease.")]
+    MetricsDecreased, // 6034
+
+    /// Growth thresholds are not valid percentages (0-100).
+    #[msg("Invalid metric guard configuration.")]
+    InvalidMetricGuards, // 6035
+
+    /// A previous report tripped the circuit breaker and awaits review.
+    #[msg("A report is held pending brand or admin review.")]
+    ReportHeldForReview, // 6036
+
+    /// There is no held report to resolve.
+    #[msg("No held report to resolve.")]
+    NoHeldReport, // 6037
+
+    /// Caller is neither the brand nor the oracle registry admin.
+    #[msg("Only the brand or the registry admin can resolve held reports.")]
+    UnauthorizedReviewer, // 6038
+
+    // ============= Oracle Bond Errors (6039-6044) =============
+
+    /// Challenge evidence is not signed by the registry arbiter.
+    #[msg("Evidence is not signed by the arbiter.")]
+    InvalidArbiterSignature, // 6039
+
+    /// Evidence was issued for another campaign, oracle, or challenger.
+    #[msg("Evidence does not match this challenge.")]
+    ChallengeMismatch, // 6040
+
+    /// Evidence does not contradict the oracle report.
+    #[msg("Evidence does not contradict the reported metrics.")]
+    ChallengeNotContradicting, // 6041
+
+    /// Challenger is neither the brand nor the influencer of the campaign.
+    #[msg("Only the brand or the influencer can challenge the oracle.")]
+    UnauthorizedChallenger, // 6042
+
+    /// Bond cannot be withdrawn while the oracle can still report.
+    #[msg("Oracle must be deactivated before withdrawing its bond.")]
+    OracleStillActive, // 6043
+
+    /// Withdrawal exceeds the bond balance.
+    #[msg("Insufficient oracle bond balance.")]
+    InsufficientBond, // 6044
+
+    // ============= Oracle Fee Errors (6045-6047) =============
+
+    /// Fee cap is lower than the per-update fee, or only one of them is zero.
+    #[msg("Invalid oracle fee configuration.")]
+    InvalidOracleFee, // 6045
+
+    /// An oracle fee is due but no oracle USDC account was provided.
+    #[msg("Oracle USDC account is required to pay the oracle fee.")]
+    OracleFeeAccountRequired, // 6046
+
+    /// Campaign still holds an unused oracle fee reserve.
+    #[msg("Oracle fee reserve must be refunded before closing.")]
+    OracleFeeReserveNotRefunded, // 6047
+
+    // ============= Report Sequencing Errors (6048-6049) =============
+
+    /// Report sequence is lower than the last accepted sequence.
+    #[msg("Report sequence is out of order.")]
+    ReportOutOfOrder, // 6048
+
+    /// Report observation is older than the last applied observation.
+    #[msg("Report observation is older than the last applied observation.")]
+    StaleReport, // 6049
+
+    // ============= Campaign Post Errors (6050-6052) =============
+
+    /// Campaign already tracks `MAX_CAMPAIGN_POSTS` posts.
+    #[msg("Campaign already tracks the maximum number of posts.")]
+    TooManyPosts, // 6050
+
+    /// Campaign has posts, so metrics must be reported per post.
+    #[msg("Campaign has posts: report metrics per post.")]
+    PostMetricsRequired, // 6051
+
+    /// Post account is missing or does not match the held report.
+    #[msg("Post does not match the held report.")]
+    HeldPostMismatch, // 6052
+
+    // ============= Metric Target Errors (6053-6054) =============
+
+    /// Too many, zero, duplicated or unsupported metric targets.
+    #[msg("Invalid metric targets.")]
+    InvalidMetricTargets, // 6053
+
+    /// Reported value has no matching metric entry.
+    #[msg("No metric target for the reported value.")]
+    MetricTargetNotFound, // 6054
+
+    // ============= Metric History Errors (6055-6056) =============
+
+    /// Campaign records its reports, but no history account was provided.
+    #[msg("Campaign records metric history: history account required.")]
+    MetricHistoryRequired, // 6055
+
+    /// History account does not belong to this campaign.
+    #[msg("Metric history does not belong to this campaign.")]
+    InvalidMetricHistory, // 6056
+
+    // ============= Optimistic Report Errors (6057-6062) =============
+
+    /// Challenge window is negative or longer than `MAX_CHALLENGE_WINDOW`.
+    #[msg("Challenge window must be between 0 and 7 days.")]
+    InvalidChallengeWindow, // 6057
+
+    /// A previous report is still waiting out its challenge window.
+    #[msg("A report is pending its challenge window.")]
+    ReportPendingChallenge, // 6058
+
+    /// There is no report in its challenge window.
+    #[msg("No report is pending its challenge window.")]
+    NoPendingReport, // 6059
+
+    /// The pending report can no longer be challenged.
+    #[msg("The challenge window for this report has closed.")]
+    ChallengeWindowClosed, // 6060
+
+    /// The pending report cannot be finalized before its challenge window ends.
+    #[msg("The challenge window for this report is still open.")]
+    ChallengeWindowOpen, // 6061
+
+    /// Caller is not the registry arbiter.
+    #[msg("Only the registry arbiter can resolve challenged reports.")]
+    UnauthorizedArbiter, // 6062
+
+    // ============= Metric Feed Errors (6063-6067) =============
+
+    /// Feed max age is zero or its metric is not supported on the platform.
+    #[msg("Invalid metric feed configuration.")]
+    InvalidMetricFeed, // 6063
+
+    /// Campaign has no metric feed configured.
+    #[msg("Campaign has no metric feed.")]
+    MetricFeedNotSet, // 6064
+
+    /// Feed account is not the configured feed or does not follow the feed layout.
+    #[msg("Feed account does not match the campaign feed.")]
+    InvalidFeedAccount, // 6065
+
+    /// Feed account names a different authority than the configured one.
+    #[msg("Feed is not written by the configured feed authority.")]
+    UnauthorizedFeedAuthority, // 6066
+
+    /// Feed timestamp is older than the configured max age.
+    #[msg("Feed value is too old.")]
+    StaleFeed, // 6067
+
+    // ============= Oracle Liveness Errors (6068-6073) =============
+
+    /// Heartbeat or cancellation notice is negative or longer than `MAX_LIVENESS_PERIOD`.
+    #[msg("Heartbeat and cancellation notice must be between 0 and 30 days.")]
+    InvalidLivenessConfig, // 6068
+
+    /// Oracle has not missed its heartbeat and no cancellation notice was given.
+    #[msg("Oracle is live: fallback not available.")]
+    OracleStillLive, // 6069
+
+    /// Fallback metrics require the influencer to open the fallback first.
+    #[msg("Oracle fallback is not open.")]
+    FallbackNotOpen, // 6070
+
+    /// Fallback metrics are not co-signed by the brand or the registry arbiter.
+    #[msg("Fallback metrics must be co-signed by the brand or the arbiter.")]
+    UnauthorizedCosigner, // 6071
+
+    /// Active campaign cancelled before its notice period elapsed (or without notice).
+    #[msg("Cancellation notice period has not elapsed.")]
+    CancelNoticePending, // 6072
+
+    /// Brand already gave cancellation notice.
+    #[msg("Cancellation already requested.")]
+    CancelAlreadyRequested, // 6073
+
+    // ============= Offer Errors (6074) =============
+
+    /// Offer instructions require the campaign to be in Offered status.
+    #[msg("Campaign must be in Offered status.")]
+    CampaignNotOffered, // 6074
+
+    // ============= Terms Errors (6075-6079) =============
+
+    /// Campaign terms changed after the version the brand reviewed.
+    #[msg("Campaign terms changed since the reviewed version.")]
+    TermsVersionMismatch, // 6075
+
+    /// Signer is neither the influencer nor the brand of the campaign.
+    #[msg("Only the influencer or the brand of this campaign can do this.")]
+    UnauthorizedParty, // 6076
+
+    /// No terms proposal is pending on the campaign.
+    #[msg("No pending terms proposal.")]
+    NoPendingTerms, // 6077
+
+    /// Campaign cannot be funded while a terms proposal awaits acceptance.
+    #[msg("A terms proposal is pending acceptance.")]
+    TermsProposalPending, // 6078
+
+    /// The proposer tried to accept its own proposal.
+    #[msg("Terms must be accepted by the other party.")]
+    CannotAcceptOwnProposal, // 6079
+
+    // ============= Pause Errors (6080-6083) =============
+
+    /// Maximum pause duration is negative or longer than `MAX_PAUSE_DURATION`.
+    #[msg("Maximum pause duration must be between 0 and 90 days.")]
+    InvalidPauseDuration, // 6080
+
+    /// Campaign does not allow pausing or its pause budget is used up.
+    #[msg("Campaign has no pause time left.")]
+    PauseNotAllowed, // 6081
+
+    /// Resume requires the campaign to be in Paused status.
+    #[msg("Campaign is not paused.")]
+    CampaignNotPaused, // 6082
+
+    /// The influencer can only resume once the pause budget is used up.
+    #[msg("Pause budget has not been used up yet.")]
+    PauseNotExpired, // 6083
+
+    // ============= Amendment Errors (6084-6085) =============
+
+    /// Amendment changes neither the deadline, the targets nor the budget.
+    #[msg("Amendment must change the deadline, the targets or the budget.")]
+    EmptyAmendment, // 6084
+
+    /// Amended targets would bring progress below milestones already paid.
+    #[msg("Amended targets cannot undo milestones already paid.")]
+    AmendmentUndoesMilestones, // 6085
+
+    // ============= Dispute Errors (6086-6088) =============
+
+    /// Dispute resolution requires the campaign to be in Disputed status.
+    #[msg("Campaign is not disputed.")]
+    CampaignNotDisputed, // 6086
+
+    /// Disputed campaigns cannot be cancelled until the arbiter resolves them.
+    #[msg("Campaign is under dispute.")]
+    CampaignDisputed, // 6087
+
+    /// Influencer share of the dispute split is above 100%.
+    #[msg("Dispute split must be between 0 and 10000 basis points.")]
+    InvalidDisputeSplit, // 6088
+
+    // ============= Kill Fee Errors (6089-6090) =============
+
+    /// Kill fee above 100% or decreasing over time.
+    #[msg("Kill fee must be at most 10000 basis points and must not decrease.")]
+    InvalidKillFee, // 6089
+
+    /// Cancelling with a kill fee requires the influencer's USDC account.
+    #[msg("Influencer token account is required to pay the kill fee.")]
+    KillFeeAccountRequired, // 6090
+
+    // ============= Settlement Errors (6091) =============
+
+    /// Agreed influencer amount exceeds the unpaid budget.
+    #[msg("Settlement amount exceeds the unpaid campaign budget.")]
+    InvalidSettlementAmount, // 6091
+
+    // ============= Content Review Errors (6092-6098) =============
+
+    /// Content review window is negative or above `MAX_CONTENT_REVIEW_WINDOW`.
+    #[msg("Content review window must be between 0 and 14 days.")]
+    InvalidContentReviewWindow, // 6092
+
+    /// Post identifier is empty or longer than `MAX_POST_ID_LEN`.
+    #[msg("Post id must be between 1 and 64 characters.")]
+    InvalidPostId, // 6093
+
+    /// Content submission requires a content review window agreed in Draft.
+    #[msg("Campaign has no content review stage.")]
+    ContentReviewNotEnabled, // 6094
+
+    /// Approved content cannot be replaced.
+    #[msg("Content is already approved.")]
+    ContentAlreadyApproved, // 6095
+
+    /// There is no submitted content awaiting review.
+    #[msg("No content is awaiting review.")]
+    NoContentToReview, // 6096
+
+    /// The review window elapsed and the content counts as approved.
+    #[msg("The content review window has closed.")]
+    ContentReviewWindowClosed, // 6097
+
+    /// Metric reports require approved content.
+    #[msg("Campaign content has not been approved yet.")]
+    ContentNotApproved, // 6098
+
+    // ============= Oracle Unbonding Errors (6099) =============
+
+    /// The bond stays locked for `ORACLE_UNBONDING_PERIOD` after deactivation.
+    #[msg("Oracle bond is still unbonding after deactivation.")]
+    OracleBondUnbonding, // 6099
+
+    // ============= Campaign Post Errors (6100) =============
+
+    /// Posts added after reports would be summed on top of counters already reported.
+    #[msg("Posts can only be added before any metrics are reported.")]
+    MetricsAlreadyReported, // 6100
+
+    // ============= Withdrawal Errors (6101) =============
+
+    /// Forfeited share of the pending earnings is above 100%.
+    #[msg("Forfeit must be between 0 and 10000 basis points.")]
+    InvalidForfeitBps, // 6101
+
+    // ============= Campaign Closing Errors (6102) =============
+
+    /// Closing a campaign also closes its posts and oracle quorum, passed as remaining accounts.
+    #[msg("All campaign posts and the oracle quorum must be passed to close the campaign.")]
+    CampaignChildAccountsRequired, // 6102
+
+    // ============= Metric Feed Errors (6103) =============
+
+    /// Feed data is only trusted when written by the configured feed program.
+    #[msg("Feed account is not owned by the configured feed program.")]
+    FeedOwnerMismatch, // 6103
+
+    // ============= Oracle Bond Requirement Errors (6104-6106) =============
+
+    /// Completed campaigns stay open for `ORACLE_CHALLENGE_PERIOD`.
+    #[msg("Completed campaign is still within its oracle challenge period.")]
+    ChallengePeriodActive, // 6104
+
+    /// Campaigns are only funded while their oracle holds `MIN_ORACLE_BOND`.
+    #[msg("Oracle bond is below the minimum required to fund a campaign.")]
+    OracleBondTooLow, // 6105
+
+    /// Slashing must compensate in the campaign's own token.
+    #[msg("Oracle bond is not denominated in the campaign's USDC mint.")]
+    OracleBondMintMismatch, // 6106
+
+    // ============= Campaign Post Closing Errors (6107) =============
+
+    /// The campaign account passed is not the one the post belongs to.
+    #[msg("Post does not belong to this campaign.")]
+    InvalidCampaignPost, // 6107
+
+    // ============= Amendment Errors (6108) =============
+
+    /// A top-up is transferred from the brand's USDC account, which must then be passed.
+    #[msg("Brand token account is required to top up the budget.")]
+    TopUpAccountRequired, // 6108
 }
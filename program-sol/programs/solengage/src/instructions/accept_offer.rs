@@ -0,0 +1,60 @@
+//! # Aceitar Oferta
+//!
+//! Este módulo define a instrução para o influenciador aceitar uma oferta da marca.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{Campaign, CampaignStatus};
+
+/// Aceita uma campanha em `Offered`.
+///
+/// Somente o influenciador pode chamar. Se a marca depositou o orçamento na oferta, a
+/// campanha passa direto para `Active`; caso contrário, passa para `Draft` e aguarda o
+/// financiamento da marca via `brand_pay_campaign`.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `AcceptOffer`.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotOffered` - Campanha não está em `Offered`.
+/// * `CampaignExpired` - Prazo da campanha expirou.
+/// * `UnauthorizedInfluencer` - Assinante não é o influenciador da campanha.
+pub fn accept_offer(ctx: Context<AcceptOffer>) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Validações de segurança
+    require_eq!(campaign.status, CampaignStatus::Offered, ErrorCode::CampaignNotOffered);
+    require!(now < campaign.deadline, ErrorCode::CampaignExpired);
+
+    campaign.status = if campaign.offer_escrowed { CampaignStatus::Active } else { CampaignStatus::Draft };
+    if campaign.status == CampaignStatus::Active {
+        campaign.activated_at = now;
+        campaign.last_oracle_report_at = now;
+    }
+    campaign.last_updated = now;
+
+    msg!("Oferta aceita pelo influenciador: campanha em {}", campaign.status);
+    Ok(())
+}
+
+/// Contas para a instrução `accept_offer`.
+#[derive(Accounts)]
+pub struct AcceptOffer<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, possuir o influenciador correto e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = influencer @ ErrorCode::UnauthorizedInfluencer,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Conta do influenciador (assinante).
+    pub influencer: Signer<'info>,
+}
@@ -0,0 +1,73 @@
+//! # Aceitar Termos
+//!
+//! Este módulo define a instrução para a contraparte aceitar a proposta de termos pendente.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{set_base_targets, Campaign, CampaignStatus};
+
+/// Aceita a proposta de termos pendente e a aplica à campanha.
+///
+/// Somente a parte que não fez a proposta pode aceitá-la, informando a versão que revisou,
+/// para que uma contraproposta enviada no meio tempo não seja aceita por engano. As metas
+/// propostas substituem as metas das entradas-base, como em `update_draft`; metas extras,
+/// pesos definidos via `set_metric_targets` e o feed de métricas são mantidos. Depois do
+/// aceite, a marca financia a campanha com essa mesma versão em `brand_pay_campaign`.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `AcceptTerms`.
+/// * `version` - Versão da proposta revisada.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotDraft` - Campanha não está em `Draft`.
+/// * `UnauthorizedParty` - Assinante não é o influenciador nem a marca da campanha.
+/// * `NoPendingTerms` - Nenhuma proposta pendente.
+/// * `CannotAcceptOwnProposal` - Assinante é o autor da proposta.
+/// * `TermsVersionMismatch` - `version` não corresponde à proposta pendente.
+/// * `InvalidDeadline` - Prazo proposto já passou.
+/// * `MetricTargetNotFound` - Métrica do feed configurado sem entrada correspondente.
+pub fn accept_terms(ctx: Context<AcceptTerms>, version: u32) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Validações de segurança
+    require_eq!(campaign.status, CampaignStatus::Draft, ErrorCode::CampaignNotDraft);
+    let proposal = campaign.pending_terms.ok_or(ErrorCode::NoPendingTerms)?;
+    require_keys_neq!(proposal.proposer, ctx.accounts.acceptor.key(), ErrorCode::CannotAcceptOwnProposal);
+    require_eq!(proposal.version, version, ErrorCode::TermsVersionMismatch);
+    require!(proposal.deadline > now, ErrorCode::InvalidDeadline);
+
+    let targets = proposal.targets;
+    set_base_targets(&mut campaign.metrics, [targets.likes, targets.comments, targets.views, targets.shares]);
+    campaign.validate_metric_feed()?;
+    campaign.amount_usdc = proposal.amount_usdc;
+    campaign.deadline = proposal.deadline;
+    campaign.pending_terms = None;
+    campaign.last_updated = now;
+
+    msg!("Termos aceitos: versão {}", campaign.terms_version);
+    Ok(())
+}
+
+/// Contas para a instrução `accept_terms`.
+#[derive(Accounts)]
+pub struct AcceptTerms<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Influenciador ou marca da campanha, contraparte da proposta (assinante).
+    #[account(
+        constraint = acceptor.key() == campaign.influencer || acceptor.key() == campaign.brand @ ErrorCode::UnauthorizedParty
+    )]
+    pub acceptor: Signer<'info>,
+}
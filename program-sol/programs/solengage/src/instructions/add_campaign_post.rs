@@ -0,0 +1,92 @@
+//! # Adicionar Post à Campanha
+//!
+//! Este módulo define a instrução para o influenciador registrar um post entregue na campanha.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{Campaign, CampaignPost, CampaignStatus, MetricValues, Platform, MAX_CAMPAIGN_POSTS};
+
+/// Registra um post (Reel, Story, vídeo...) cujas métricas compõem o progresso da campanha.
+///
+/// Somente o influenciador pode chamar, em `Draft` ou `Active`, antes de qualquer relatório
+/// de métricas. O post começa com métricas zeradas; a partir do primeiro post, o oráculo
+/// passa a reportar cada post via `update_post_metrics` e o progresso da campanha é
+/// calculado sobre a soma dos posts (contadores já reportados seriam somados em dobro).
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `AddCampaignPost`.
+/// * `url_hash` - Hash SHA-256 da URL (ou identificador) do post.
+/// * `platform` - Rede social onde o post foi publicado.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotActive` - Campanha não está em `Draft` nem em `Active`.
+/// * `MetricsAlreadyReported` - A campanha já recebeu relatórios de métricas.
+/// * `UnauthorizedInfluencer` - Assinante não é o influenciador da campanha.
+/// * `TooManyPosts` - Campanha já possui `MAX_CAMPAIGN_POSTS` posts.
+pub fn add_campaign_post(ctx: Context<AddCampaignPost>, url_hash: [u8; 32], platform: Platform) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    // Validações de segurança
+    require!(
+        matches!(campaign.status, CampaignStatus::Draft | CampaignStatus::Active),
+        ErrorCode::CampaignNotActive
+    );
+    require!(
+        campaign.report_sequence == 0 && campaign.last_observed_at == 0 && !campaign.has_unapplied_report(),
+        ErrorCode::MetricsAlreadyReported
+    );
+    require!(campaign.post_count < MAX_CAMPAIGN_POSTS, ErrorCode::TooManyPosts);
+
+    let now = Clock::get()?.unix_timestamp;
+    let post = &mut ctx.accounts.campaign_post;
+    post.campaign = campaign.key();
+    post.influencer = ctx.accounts.influencer.key();
+    post.url_hash = url_hash;
+    post.platform = platform;
+    post.index = campaign.post_count;
+    post.metrics = MetricValues::default();
+    post.held_metrics = None;
+    post.created_at = now;
+    post.bump = ctx.bumps.campaign_post;
+
+    campaign.post_count += 1;
+    campaign.last_updated = now;
+
+    Ok(())
+}
+
+/// Contas para a instrução `add_campaign_post`.
+#[derive(Accounts)]
+#[instruction(url_hash: [u8; 32])]
+pub struct AddCampaignPost<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, possuir o influenciador correto e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = influencer @ ErrorCode::UnauthorizedInfluencer,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Conta do post a ser inicializada.
+    ///
+    /// PDA derivada de `["campaign_post", campaign, url_hash]` (o mesmo post não pode ser registrado duas vezes).
+    #[account(
+        init,
+        payer = influencer,
+        space = 8 + CampaignPost::INIT_SPACE,
+        seeds = [b"campaign_post", campaign.key().as_ref(), url_hash.as_ref()],
+        bump
+    )]
+    pub campaign_post: Account<'info, CampaignPost>,
+    /// Conta do influenciador, também pagador da inicialização.
+    #[account(mut)]
+    pub influencer: Signer<'info>,
+    /// Programa do sistema Solana.
+    pub system_program: Program<'info, System>,
+}
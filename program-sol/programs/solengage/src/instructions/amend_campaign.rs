@@ -0,0 +1,173 @@
+//! # Aditivo de Campanha
+//!
+//! Este módulo define a instrução para marca e influenciador alterarem juntos os termos de uma campanha ativa.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::errors::ErrorCode;
+use crate::state::{build_metric_entries, Campaign, CampaignStatus, MetricTarget};
+
+/// Altera os termos de uma campanha `Active` com a assinatura das duas partes.
+///
+/// Cada alteração é opcional:
+/// * `deadline` - Novo prazo, que só pode estender o atual.
+/// * `targets` - Novas metas (como em `set_metric_targets`); os valores já reportados são
+///   mantidos nas entradas com a mesma métrica e plataforma.
+/// * `top_up_amount` - USDC adicional transferido pela marca ao cofre e somado ao orçamento.
+///
+/// Depois da alteração, os marcos já atingidos pelo novo progresso são recalculados: o
+/// influenciador recebe a diferença entre o valor acumulado devido (novas metas e novo
+/// orçamento) e o já pago, sem pagar duas vezes o mesmo marco. As metas não podem reduzir
+/// o progresso abaixo dos marcos já pagos. Se a campanha tiver um feed de métricas, a
+/// métrica do feed precisa continuar entre as metas. Se o progresso chegar a 100%, a
+/// campanha é concluída como em `update_campaign_metrics` (a conta pode ser fechada via
+/// `close_campaign` depois do período de contestação).
+///
+/// A conta USDC da marca só é exigida quando há aporte.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `AmendCampaign`.
+/// * `deadline` - Novo prazo (timestamp Unix), se alterado.
+/// * `targets` - Novas metas, se alteradas.
+/// * `top_up_amount` - USDC adicional ao orçamento (0 = sem aporte).
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotActive` - Campanha não está em `Active`.
+/// * `UnauthorizedBrand` / `UnauthorizedInfluencer` - Assinantes não correspondem às partes.
+/// * `EmptyAmendment` - Nenhuma alteração informada.
+/// * `ReportHeldForReview` / `ReportPendingChallenge` - Há relatório ainda não aplicado.
+/// * `InvalidDeadline` - Novo prazo anterior ao atual.
+/// * `NoTargetsSet` / `InvalidMetricTargets` - Metas inválidas.
+/// * `MetricTargetNotFound` - Métrica do feed configurado removida das metas.
+/// * `TopUpAccountRequired` - Aporte sem a conta USDC da marca.
+/// * `AmendmentUndoesMilestones` - Novas metas reduzem o progresso abaixo dos marcos pagos.
+/// * `MathOverflow` - Overflow aritmético.
+pub fn amend_campaign(
+    ctx: Context<AmendCampaign>,
+    deadline: Option<i64>,
+    targets: Option<Vec<MetricTarget>>,
+    top_up_amount: u64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let campaign = &mut ctx.accounts.campaign;
+
+    // Validações de segurança
+    require_eq!(campaign.status, CampaignStatus::Active, ErrorCode::CampaignNotActive);
+    require!(deadline.is_some() || targets.is_some() || top_up_amount > 0, ErrorCode::EmptyAmendment);
+    require!(!campaign.report_held, ErrorCode::ReportHeldForReview);
+    require!(campaign.report_pending_since == 0, ErrorCode::ReportPendingChallenge);
+
+    if let Some(deadline) = deadline {
+        require!(deadline >= campaign.deadline && deadline > now, ErrorCode::InvalidDeadline);
+        campaign.deadline = deadline;
+    }
+
+    if let Some(targets) = targets {
+        let mut entries = build_metric_entries(&targets)?;
+        for entry in entries.iter_mut() {
+            if let Some(previous) = campaign.metrics.iter().find(|previous| previous.matches(entry.kind, entry.platform)) {
+                entry.current = previous.current;
+            }
+        }
+        campaign.metrics = entries;
+        campaign.validate_metric_feed()?;
+    }
+
+    if top_up_amount > 0 {
+        let brand_usdc_account = ctx.accounts.brand_usdc_account.as_ref().ok_or(ErrorCode::TopUpAccountRequired)?;
+
+        // CPI para transferir o aporte da marca para o cofre da campanha
+        let cpi_accounts = Transfer {
+            from: brand_usdc_account.to_account_info(),
+            to: ctx.accounts.campaign_usdc_account.to_account_info(),
+            authority: ctx.accounts.brand.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+        token::transfer(cpi_ctx, top_up_amount)?;
+        campaign.amount_usdc = campaign.amount_usdc.checked_add(top_up_amount).ok_or(ErrorCode::MathOverflow)?;
+    }
+
+    // Recalcula os marcos atingidos com as novas metas e o novo orçamento
+    let progress = campaign.get_progress_percentage();
+    let milestones_achieved = (progress / 10) as usize;
+    let milestones_paid = campaign.payment_milestones.iter().filter(|paid| **paid).count();
+    require!(milestones_achieved >= milestones_paid, ErrorCode::AmendmentUndoesMilestones);
+
+    if milestones_achieved > 0 {
+        let owed = campaign
+            .cumulative_payout_for_milestone(milestones_achieved - 1)?
+            .saturating_sub(campaign.paid_amount)
+            .min(campaign.amount_usdc.saturating_sub(campaign.paid_amount));
+
+        if owed > 0 {
+            let bump = ctx.bumps.campaign;
+            let seeds = &[
+                b"campaign".as_ref(),
+                campaign.influencer.as_ref(),
+                campaign.brand.as_ref(),
+                campaign.name.as_bytes(),
+                &[bump],
+            ];
+            let signer = &[&seeds[..]];
+
+            // CPI para pagar ao influenciador a diferença devida pelos marcos atingidos
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.campaign_usdc_account.to_account_info(),
+                to: ctx.accounts.influencer_usdc_account.to_account_info(),
+                authority: campaign.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+            token::transfer(cpi_ctx, owed)?;
+            campaign.paid_amount = campaign.paid_amount.checked_add(owed).ok_or(ErrorCode::MathOverflow)?;
+        }
+
+        for paid in campaign.payment_milestones.iter_mut().take(milestones_achieved) {
+            *paid = true;
+        }
+    }
+
+    if progress >= 100 {
+        campaign.complete(now);
+    }
+
+    campaign.bump_terms_version();
+    campaign.last_updated = now;
+
+    msg!("Aditivo aplicado: progresso {}%, total pago {}", progress, campaign.paid_amount);
+    Ok(())
+}
+
+/// Contas para a instrução `amend_campaign`.
+#[derive(Accounts)]
+pub struct AmendCampaign<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, possuir a marca e o influenciador corretos e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = brand @ ErrorCode::UnauthorizedBrand,
+        has_one = influencer @ ErrorCode::UnauthorizedInfluencer,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Conta da marca (assinante).
+    pub brand: Signer<'info>,
+    /// Conta do influenciador (assinante).
+    pub influencer: Signer<'info>,
+    /// Conta de token USDC da marca (fonte do aporte, obrigatória se houver aporte).
+    #[account(mut, constraint = brand_usdc_account.owner == brand.key() @ ErrorCode::InvalidTokenAccount)]
+    pub brand_usdc_account: Option<Account<'info, TokenAccount>>,
+    /// Conta de token USDC do cofre da campanha.
+    #[account(mut, constraint = campaign_usdc_account.owner == campaign.key() @ ErrorCode::InvalidTokenAccount)]
+    pub campaign_usdc_account: Account<'info, TokenAccount>,
+    /// Conta de token USDC do influenciador (destino dos marcos recalculados).
+    #[account(mut, constraint = influencer_usdc_account.owner == influencer.key() @ ErrorCode::InvalidTokenAccount)]
+    pub influencer_usdc_account: Account<'info, TokenAccount>,
+    /// Programa SPL Token.
+    pub token_program: Program<'info, Token>,
+}
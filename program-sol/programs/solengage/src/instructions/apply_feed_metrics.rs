@@ -0,0 +1,113 @@
+//! # Aplicar Métricas do Feed
+//!
+//! Este módulo define a instrução permissionless que aplica o valor do feed externo de métricas da campanha.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::errors::ErrorCode;
+use crate::instructions::update_campaign_metrics::{process_staged_report, ReportAccounts};
+use crate::state::{Campaign, CampaignStatus, MetricHistory, OracleEntry};
+
+/// Lê o feed externo da campanha e aplica seu valor à entrada de métrica configurada.
+///
+/// Qualquer conta pode chamar (crank). O feed deve ser a conta configurada em
+/// `set_metric_feed`, pertencer ao programa configurado, estar escrito pela autoridade esperada e ter timestamp dentro da
+/// idade máxima. O valor passa pelas mesmas proteções e pagamentos por marco de
+/// `update_campaign_metrics` (incluindo circuit breaker e janela de contestação);
+/// nenhuma taxa de oráculo é paga.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `ApplyFeedMetrics`.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotActive` - Campanha não está em `Active`.
+/// * `ContentNotApproved` - Conteúdo da campanha ainda não aprovado pela marca.
+/// * `CampaignExpired` - Prazo da campanha expirou.
+/// * `MetricFeedNotSet` - Campanha não tem feed configurado.
+/// * `PostMetricsRequired` - Feed de contador geral em campanha com posts.
+/// * `MetricHistoryRequired` - Campanha registra histórico e a conta não foi informada.
+/// * `InvalidFeedAccount` - Conta não é o feed configurado ou não segue o layout.
+/// * `FeedOwnerMismatch` - Conta do feed não pertence ao programa configurado.
+/// * `UnauthorizedFeedAuthority` - Feed escrito por outra autoridade.
+/// * `InvalidObservationTime` - Timestamp do feed no futuro.
+/// * `StaleFeed` - Timestamp do feed mais antigo que a idade máxima.
+/// * `StaleReport` - Timestamp do feed não é posterior à última observação aplicada.
+/// * `MetricTargetNotFound` - Campanha não tem entrada para a métrica do feed.
+/// * `MetricsDecreased` - Valor menor que o valor atual da entrada.
+/// * `ReportHeldForReview` - Há um relatório retido aguardando revisão.
+/// * `ReportPendingChallenge` - Há um relatório em janela de contestação.
+/// * `MathOverflow` - Overflow aritmético durante cálculos de pagamento.
+pub fn apply_feed_metrics(ctx: Context<ApplyFeedMetrics>) -> Result<()> {
+    // Valida status da campanha e deadline
+    let now = Clock::get()?.unix_timestamp;
+    let campaign = &mut ctx.accounts.campaign;
+    require_eq!(campaign.status, CampaignStatus::Active, ErrorCode::CampaignNotActive);
+    require!(campaign.content_approved(now), ErrorCode::ContentNotApproved);
+    require!(now < campaign.deadline, ErrorCode::CampaignExpired);
+    let metric_feed = campaign.metric_feed.ok_or(ErrorCode::MetricFeedNotSet)?;
+
+    // Em campanhas com posts, os contadores gerais são a soma dos posts
+    require!(
+        campaign.post_count == 0 || metric_feed.platform.is_some() || metric_feed.kind.base_index().is_none(),
+        ErrorCode::PostMetricsRequired
+    );
+
+    // Valida endereço, autoridade e frescor do feed
+    let (reading, observed_at) = metric_feed.read(&ctx.accounts.feed, now)?;
+    require!(observed_at > campaign.last_observed_at, ErrorCode::StaleReport);
+
+    campaign.stage_readings(&[reading])?;
+
+    process_staged_report(
+        &mut ctx.accounts.campaign,
+        ReportAccounts {
+            campaign_bump: ctx.bumps.campaign,
+            campaign_usdc_account: &ctx.accounts.campaign_usdc_account,
+            influencer_usdc_account: &ctx.accounts.influencer_usdc_account,
+            oracle_usdc_account: None,
+            token_program: &ctx.accounts.token_program,
+            metric_history: ctx.accounts.metric_history.as_mut(),
+        },
+        observed_at,
+        None,
+    )
+}
+
+/// Contas para a instrução `apply_feed_metrics`.
+#[derive(Accounts)]
+pub struct ApplyFeedMetrics<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, referenciar a entrada de oráculo informada e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = oracle @ ErrorCode::UnauthorizedOracle,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Conta do feed externo (validada contra `campaign.metric_feed`).
+    /// CHECK: Endereço, layout e autoridade validados em `MetricFeed::read`.
+    pub feed: UncheckedAccount<'info>,
+    /// Entrada do oráculo referenciada pela campanha.
+    pub oracle: Account<'info, OracleEntry>,
+    /// Chave atual do oráculo.
+    /// CHECK: Validada contra `oracle.authority`.
+    #[account(address = oracle.authority @ ErrorCode::UnauthorizedOracle)]
+    pub oracle_authority: AccountInfo<'info>,
+    /// Histórico de métricas da campanha (obrigatório quando o histórico está habilitado).
+    #[account(mut, has_one = campaign @ ErrorCode::InvalidMetricHistory)]
+    pub metric_history: Option<Account<'info, MetricHistory>>,
+    /// Cofre USDC da campanha (fonte dos pagamentos).
+    #[account(mut, constraint = campaign_usdc_account.owner == campaign.key() @ ErrorCode::InvalidTokenAccount)]
+    pub campaign_usdc_account: Account<'info, TokenAccount>,
+    /// Conta USDC do influenciador (destino dos pagamentos).
+    #[account(mut, constraint = influencer_usdc_account.owner == campaign.influencer @ ErrorCode::InvalidTokenAccount)]
+    pub influencer_usdc_account: Account<'info, TokenAccount>,
+    /// Programa SPL Token.
+    pub token_program: Program<'info, Token>,
+}
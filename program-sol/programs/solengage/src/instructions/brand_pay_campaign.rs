@@ -5,29 +5,46 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::errors::ErrorCode;
-use crate::state::{Campaign, CampaignStatus};
+use crate::state::{Campaign, CampaignStatus, OracleBond};
 
 /// Ativa a campanha transferindo USDC da marca para o cofre da campanha.
 ///
 /// Esta função muda o status de `Draft` para `Active`.
-/// O valor total da campanha deve ser transferido de uma vez (sem financiamento parcial).
+/// O valor total da campanha deve ser transferido de uma vez (sem financiamento parcial),
+/// junto com a reserva de taxas do oráculo (`oracle_fee_cap`), se configurada.
 /// Realiza validações para garantir que a campanha está no estado correto e não expirou.
+/// A marca informa a versão dos termos que revisou; se o influenciador alterou os termos
+/// depois disso (`update_draft` ou qualquer setter de `Draft`), o pagamento é rejeitado.
+/// Enquanto houver uma proposta de termos pendente, a campanha não pode ser financiada.
+/// O oráculo da campanha deve ter uma caução de pelo menos `MIN_ORACLE_BOND` no mesmo
+/// token do cofre da campanha, para que contestações compensem em USDC.
 ///
 /// # Argumentos
 ///
 /// * `ctx` - Contexto da instrução `BrandPayCampaign`.
+/// * `terms_version` - Versão dos termos revisada pela marca (`campaign.terms_version`).
 ///
 /// # Erros
 ///
 /// Retorna `ErrorCode` se ocorrer:
 /// * `CampaignNotDraft` - Campanha não está em `Draft`.
 /// * `CampaignExpired` - Prazo da campanha expirou.
-pub fn brand_pay_campaign(ctx: Context<BrandPayCampaign>) -> Result<()> {
+/// * `TermsProposalPending` - Proposta de termos aguardando aceite.
+/// * `TermsVersionMismatch` - Termos alterados depois da versão revisada pela marca.
+/// * `OracleBondMintMismatch` - Caução do oráculo em outro token.
+/// * `OracleBondTooLow` - Caução do oráculo abaixo de `MIN_ORACLE_BOND`.
+/// * `MathOverflow` - Overflow ao somar orçamento e reserva de taxas.
+pub fn brand_pay_campaign(ctx: Context<BrandPayCampaign>, terms_version: u32) -> Result<()> {
     let campaign = &mut ctx.accounts.campaign;
 
     // Validações de segurança
     require_eq!(campaign.status, CampaignStatus::Draft, ErrorCode::CampaignNotDraft);
     require!(Clock::get()?.unix_timestamp < campaign.deadline, ErrorCode::CampaignExpired);
+    require!(campaign.pending_terms.is_none(), ErrorCode::TermsProposalPending);
+    require_eq!(campaign.terms_version, terms_version, ErrorCode::TermsVersionMismatch);
+    ctx.accounts
+        .oracle_bond
+        .validate_for_campaign(ctx.accounts.bond_vault.amount, ctx.accounts.campaign_usdc_account.mint)?;
 
     // CPI para transferir USDC da marca para o cofre da campanha
     let cpi_accounts = Transfer {
@@ -37,11 +54,16 @@ pub fn brand_pay_campaign(ctx: Context<BrandPayCampaign>) -> Result<()> {
     };
     let cpi_program = ctx.accounts.token_program.to_account_info();
     let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    token::transfer(cpi_ctx, campaign.amount_usdc)?;
+    let total_deposit = campaign.amount_usdc.checked_add(campaign.oracle_fee_cap).ok_or(ErrorCode::MathOverflow)?;
+    token::transfer(cpi_ctx, total_deposit)?;
 
-    // Atualiza status da campanha e timestamp de última atualização
+    // Atualiza status da campanha, reserva de taxas e timestamp de última atualização
+    let now = Clock::get()?.unix_timestamp;
     campaign.status = CampaignStatus::Active;
-    campaign.last_updated = Clock::get()?.unix_timestamp;
+    campaign.oracle_fee_reserve = campaign.oracle_fee_cap;
+    campaign.activated_at = now;
+    campaign.last_oracle_report_at = now;
+    campaign.last_updated = now;
 
     Ok(())
 }
@@ -69,6 +91,12 @@ pub struct BrandPayCampaign<'info> {
     /// Conta de token USDC do cofre da campanha (destino da transferência).
     #[account(mut)]
     pub campaign_usdc_account: Account<'info, TokenAccount>,
+    /// Caução do oráculo referenciado por `campaign.oracle`.
+    #[account(seeds = [b"oracle_bond", campaign.oracle.as_ref()], bump = oracle_bond.bump)]
+    pub oracle_bond: Account<'info, OracleBond>,
+    /// Cofre de tokens da caução do oráculo.
+    #[account(seeds = [b"oracle_bond_vault", campaign.oracle.as_ref()], bump = oracle_bond.vault_bump)]
+    pub bond_vault: Account<'info, TokenAccount>,
     /// Programa SPL Token.
     pub token_program: Program<'info, Token>,
 }
@@ -5,14 +5,25 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::errors::ErrorCode;
-use crate::state::{Campaign, CampaignStatus};
+use crate::state::{Campaign, CampaignStatus, BPS_DENOMINATOR};
 
 /// Cancela uma campanha ativa ou pendente e reembolsa qualquer USDC restante à marca.
 ///
 /// Somente a marca que criou a campanha pode chamar esta função.
-/// Se a campanha estiver `Active`, o saldo restante no cofre da campanha
-/// (valor total menos o já pago) é transferido de volta para a conta USDC da marca.
+/// Se a campanha estiver `Active` (ou `Paused`), o saldo restante no cofre da campanha
+/// (valor total menos o já pago, mais a reserva de taxas do oráculo não usada)
+/// é transferido de volta para a conta USDC da marca. Uma oferta (`Offered`) com
+/// orçamento depositado também tem o depósito devolvido.
+/// Se uma kill fee foi acordada (`set_kill_fee`), ao cancelar uma campanha `Active` ou
+/// `Paused` a parcela correspondente do orçamento não pago vai para o influenciador
+/// (a conta USDC do influenciador deve ser informada) e apenas o restante é reembolsado.
+/// A partir do prazo final a kill fee é zero: a campanha expirou sem ser concluída.
 /// Em seguida, o status da campanha é definido como `Cancelled`.
+/// Se a campanha tiver aviso prévio de cancelamento, uma campanha `Active` só pode ser
+/// cancelada depois de `request_cancellation` e do fim do prazo de aviso, durante o qual
+/// o influenciador pode liquidar os marcos já conquistados.
+/// Um relatório retido ou pendente precisa ser resolvido ou finalizado antes do
+/// cancelamento, para que os marcos que ele conquistou sejam pagos.
 ///
 /// # Argumentos
 ///
@@ -22,28 +33,83 @@ use crate::state::{Campaign, CampaignStatus};
 ///
 /// Retorna um `ErrorCode` se ocorrer uma das condições:
 /// * `CampaignAlreadyCompleted` - Campanha já está em `Completed`.
+/// * `CampaignDisputed` - Campanha em disputa (aguarda `resolve_dispute`).
+/// * `CampaignNotActive` - Campanha já cancelada ou encerrada pelo influenciador (`Withdrawn`).
 /// * `UnauthorizedBrand` - A marca chamadora não corresponde à marca da campanha.
+/// * `ReportHeldForReview` - Há um relatório retido aguardando revisão.
+/// * `ReportPendingChallenge` - Há um relatório em janela de contestação.
+/// * `CancelNoticePending` - Campanha ativa sem aviso prévio ou com o prazo de aviso em curso.
+/// * `KillFeeAccountRequired` - Kill fee devida sem a conta USDC do influenciador.
 /// * `MathOverflow` - Overflow aritmético durante o cálculo do reembolso.
 pub fn cancel_campaign(ctx: Context<CancelCampaign>) -> Result<()> {
     // Validações de segurança
-    require!(ctx.accounts.campaign.status != CampaignStatus::Completed, ErrorCode::CampaignAlreadyCompleted);
+    match ctx.accounts.campaign.status {
+        CampaignStatus::Draft | CampaignStatus::Offered | CampaignStatus::Active | CampaignStatus::Paused => {}
+        CampaignStatus::Completed => return err!(ErrorCode::CampaignAlreadyCompleted),
+        CampaignStatus::Disputed => return err!(ErrorCode::CampaignDisputed),
+        CampaignStatus::Cancelled | CampaignStatus::Withdrawn => return err!(ErrorCode::CampaignNotActive),
+    }
     require_keys_eq!(ctx.accounts.brand.key(), ctx.accounts.campaign.brand, ErrorCode::UnauthorizedBrand);
 
-    if ctx.accounts.campaign.status == CampaignStatus::Active {
-        let remaining_amount = ctx.accounts.campaign.amount_usdc.checked_sub(ctx.accounts.campaign.paid_amount)
+    // Relatório retido ou em contestação deve ser resolvido antes (o influenciador pode ter ganhos pendentes)
+    require!(!ctx.accounts.campaign.report_held, ErrorCode::ReportHeldForReview);
+    require!(ctx.accounts.campaign.report_pending_since == 0, ErrorCode::ReportPendingChallenge);
+
+    // Campanha ativa com aviso prévio: exige `request_cancellation` e o fim do prazo
+    let campaign = &ctx.accounts.campaign;
+    let running = matches!(campaign.status, CampaignStatus::Active | CampaignStatus::Paused);
+    if running && campaign.cancel_notice_period > 0 {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            campaign.cancel_requested_at > 0
+                && now >= campaign.cancel_requested_at.saturating_add(campaign.cancel_notice_period),
+            ErrorCode::CancelNoticePending
+        );
+    }
+
+    let funded = running || (campaign.status == CampaignStatus::Offered && campaign.offer_escrowed);
+    if funded {
+        let unpaid = ctx.accounts.campaign.amount_usdc.checked_sub(ctx.accounts.campaign.paid_amount)
             .ok_or(ErrorCode::MathOverflow)?;
 
-        if remaining_amount > 0 {
-            let bump = ctx.bumps.campaign;
-            let seeds = &[
-                b"campaign".as_ref(),
-                ctx.accounts.campaign.influencer.as_ref(),
-                ctx.accounts.campaign.brand.as_ref(),
-                ctx.accounts.campaign.name.as_bytes(),
-                &[bump],
-            ];
-            let signer = &[&seeds[..]];
+        // Kill fee: parcela do saldo não pago devida ao influenciador ao cancelar após a ativação
+        let kill_fee = if running {
+            let bps = ctx.accounts.campaign.kill_fee_bps_at(Clock::get()?.unix_timestamp);
+            u64::try_from(u128::from(unpaid) * u128::from(bps) / u128::from(BPS_DENOMINATOR))
+                .map_err(|_| ErrorCode::MathOverflow)?
+        } else {
+            0
+        };
+
+        let remaining_amount = (unpaid - kill_fee)
+            .checked_add(ctx.accounts.campaign.oracle_fee_reserve)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        let bump = ctx.bumps.campaign;
+        let seeds = &[
+            b"campaign".as_ref(),
+            ctx.accounts.campaign.influencer.as_ref(),
+            ctx.accounts.campaign.brand.as_ref(),
+            ctx.accounts.campaign.name.as_bytes(),
+            &[bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        if kill_fee > 0 {
+            let influencer_usdc_account = ctx.accounts.influencer_usdc_account.as_ref().ok_or(ErrorCode::KillFeeAccountRequired)?;
 
+            // CPI para pagar a kill fee ao influenciador a partir do cofre da campanha
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.campaign_usdc_account.to_account_info(),
+                to: influencer_usdc_account.to_account_info(),
+                authority: ctx.accounts.campaign.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+            token::transfer(cpi_ctx, kill_fee)?;
+            msg!("Kill fee paga ao influenciador: {}", kill_fee);
+        }
+
+        if remaining_amount > 0 {
             // CPI para transferir o USDC restante do cofre da campanha para a marca
             let cpi_accounts = Transfer {
                 from: ctx.accounts.campaign_usdc_account.to_account_info(),
@@ -54,8 +120,11 @@ pub fn cancel_campaign(ctx: Context<CancelCampaign>) -> Result<()> {
             let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
             token::transfer(cpi_ctx, remaining_amount)?;
         }
+
+        ctx.accounts.campaign.paid_amount = ctx.accounts.campaign.paid_amount.checked_add(kill_fee).ok_or(ErrorCode::MathOverflow)?;
     }
 
+    ctx.accounts.campaign.oracle_fee_reserve = 0;
     ctx.accounts.campaign.status = CampaignStatus::Cancelled;
     ctx.accounts.campaign.last_updated = Clock::get()?.unix_timestamp;
 
@@ -85,6 +154,9 @@ pub struct CancelCampaign<'info> {
     /// Conta de token USDC do cofre da campanha (fonte do reembolso).
     #[account(mut)]
     pub campaign_usdc_account: Account<'info, TokenAccount>,
+    /// Conta de token USDC do influenciador (destino da kill fee, obrigatória se houver taxa devida).
+    #[account(mut, constraint = influencer_usdc_account.owner == campaign.influencer @ ErrorCode::InvalidTokenAccount)]
+    pub influencer_usdc_account: Option<Account<'info, TokenAccount>>,
     /// Programa SPL Token.
     pub token_program: Program<'info, Token>,
 }
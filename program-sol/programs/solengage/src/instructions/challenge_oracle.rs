@@ -0,0 +1,153 @@
+//! # Contestar Oráculo
+//!
+//! Este módulo define a instrução para a marca ou o influenciador contestar um relatório
+//! do oráculo com evidência assinada pelo árbitro e ser compensado pela caução.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::errors::ErrorCode;
+use crate::instructions::submit_signed_metrics::verify_ed25519_signature;
+use crate::state::{Campaign, ChallengeEvidence, OracleBond, OracleChallenge, OracleRegistry};
+
+/// Sustenta uma contestação contra o oráculo da campanha e executa o slashing.
+///
+/// A parte prejudicada (marca ou influenciador) envia a evidência assinada pelo
+/// árbitro do registro: os valores reportados pelo oráculo e os valores corretos.
+/// A transação deve conter, imediatamente antes desta instrução, uma instrução do
+/// programa Ed25519 verificando a assinatura do árbitro sobre `evidence.message()`.
+/// O valor do slash (limitado ao saldo da caução) é transferido ao contestante e a
+/// contestação fica registrada em `OracleChallenge`. Um oráculo desativado continua
+/// sujeito ao slashing enquanto sua caução está em unbonding.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `ChallengeOracle`.
+/// * `evidence` - Decisão do árbitro (campanha, oráculo, contestante, valores, slash, nonce).
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `UnauthorizedChallenger` - Assinante não é a marca nem o influenciador da campanha.
+/// * `ChallengeMismatch` - Evidência emitida para outra campanha, oráculo ou contestante.
+/// * `ChallengeNotContradicting` - Valores corretos iguais aos reportados.
+/// * `InvalidAmount` - Valor do slash igual a zero.
+/// * `InvalidSignatureInstruction` - Instrução Ed25519 ausente ou malformada.
+/// * `InvalidArbiterSignature` - Evidência não assinada pelo árbitro.
+/// * `InvalidTokenAccount` - Conta de destino não pertence ao contestante ou é de outro token.
+/// * `MathOverflow` - Overflow nos contadores da caução.
+pub fn challenge_oracle(ctx: Context<ChallengeOracle>, evidence: ChallengeEvidence) -> Result<()> {
+    let campaign = &ctx.accounts.campaign;
+    let challenger = ctx.accounts.challenger.key();
+
+    // Valida partes e conteúdo da evidência
+    require!(
+        challenger == campaign.brand || challenger == campaign.influencer,
+        ErrorCode::UnauthorizedChallenger
+    );
+    require!(
+        evidence.campaign == campaign.key()
+            && evidence.oracle == campaign.oracle
+            && evidence.challenger == challenger,
+        ErrorCode::ChallengeMismatch
+    );
+    require!(evidence.reported != evidence.observed, ErrorCode::ChallengeNotContradicting);
+    require!(evidence.slash_amount > 0, ErrorCode::InvalidAmount);
+
+    verify_ed25519_signature(
+        &ctx.accounts.instructions_sysvar,
+        &ctx.accounts.oracle_registry.arbiter,
+        &evidence.message(),
+        ErrorCode::InvalidArbiterSignature,
+    )?;
+
+    // Slash limitado ao saldo disponível da caução
+    let slashed_amount = evidence.slash_amount.min(ctx.accounts.bond_vault.amount);
+    if slashed_amount > 0 {
+        let oracle_key = campaign.oracle;
+        let seeds = &[b"oracle_bond".as_ref(), oracle_key.as_ref(), &[ctx.accounts.oracle_bond.bump]];
+        let signer = &[&seeds[..]];
+
+        // CPI para transferir tokens da caução ao contestante
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.bond_vault.to_account_info(),
+            to: ctx.accounts.challenger_token_account.to_account_info(),
+            authority: ctx.accounts.oracle_bond.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+        token::transfer(cpi_ctx, slashed_amount)?;
+    } else {
+        msg!("Caução do oráculo vazia: contestação registrada sem compensação");
+    }
+
+    let bond = &mut ctx.accounts.oracle_bond;
+    bond.slashed_amount = bond.slashed_amount.checked_add(slashed_amount).ok_or(ErrorCode::MathOverflow)?;
+    bond.slash_count = bond.slash_count.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+
+    let record = &mut ctx.accounts.oracle_challenge;
+    record.campaign = evidence.campaign;
+    record.oracle = evidence.oracle;
+    record.challenger = challenger;
+    record.reported = evidence.reported;
+    record.observed = evidence.observed;
+    record.slashed_amount = slashed_amount;
+    record.created_at = Clock::get()?.unix_timestamp;
+    record.bump = ctx.bumps.oracle_challenge;
+
+    Ok(())
+}
+
+/// Contas para a instrução `challenge_oracle`.
+#[derive(Accounts)]
+#[instruction(evidence: ChallengeEvidence)]
+pub struct ChallengeOracle<'info> {
+    /// Conta da campanha onde ocorreu o relatório incorreto.
+    ///
+    /// Deve ser uma PDA derivada de `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Registro global de oráculos (fornece o árbitro).
+    #[account(seeds = [b"oracle_registry"], bump = oracle_registry.bump)]
+    pub oracle_registry: Account<'info, OracleRegistry>,
+    /// Caução do oráculo referenciado por `campaign.oracle`.
+    #[account(
+        mut,
+        seeds = [b"oracle_bond", campaign.oracle.as_ref()],
+        bump = oracle_bond.bump
+    )]
+    pub oracle_bond: Account<'info, OracleBond>,
+    /// Cofre de tokens da caução.
+    #[account(mut, address = oracle_bond.vault @ ErrorCode::InvalidTokenAccount)]
+    pub bond_vault: Account<'info, TokenAccount>,
+    /// Registro da contestação (impede replay do mesmo nonce).
+    ///
+    /// PDA derivada de `["oracle_challenge", campaign, evidence.nonce]`.
+    #[account(
+        init,
+        payer = challenger,
+        space = 8 + OracleChallenge::INIT_SPACE,
+        seeds = [b"oracle_challenge", campaign.key().as_ref(), evidence.nonce.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub oracle_challenge: Account<'info, OracleChallenge>,
+    /// Conta de tokens do contestante (destino da compensação).
+    #[account(
+        mut,
+        constraint = challenger_token_account.owner == challenger.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = challenger_token_account.mint == oracle_bond.mint @ ErrorCode::InvalidTokenAccount
+    )]
+    pub challenger_token_account: Account<'info, TokenAccount>,
+    /// Marca ou influenciador da campanha (assinante e pagador).
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+    /// Sysvar de instruções, usada para inspecionar a instrução Ed25519.
+    /// CHECK: Endereço validado como a sysvar de instruções.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+    /// Programa SPL Token.
+    pub token_program: Program<'info, Token>,
+    /// Programa do sistema Solana.
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,62 @@
+//! # Contestar Relatório Pendente
+//!
+//! Este módulo define a instrução para a marca congelar um relatório em janela de contestação.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{Campaign, CampaignStatus};
+
+/// Contesta o relatório pendente e o congela para arbitragem.
+///
+/// Somente a marca pode chamar, enquanto a janela de contestação do relatório está aberta.
+/// O relatório passa a ser um relatório retido marcado como contestado: nenhum pagamento
+/// é feito e novos relatórios são rejeitados até que o árbitro do registro o aprove ou
+/// descarte via `resolve_held_report`.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `ChallengePendingReport`.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotActive` - Campanha não está em `Active`.
+/// * `UnauthorizedBrand` - Assinante não é a marca da campanha.
+/// * `NoPendingReport` - Não há relatório em janela de contestação.
+/// * `ChallengeWindowClosed` - A janela de contestação já terminou.
+pub fn challenge_pending_report(ctx: Context<ChallengePendingReport>) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Validações de segurança
+    require_eq!(campaign.status, CampaignStatus::Active, ErrorCode::CampaignNotActive);
+    require!(campaign.report_pending_since > 0, ErrorCode::NoPendingReport);
+    require!(campaign.challenge_window_open(now), ErrorCode::ChallengeWindowClosed);
+
+    // Congela o relatório: os valores continuam pendentes nas entradas de métricas
+    campaign.report_pending_since = 0;
+    campaign.report_held = true;
+    campaign.report_challenged = true;
+    campaign.last_updated = now;
+
+    msg!("Relatório contestado pela marca, aguardando o árbitro");
+    Ok(())
+}
+
+/// Contas para a instrução `challenge_pending_report`.
+#[derive(Accounts)]
+pub struct ChallengePendingReport<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, possuir a marca correta e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = brand @ ErrorCode::UnauthorizedBrand,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Conta da marca (assinante).
+    pub brand: Signer<'info>,
+}
@@ -4,12 +4,14 @@
 
 use anchor_lang::prelude::*;
 use crate::errors::ErrorCode;
-use crate::state::{Campaign, CampaignStatus};
+use crate::state::{Campaign, CampaignStatus, OracleEntry, ORACLE_CHALLENGE_PERIOD};
 
 /// Closes a completed campaign account.
 ///
-/// This function can only be called when the campaign is in the `Completed` status.
-/// It closes the campaign account and refunds the remaining rent to the oracle.
+/// This function can only be called when the campaign is in the `Completed` status and
+/// `ORACLE_CHALLENGE_PERIOD` has elapsed since completion, so the report that completed
+/// it can still be challenged against the campaign account.
+/// It closes the campaign account and refunds the remaining rent to the oracle's current key.
 ///
 /// # Arguments
 ///
@@ -17,10 +19,17 @@ use crate::state::{Campaign, CampaignStatus};
 ///
 /// # Errors
 ///
-/// This function will return an `ErrorCode` if the campaign is not in `Completed` status.
+/// This function will return an `ErrorCode` if the campaign is not in `Completed` status,
+/// if its challenge period is still running (`ChallengePeriodActive`), or if the unused
+/// oracle fee reserve has not been refunded via `refund_oracle_fees`.
 pub fn close_campaign(ctx: Context<CloseCampaign>) -> Result<()> {
     // Security validation: Ensure campaign is completed before closing.
     require_eq!(ctx.accounts.campaign.status, CampaignStatus::Completed, ErrorCode::CampaignNotInTerminalState);
+    require!(
+        Clock::get()?.unix_timestamp >= ctx.accounts.campaign.completed_at.saturating_add(ORACLE_CHALLENGE_PERIOD),
+        ErrorCode::ChallengePeriodActive
+    );
+    require_eq!(ctx.accounts.campaign.oracle_fee_reserve, 0, ErrorCode::OracleFeeReserveNotRefunded);
     Ok(())
 }
 
@@ -29,19 +38,21 @@ pub fn close_campaign(ctx: Context<CloseCampaign>) -> Result<()> {
 pub struct CloseCampaign<'info> {
     /// The campaign account to be closed.
     ///
-    /// Must be mutable, have the correct oracle, and be a PDA derived from
+    /// Must be mutable, reference the given oracle entry, and be a PDA derived from
     /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
     /// The `close` constraint ensures the account is closed and rent refunded to the oracle.
     #[account(
         mut,
-        close = oracle,
+        close = oracle_authority,
         has_one = oracle,
         seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
         bump
     )]
     pub campaign: Account<'info, Campaign>,
-    /// The oracle account that receives the rent refund from the closed campaign account.
-    #[account(mut)]
-    /// CHECK: Oracle receives the rent refund
-    pub oracle: AccountInfo<'info>,
+    /// The oracle's registry entry referenced by the campaign.
+    pub oracle: Account<'info, OracleEntry>,
+    /// The oracle's current key, which receives the rent refund from the closed campaign account.
+    #[account(mut, address = oracle.authority @ ErrorCode::UnauthorizedOracle)]
+    /// CHECK: Validated against the oracle entry authority
+    pub oracle_authority: AccountInfo<'info>,
 }
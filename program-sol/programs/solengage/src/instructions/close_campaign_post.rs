@@ -0,0 +1,59 @@
+//! # Fechar Post da Campanha
+//!
+//! Este módulo define a instrução para o influenciador fechar um post de uma campanha encerrada.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{Campaign, CampaignPost, CampaignStatus};
+
+/// Fecha a conta `CampaignPost` e devolve o rent ao influenciador que a pagou.
+///
+/// Permitido quando a campanha está em `Completed` ou `Cancelled`, ou quando a conta da
+/// campanha já foi fechada (`close_campaign`). Campanhas `Withdrawn` fecham seus posts em
+/// `close_withdrawal`, que exige todos os posts da campanha, por isso não são aceitas aqui.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `CloseCampaignPost`.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `UnauthorizedInfluencer` - Assinante não é o influenciador que registrou o post.
+/// * `InvalidCampaignPost` - Conta de campanha informada não é a do post.
+/// * `CampaignNotInTerminalState` - Campanha ainda aberta e não está em `Completed` nem `Cancelled`.
+pub fn close_campaign_post(ctx: Context<CloseCampaignPost>) -> Result<()> {
+    let campaign_info = &ctx.accounts.campaign;
+
+    // Conta da campanha ainda existe: exige estado terminal
+    if *campaign_info.owner == crate::ID && !campaign_info.data_is_empty() {
+        let campaign = Campaign::try_deserialize(&mut &campaign_info.try_borrow_data()?[..])?;
+        require!(
+            matches!(campaign.status, CampaignStatus::Completed | CampaignStatus::Cancelled),
+            ErrorCode::CampaignNotInTerminalState
+        );
+    }
+
+    Ok(())
+}
+
+/// Contas para a instrução `close_campaign_post`.
+#[derive(Accounts)]
+pub struct CloseCampaignPost<'info> {
+    /// Conta do post a ser fechada; o rent é devolvido ao influenciador.
+    #[account(
+        mut,
+        close = influencer,
+        has_one = influencer @ ErrorCode::UnauthorizedInfluencer,
+        seeds = [b"campaign_post", campaign_post.campaign.as_ref(), campaign_post.url_hash.as_ref()],
+        bump = campaign_post.bump
+    )]
+    pub campaign_post: Account<'info, CampaignPost>,
+    /// Conta da campanha do post (pode já ter sido fechada).
+    /// CHECK: Endereço validado contra `campaign_post.campaign`.
+    #[account(address = campaign_post.campaign @ ErrorCode::InvalidCampaignPost)]
+    pub campaign: UncheckedAccount<'info>,
+    /// Conta do influenciador (assinante, recebe o rent).
+    #[account(mut)]
+    pub influencer: Signer<'info>,
+}
@@ -0,0 +1,58 @@
+//! # Fechar Histórico de Métricas
+//!
+//! Este módulo define a instrução para a marca fechar o histórico de uma campanha encerrada.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{Campaign, CampaignStatus, MetricHistory};
+
+/// Fecha a conta `MetricHistory` e devolve o rent à marca.
+///
+/// Permitido quando a campanha está em `Completed`, `Cancelled` ou `Withdrawn`, ou quando a conta
+/// da campanha já foi fechada (`close_campaign`, `settle_campaign` ou `close_withdrawal`).
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `CloseMetricHistory`.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `UnauthorizedBrand` - Assinante não é a marca que criou o histórico.
+/// * `InvalidMetricHistory` - Conta de campanha informada não é a do histórico.
+/// * `CampaignNotInTerminalState` - Campanha ainda aberta e não encerrada.
+pub fn close_metric_history(ctx: Context<CloseMetricHistory>) -> Result<()> {
+    let campaign_info = &ctx.accounts.campaign;
+
+    // Conta da campanha ainda existe: exige estado terminal
+    if *campaign_info.owner == crate::ID && !campaign_info.data_is_empty() {
+        let campaign = Campaign::try_deserialize(&mut &campaign_info.try_borrow_data()?[..])?;
+        require!(
+            matches!(campaign.status, CampaignStatus::Completed | CampaignStatus::Cancelled | CampaignStatus::Withdrawn),
+            ErrorCode::CampaignNotInTerminalState
+        );
+    }
+
+    Ok(())
+}
+
+/// Contas para a instrução `close_metric_history`.
+#[derive(Accounts)]
+pub struct CloseMetricHistory<'info> {
+    /// Conta do histórico a ser fechada; o rent é devolvido à marca.
+    #[account(
+        mut,
+        close = brand,
+        has_one = brand @ ErrorCode::UnauthorizedBrand,
+        seeds = [b"metric_history", metric_history.campaign.as_ref()],
+        bump = metric_history.bump
+    )]
+    pub metric_history: Account<'info, MetricHistory>,
+    /// Conta da campanha do histórico (pode já ter sido fechada).
+    /// CHECK: Endereço validado contra `metric_history.campaign`.
+    #[account(address = metric_history.campaign @ ErrorCode::InvalidMetricHistory)]
+    pub campaign: UncheckedAccount<'info>,
+    /// Conta da marca (assinante, recebe o rent).
+    #[account(mut)]
+    pub brand: Signer<'info>,
+}
@@ -0,0 +1,86 @@
+//! # Fechar Desistência
+//!
+//! Este módulo define a instrução para o influenciador fechar uma campanha encerrada por desistência.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{Campaign, CampaignPost, CampaignStatus, OracleQuorum};
+
+/// Fecha a campanha `Withdrawn`, devolvendo o rent ao influenciador.
+///
+/// O `WithdrawalRecord` da campanha não é fechado: ele permanece como registro de reputação.
+/// Os posts da campanha e o quórum de oráculos (quando habilitado) devem ser informados em
+/// `remaining_accounts` e são fechados junto com a campanha. O histórico de métricas, se
+/// existir, continua com a marca e pode ser fechado depois com `close_metric_history`.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `CloseWithdrawal`.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `UnauthorizedInfluencer` - Assinante não é o influenciador da campanha.
+/// * `CampaignNotInTerminalState` - Campanha não está em `Withdrawn`.
+/// * `CampaignChildAccountsRequired` - Posts ou quórum da campanha ausentes ou inválidos.
+pub fn close_withdrawal<'info>(ctx: Context<'_, '_, 'info, 'info, CloseWithdrawal<'info>>) -> Result<()> {
+    require_eq!(ctx.accounts.campaign.status, CampaignStatus::Withdrawn, ErrorCode::CampaignNotInTerminalState);
+
+    let influencer = ctx.accounts.influencer.to_account_info();
+    close_campaign_children(&ctx.accounts.campaign, ctx.remaining_accounts, &influencer)
+}
+
+/// Fecha os posts e o quórum de oráculos de uma campanha que está sendo fechada.
+///
+/// `children` deve conter exatamente os `post_count` posts da campanha e, se o quórum
+/// estiver habilitado, a conta `["oracle_quorum", campaign]`, em qualquer ordem. Uma conta
+/// repetida falha ao ser lida pela segunda vez, pois já foi fechada. O rent vai para
+/// `rent_receiver`.
+pub(crate) fn close_campaign_children<'info>(
+    campaign: &Account<'info, Campaign>,
+    children: &'info [AccountInfo<'info>],
+    rent_receiver: &AccountInfo<'info>,
+) -> Result<()> {
+    let quorum_key = campaign
+        .quorum_enabled
+        .then(|| Pubkey::find_program_address(&[b"oracle_quorum", campaign.key().as_ref()], &crate::ID).0);
+    require!(
+        children.len() == usize::from(campaign.post_count) + usize::from(quorum_key.is_some()),
+        ErrorCode::CampaignChildAccountsRequired
+    );
+
+    for child in children {
+        if Some(child.key()) == quorum_key {
+            let quorum = Account::<OracleQuorum>::try_from(child)?;
+            quorum.close(rent_receiver.clone())?;
+        } else {
+            let post = Account::<CampaignPost>::try_from(child)?;
+            require_keys_eq!(post.campaign, campaign.key(), ErrorCode::CampaignChildAccountsRequired);
+            post.close(rent_receiver.clone())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Contas para a instrução `close_withdrawal`.
+///
+/// Os posts e o quórum da campanha são passados em `remaining_accounts`.
+#[derive(Accounts)]
+pub struct CloseWithdrawal<'info> {
+    /// Conta da campanha, fechada ao final (rent devolvido ao influenciador).
+    ///
+    /// Deve ser mutável, possuir o influenciador correto e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        close = influencer,
+        has_one = influencer @ ErrorCode::UnauthorizedInfluencer,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Conta do influenciador (assinante, recebe o rent).
+    #[account(mut)]
+    pub influencer: Signer<'info>,
+}
@@ -4,12 +4,14 @@
 
 use anchor_lang::prelude::*;
 use crate::errors::ErrorCode;
-use crate::state::{Campaign, CampaignStatus};
+use crate::state::{base_metric_entries, Campaign, CampaignStatus, MetricGuards, OracleEntry, PayoutCurve};
 
 /// Cria uma nova campanha em status `Draft`.
 ///
 /// Inicializa uma conta `Campaign` com os detalhes fornecidos.
 /// Realiza validações para garantir a integridade dos dados da campanha.
+/// As metas gerais viram as entradas-base de `metrics`, ponderadas pelo próprio valor;
+/// outras métricas e pesos podem ser definidos em `Draft` via `set_metric_targets`.
 ///
 /// # Argumentos
 ///
@@ -35,6 +37,9 @@ use crate::state::{Campaign, CampaignStatus};
 /// * `InvalidAmount` - `amount_usdc` igual a zero.
 /// * `InvalidDeadline` - `deadline` no passado.
 /// * `NoTargetsSet` - Todas as metas (likes, comments, views, shares) iguais a zero.
+/// * `OracleNotRegistered` - Oráculo desativado no registro global.
+/// * `OracleConflictOfInterest` - Chave do oráculo pertence ao influenciador ou à marca.
+#[allow(clippy::too_many_arguments)]
 pub fn create_campaign(
     ctx: Context<CreateCampaign>,
     name: String,
@@ -48,7 +53,50 @@ pub fn create_campaign(
     amount_usdc: u64,
     deadline: i64,
 ) -> Result<()> {
-    // Validações de entrada
+    validate_campaign_terms(
+        &name,
+        &nickname,
+        &brand_name,
+        &hashtag,
+        target_likes,
+        target_comments,
+        target_views,
+        target_shares,
+        amount_usdc,
+        deadline,
+    )?;
+
+    let campaign = &mut ctx.accounts.campaign;
+    campaign.influencer = ctx.accounts.influencer.key();
+    campaign.brand = ctx.accounts.brand.key();
+    campaign.name = name;
+    campaign.nickname = nickname;
+    campaign.brand_name = brand_name;
+    campaign.hashtag = hashtag;
+    campaign.metrics = base_metric_entries(target_likes, target_comments, target_views, target_shares);
+    campaign.amount_usdc = amount_usdc;
+    campaign.deadline = deadline;
+    initialize_campaign_state(campaign, ctx.accounts.oracle.key(), CampaignStatus::Draft)?;
+
+    Ok(())
+}
+
+/// Valida os termos de uma campanha com as regras de `create_campaign`.
+///
+/// Compartilhado pelas instruções que criam ou alteram termos de campanha.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn validate_campaign_terms(
+    name: &str,
+    nickname: &str,
+    brand_name: &str,
+    hashtag: &str,
+    target_likes: u64,
+    target_comments: u64,
+    target_views: u64,
+    target_shares: u64,
+    amount_usdc: u64,
+    deadline: i64,
+) -> Result<()> {
     require!(name.len() <= 50, ErrorCode::NameTooLong);
     require!(nickname.len() <= 50, ErrorCode::NicknameTooLong);
     require!(brand_name.len() <= 50, ErrorCode::BrandNameTooLong);
@@ -59,30 +107,58 @@ pub fn create_campaign(
         target_likes > 0 || target_comments > 0 || target_views > 0 || target_shares > 0,
         ErrorCode::NoTargetsSet
     );
+    Ok(())
+}
 
-    let campaign = &mut ctx.accounts.campaign;
-    campaign.influencer = ctx.accounts.influencer.key();
-    campaign.brand = ctx.accounts.brand.key();
-    campaign.name = name;
-    campaign.nickname = nickname;
-    campaign.brand_name = brand_name;
-    campaign.hashtag = hashtag;
-    campaign.target_likes = target_likes;
-    campaign.target_comments = target_comments;
-    campaign.target_views = target_views;
-    campaign.target_shares = target_shares;
-    campaign.amount_usdc = amount_usdc;
-    campaign.deadline = deadline;
-    campaign.current_likes = 0;
-    campaign.current_comments = 0;
-    campaign.current_views = 0;
-    campaign.current_shares = 0;
-    campaign.status = CampaignStatus::Draft;
+/// Inicializa o estado de uma nova campanha (oráculo, status e valores padrão).
+///
+/// Os termos (partes, nome, metas, orçamento e prazo) devem ser definidos pelo chamador.
+pub(crate) fn initialize_campaign_state(campaign: &mut Campaign, oracle: Pubkey, status: CampaignStatus) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    campaign.status = status;
     campaign.paid_amount = 0;
-    campaign.oracle = ctx.accounts.oracle.key();
-    campaign.created_at = Clock::get()?.unix_timestamp;
-    campaign.last_updated = Clock::get()?.unix_timestamp;
+    campaign.oracle = oracle;
+    campaign.quorum_enabled = false;
+    campaign.report_sequence = 0;
+    campaign.metric_guards = MetricGuards::default();
+    campaign.report_held = false;
+    campaign.held_at = 0;
+    campaign.held_post = None;
+    campaign.challenge_window = 0;
+    campaign.report_pending_since = 0;
+    campaign.report_challenged = false;
+    campaign.post_count = 0;
+    campaign.history_enabled = false;
+    campaign.metric_feed = None;
+    campaign.offer_escrowed = false;
+    campaign.terms_version = 0;
+    campaign.pending_terms = None;
+    campaign.oracle_heartbeat = 0;
+    campaign.last_oracle_report_at = 0;
+    campaign.cancel_notice_period = 0;
+    campaign.fallback_opened_at = 0;
+    campaign.cancel_requested_at = 0;
+    campaign.max_pause_duration = 0;
+    campaign.paused_at = 0;
+    campaign.total_paused = 0;
+    campaign.disputed_by = None;
+    campaign.dispute_opened_at = 0;
+    campaign.kill_fee_bps = 0;
+    campaign.kill_fee_max_bps = 0;
+    campaign.activated_at = 0;
+    campaign.content_review_window = 0;
+    campaign.content = None;
+    campaign.oracle_fee_per_update = 0;
+    campaign.oracle_fee_cap = 0;
+    campaign.oracle_fee_reserve = 0;
+    campaign.oracle_fees_paid = 0;
+    campaign.report_fee_recipient = None;
+    campaign.created_at = now;
+    campaign.last_updated = now;
+    campaign.last_observed_at = 0;
+    campaign.completed_at = 0;
     campaign.payment_milestones = [false; 10];
+    campaign.payout_curve = PayoutCurve::Linear;
 
     Ok(())
 }
@@ -107,9 +183,16 @@ pub struct CreateCampaign<'info> {
     pub influencer: Signer<'info>,
     /// Conta da marca (SystemAccount).
     pub brand: SystemAccount<'info>,
-    /// Conta do oráculo.
-    /// CHECK: Verificado via validação de assinatura no handler da instrução.
-    pub oracle: AccountInfo<'info>,
+    /// Entrada do oráculo no registro global.
+    ///
+    /// Deve estar ativa, e sua chave não pode pertencer ao influenciador nem à marca.
+    #[account(
+        seeds = [b"oracle", oracle.id.to_le_bytes().as_ref()],
+        bump = oracle.bump,
+        constraint = oracle.active @ ErrorCode::OracleNotRegistered,
+        constraint = oracle.authority != influencer.key() && oracle.authority != brand.key() @ ErrorCode::OracleConflictOfInterest
+    )]
+    pub oracle: Account<'info, OracleEntry>,
     /// Programa do sistema Solana.
     pub system_program: Program<'info, System>,
 }
@@ -0,0 +1,153 @@
+//! # Criar Oferta
+//!
+//! Este módulo define a instrução para a marca propor uma campanha a um influenciador.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::errors::ErrorCode;
+use crate::state::{base_metric_entries, Campaign, CampaignStatus, OracleBond, OracleEntry};
+use super::create_campaign::{initialize_campaign_state, validate_campaign_terms};
+
+/// Cria uma campanha em status `Offered`, proposta pela marca.
+///
+/// A marca assina e paga o aluguel da conta; os termos seguem as mesmas validações de
+/// `create_campaign`. Se `escrow` for verdadeiro, `amount_usdc` é transferido da marca
+/// para o cofre da campanha já na criação, e o aceite do influenciador ativa a campanha
+/// diretamente; nesse caso o oráculo deve ter uma caução de pelo menos `MIN_ORACLE_BOND`
+/// no mesmo token do cofre. Sem depósito, o aceite leva a campanha para `Draft`, e a marca a financia
+/// depois via `brand_pay_campaign`.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `CreateOffer`.
+/// * `name` - Nome único da campanha (máx. 50 caracteres).
+/// * `nickname` - Apelido/handle do influenciador (máx. 50 caracteres).
+/// * `brand_name` - Nome da marca (máx. 50 caracteres).
+/// * `hashtag` - Hashtag da campanha (máx. 50 caracteres).
+/// * `target_likes` - Meta de curtidas.
+/// * `target_comments` - Meta de comentários.
+/// * `target_views` - Meta de visualizações.
+/// * `target_shares` - Meta de compartilhamentos.
+/// * `amount_usdc` - Orçamento total da campanha em USDC (6 decimais).
+/// * `deadline` - Timestamp Unix de expiração da campanha.
+/// * `escrow` - Se o orçamento é depositado no cofre já na oferta.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * Qualquer erro de validação de `create_campaign` (`NameTooLong` ... `NoTargetsSet`).
+/// * `OracleNotRegistered` - Oráculo desativado no registro global.
+/// * `OracleConflictOfInterest` - Chave do oráculo pertence ao influenciador ou à marca.
+/// * `InvalidTokenAccount` - Cofre não pertence à campanha.
+/// * `OracleBondMintMismatch` - Com depósito, caução do oráculo em outro token.
+/// * `OracleBondTooLow` - Com depósito, caução do oráculo ausente ou abaixo de `MIN_ORACLE_BOND`.
+#[allow(clippy::too_many_arguments)]
+pub fn create_offer(
+    ctx: Context<CreateOffer>,
+    name: String,
+    nickname: String,
+    brand_name: String,
+    hashtag: String,
+    target_likes: u64,
+    target_comments: u64,
+    target_views: u64,
+    target_shares: u64,
+    amount_usdc: u64,
+    deadline: i64,
+    escrow: bool,
+) -> Result<()> {
+    validate_campaign_terms(
+        &name,
+        &nickname,
+        &brand_name,
+        &hashtag,
+        target_likes,
+        target_comments,
+        target_views,
+        target_shares,
+        amount_usdc,
+        deadline,
+    )?;
+
+    if escrow {
+        let (oracle_bond, bond_vault) = ctx
+            .accounts
+            .oracle_bond
+            .as_ref()
+            .zip(ctx.accounts.bond_vault.as_ref())
+            .ok_or(ErrorCode::OracleBondTooLow)?;
+        oracle_bond.validate_for_campaign(bond_vault.amount, ctx.accounts.campaign_usdc_account.mint)?;
+
+        // CPI para depositar o orçamento no cofre da campanha
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.brand_usdc_account.to_account_info(),
+            to: ctx.accounts.campaign_usdc_account.to_account_info(),
+            authority: ctx.accounts.brand.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+        token::transfer(cpi_ctx, amount_usdc)?;
+    }
+
+    let campaign = &mut ctx.accounts.campaign;
+    campaign.influencer = ctx.accounts.influencer.key();
+    campaign.brand = ctx.accounts.brand.key();
+    campaign.name = name;
+    campaign.nickname = nickname;
+    campaign.brand_name = brand_name;
+    campaign.hashtag = hashtag;
+    campaign.metrics = base_metric_entries(target_likes, target_comments, target_views, target_shares);
+    campaign.amount_usdc = amount_usdc;
+    campaign.deadline = deadline;
+    initialize_campaign_state(campaign, ctx.accounts.oracle.key(), CampaignStatus::Offered)?;
+    campaign.offer_escrowed = escrow;
+
+    Ok(())
+}
+
+/// Contas para a instrução `create_offer`.
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateOffer<'info> {
+    /// Conta da campanha a ser inicializada.
+    ///
+    /// PDA derivada de `["campaign", influencer, brand, name]`.
+    #[account(
+        init,
+        payer = brand,
+        space = 8 + Campaign::INIT_SPACE,
+        seeds = [b"campaign", influencer.key().as_ref(), brand.key().as_ref(), name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Conta da marca (assinante), também pagadora da inicialização.
+    #[account(mut)]
+    pub brand: Signer<'info>,
+    /// Conta do influenciador (SystemAccount).
+    pub influencer: SystemAccount<'info>,
+    /// Entrada do oráculo no registro global.
+    ///
+    /// Deve estar ativa, e sua chave não pode pertencer ao influenciador nem à marca.
+    #[account(
+        seeds = [b"oracle", oracle.id.to_le_bytes().as_ref()],
+        bump = oracle.bump,
+        constraint = oracle.active @ ErrorCode::OracleNotRegistered,
+        constraint = oracle.authority != influencer.key() && oracle.authority != brand.key() @ ErrorCode::OracleConflictOfInterest
+    )]
+    pub oracle: Account<'info, OracleEntry>,
+    /// Caução do oráculo (obrigatória quando há depósito).
+    #[account(seeds = [b"oracle_bond", oracle.key().as_ref()], bump = oracle_bond.bump)]
+    pub oracle_bond: Option<Account<'info, OracleBond>>,
+    /// Cofre de tokens da caução do oráculo (obrigatório quando há depósito).
+    #[account(seeds = [b"oracle_bond_vault", oracle.key().as_ref()], bump)]
+    pub bond_vault: Option<Account<'info, TokenAccount>>,
+    /// Conta de token USDC da marca (fonte do depósito).
+    #[account(mut)]
+    pub brand_usdc_account: Account<'info, TokenAccount>,
+    /// Conta de token USDC do cofre da campanha (destino do depósito).
+    #[account(mut, constraint = campaign_usdc_account.owner == campaign.key() @ ErrorCode::InvalidTokenAccount)]
+    pub campaign_usdc_account: Account<'info, TokenAccount>,
+    /// Programa SPL Token.
+    pub token_program: Program<'info, Token>,
+    /// Programa do sistema Solana.
+    pub system_program: Program<'info, System>,
+}
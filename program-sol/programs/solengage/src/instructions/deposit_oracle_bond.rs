@@ -0,0 +1,61 @@
+//! # Depositar Caução do Oráculo
+//!
+//! Este módulo define a instrução para travar tokens na caução de um oráculo.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::errors::ErrorCode;
+use crate::state::{OracleBond, OracleEntry};
+
+/// Deposita tokens na caução do oráculo.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `DepositOracleBond`.
+/// * `amount` - Quantidade de tokens a travar.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `InvalidAmount` - Quantidade igual a zero.
+/// * `UnauthorizedOracle` - Assinante não é a chave atual do oráculo.
+/// * `InvalidTokenAccount` - Conta de origem não pertence ao oráculo.
+pub fn deposit_oracle_bond(ctx: Context<DepositOracleBond>, amount: u64) -> Result<()> {
+    require!(amount > 0, ErrorCode::InvalidAmount);
+
+    // CPI para transferir tokens do oráculo ao cofre da caução
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.authority_token_account.to_account_info(),
+        to: ctx.accounts.bond_vault.to_account_info(),
+        authority: ctx.accounts.oracle_authority.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+
+    Ok(())
+}
+
+/// Contas para a instrução `deposit_oracle_bond`.
+#[derive(Accounts)]
+pub struct DepositOracleBond<'info> {
+    /// Entrada do oráculo no registro global.
+    pub oracle: Account<'info, OracleEntry>,
+    /// Caução do oráculo.
+    #[account(
+        has_one = oracle,
+        seeds = [b"oracle_bond", oracle.key().as_ref()],
+        bump = oracle_bond.bump
+    )]
+    pub oracle_bond: Account<'info, OracleBond>,
+    /// Cofre de tokens da caução.
+    #[account(mut, address = oracle_bond.vault @ ErrorCode::InvalidTokenAccount)]
+    pub bond_vault: Account<'info, TokenAccount>,
+    /// Conta de tokens do oráculo (origem do depósito).
+    #[account(mut, constraint = authority_token_account.owner == oracle_authority.key() @ ErrorCode::InvalidTokenAccount)]
+    pub authority_token_account: Account<'info, TokenAccount>,
+    /// Chave atual do oráculo (assinante).
+    #[account(address = oracle.authority @ ErrorCode::UnauthorizedOracle)]
+    pub oracle_authority: Signer<'info>,
+    /// Programa SPL Token.
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,105 @@
+//! # Finalizar Relatório Pendente
+//!
+//! Este módulo define a instrução permissionless que aplica um relatório cuja janela de contestação terminou.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::errors::ErrorCode;
+use crate::instructions::update_campaign_metrics::{apply_metrics, ReportAccounts};
+use crate::state::{Campaign, CampaignPost, CampaignStatus, MetricHistory, OracleEntry};
+
+/// Aplica o relatório pendente depois que a janela de contestação termina sem contestação.
+///
+/// Qualquer conta pode chamar (crank). Os valores pendentes são aplicados pela mesma
+/// lógica de progresso e pagamento de `update_campaign_metrics`. Se o relatório veio de
+/// `update_post_metrics`, o post correspondente deve ser informado; se a campanha registra
+/// histórico, a conta `MetricHistory` também. A taxa do oráculo que enviou o relatório
+/// é paga neste momento, na conta USDC desse oráculo.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `FinalizePendingReport`.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotActive` - Campanha não está em `Active`.
+/// * `NoPendingReport` - Não há relatório em janela de contestação.
+/// * `ChallengeWindowOpen` - A janela de contestação ainda não terminou.
+/// * `HeldPostMismatch` - Post ausente ou diferente do post do relatório pendente.
+/// * `MetricHistoryRequired` - Campanha registra histórico e a conta não foi informada.
+/// * `OracleFeeAccountRequired` - Taxa devida sem conta USDC do oráculo informada.
+/// * `InvalidTokenAccount` - Contas de token não pertencem à campanha/influenciador/oráculo.
+/// * `MathOverflow` - Overflow aritmético durante cálculos de pagamento.
+pub fn finalize_pending_report(ctx: Context<FinalizePendingReport>) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Validações de segurança
+    require_eq!(campaign.status, CampaignStatus::Active, ErrorCode::CampaignNotActive);
+    require!(campaign.report_pending_since > 0, ErrorCode::NoPendingReport);
+    require!(!campaign.challenge_window_open(now), ErrorCode::ChallengeWindowOpen);
+
+    campaign.report_pending_since = 0;
+    let observed_at = campaign.held_at;
+    campaign.held_at = 0;
+
+    // Relatório de post: o post recebe as métricas junto com a campanha
+    if let Some(held_post) = campaign.held_post.take() {
+        let post = ctx.accounts.campaign_post.as_mut().ok_or(ErrorCode::HeldPostMismatch)?;
+        require_keys_eq!(post.key(), held_post, ErrorCode::HeldPostMismatch);
+        post.metrics = post.held_metrics.take().ok_or(ErrorCode::HeldPostMismatch)?;
+    }
+
+    campaign.last_observed_at = observed_at;
+    apply_metrics(
+        campaign,
+        ReportAccounts {
+            campaign_bump: ctx.bumps.campaign,
+            campaign_usdc_account: &ctx.accounts.campaign_usdc_account,
+            influencer_usdc_account: &ctx.accounts.influencer_usdc_account,
+            oracle_usdc_account: ctx.accounts.oracle_usdc_account.as_ref(),
+            token_program: &ctx.accounts.token_program,
+            metric_history: ctx.accounts.metric_history.as_mut(),
+        },
+    )
+}
+
+/// Contas para a instrução `finalize_pending_report`.
+#[derive(Accounts)]
+pub struct FinalizePendingReport<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, referenciar a entrada de oráculo informada e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = oracle @ ErrorCode::UnauthorizedOracle,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Entrada do oráculo referenciada pela campanha.
+    pub oracle: Account<'info, OracleEntry>,
+    /// Chave atual do oráculo.
+    /// CHECK: Validada contra `oracle.authority`.
+    #[account(address = oracle.authority @ ErrorCode::UnauthorizedOracle)]
+    pub oracle_authority: AccountInfo<'info>,
+    /// Post do relatório pendente (obrigatório quando o relatório veio de `update_post_metrics`).
+    #[account(mut, has_one = campaign @ ErrorCode::HeldPostMismatch)]
+    pub campaign_post: Option<Account<'info, CampaignPost>>,
+    /// Histórico de métricas da campanha (obrigatório quando o histórico está habilitado).
+    #[account(mut, has_one = campaign @ ErrorCode::InvalidMetricHistory)]
+    pub metric_history: Option<Account<'info, MetricHistory>>,
+    /// Cofre USDC da campanha (fonte dos pagamentos).
+    #[account(mut, constraint = campaign_usdc_account.owner == campaign.key() @ ErrorCode::InvalidTokenAccount)]
+    pub campaign_usdc_account: Account<'info, TokenAccount>,
+    /// Conta USDC do influenciador (destino dos pagamentos).
+    #[account(mut, constraint = influencer_usdc_account.owner == campaign.influencer @ ErrorCode::InvalidTokenAccount)]
+    pub influencer_usdc_account: Account<'info, TokenAccount>,
+    /// Conta USDC do oráculo que enviou o relatório (recebe a taxa; obrigatória quando há taxa devida).
+    #[account(mut)]
+    pub oracle_usdc_account: Option<Account<'info, TokenAccount>>,
+    /// Programa SPL Token.
+    pub token_program: Program<'info, Token>,
+}
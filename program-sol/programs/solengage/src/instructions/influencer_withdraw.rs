@@ -0,0 +1,168 @@
+//! # Desistência do Influenciador
+//!
+//! Este módulo define a instrução para o influenciador abandonar uma campanha em andamento.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::errors::ErrorCode;
+use crate::state::{Campaign, CampaignStatus, WithdrawalRecord, BPS_DENOMINATOR};
+
+/// Encerra uma campanha `Active` ou `Paused` por iniciativa do influenciador.
+///
+/// O influenciador mantém o que já foi pago; o orçamento não pago (mais a reserva de
+/// taxas do oráculo não usada) é devolvido à marca e a campanha passa a `Withdrawn`.
+///
+/// Ganhos de um relatório ainda não aplicado (retido pelo circuit breaker ou aguardando
+/// a janela de contestação) são divididos por `forfeit_bps`: essa fração fica com a marca
+/// e o restante é pago ao influenciador na desistência. Um relatório retido ou ainda dentro
+/// da janela de contestação pode ser contestado pela marca, então exige renúncia total
+/// (`forfeit_bps = 10000`) ou resolução/finalização prévia.
+///
+/// O resultado é registrado em uma conta `WithdrawalRecord` (`["withdrawal", campaign]`),
+/// paga pelo influenciador e nunca fechada, para fins de reputação. A campanha pode ser
+/// fechada depois com `close_withdrawal`.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `InfluencerWithdraw`.
+/// * `forfeit_bps` - Fração (em basis points, até 10000) dos ganhos de um relatório ainda
+///   não aplicado que o influenciador renuncia em favor da marca.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `UnauthorizedInfluencer` - Assinante não é o influenciador da campanha.
+/// * `CampaignNotActive` - Campanha não está em `Active` nem em `Paused`.
+/// * `InvalidForfeitBps` - `forfeit_bps` acima de 10000.
+/// * `ReportHeldForReview` - Relatório retido sem renúncia total.
+/// * `ReportPendingChallenge` - Relatório em janela de contestação sem renúncia total.
+/// * `InvalidTokenAccount` - Contas de token não pertencem à campanha/marca/influenciador.
+/// * `MathOverflow` - Overflow aritmético.
+pub fn influencer_withdraw(ctx: Context<InfluencerWithdraw>, forfeit_bps: u16) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let campaign = &mut ctx.accounts.campaign;
+
+    // Validações de segurança
+    require!(
+        matches!(campaign.status, CampaignStatus::Active | CampaignStatus::Paused),
+        ErrorCode::CampaignNotActive
+    );
+    require!(u64::from(forfeit_bps) <= BPS_DENOMINATOR, ErrorCode::InvalidForfeitBps);
+    let full_forfeit = u64::from(forfeit_bps) == BPS_DENOMINATOR;
+    require!(!campaign.report_held || full_forfeit, ErrorCode::ReportHeldForReview);
+    require!(!campaign.challenge_window_open(now) || full_forfeit, ErrorCode::ReportPendingChallenge);
+
+    // Divide os ganhos do relatório não aplicado entre a marca e o influenciador
+    let pending_payout = campaign.pending_payout()?;
+    let forfeited_amount = u64::try_from(u128::from(pending_payout) * u128::from(forfeit_bps) / u128::from(BPS_DENOMINATOR))
+        .map_err(|_| ErrorCode::MathOverflow)?;
+    let kept_amount = pending_payout - forfeited_amount;
+    campaign.paid_amount = campaign.paid_amount.checked_add(kept_amount).ok_or(ErrorCode::MathOverflow)?;
+
+    let unpaid = campaign.amount_usdc.checked_sub(campaign.paid_amount).ok_or(ErrorCode::MathOverflow)?;
+    let refund_amount = unpaid.checked_add(campaign.oracle_fee_reserve).ok_or(ErrorCode::MathOverflow)?;
+
+    let bump = ctx.bumps.campaign;
+    let seeds = &[
+        b"campaign".as_ref(),
+        campaign.influencer.as_ref(),
+        campaign.brand.as_ref(),
+        campaign.name.as_bytes(),
+        &[bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    if kept_amount > 0 {
+        // CPI para pagar ao influenciador a parte não renunciada dos ganhos pendentes
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.campaign_usdc_account.to_account_info(),
+            to: ctx.accounts.influencer_usdc_account.to_account_info(),
+            authority: campaign.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+        token::transfer(cpi_ctx, kept_amount)?;
+    }
+
+    if refund_amount > 0 {
+        // CPI para devolver à marca o orçamento não pago
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.campaign_usdc_account.to_account_info(),
+            to: ctx.accounts.brand_usdc_account.to_account_info(),
+            authority: campaign.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+        token::transfer(cpi_ctx, refund_amount)?;
+    }
+
+    let record = &mut ctx.accounts.withdrawal_record;
+    record.campaign = campaign.key();
+    record.influencer = campaign.influencer;
+    record.brand = campaign.brand;
+    record.withdrawn_at = now;
+    record.progress = campaign.get_progress_percentage() as u8;
+    record.paid_amount = campaign.paid_amount;
+    record.refunded_amount = unpaid;
+    record.forfeited_amount = forfeited_amount;
+    record.bump = ctx.bumps.withdrawal_record;
+
+    campaign.oracle_fee_reserve = 0;
+    campaign.discard_pending_values();
+    campaign.report_held = false;
+    campaign.report_challenged = false;
+    campaign.report_pending_since = 0;
+    campaign.held_at = 0;
+    campaign.held_post = None;
+    campaign.status = CampaignStatus::Withdrawn;
+    campaign.last_updated = now;
+
+    msg!(
+        "Influenciador desistiu: {} devolvidos à marca, {} pendentes pagos, {} renunciados",
+        unpaid,
+        kept_amount,
+        forfeited_amount
+    );
+    Ok(())
+}
+
+/// Contas para a instrução `influencer_withdraw`.
+#[derive(Accounts)]
+pub struct InfluencerWithdraw<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, possuir o influenciador correto e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = influencer @ ErrorCode::UnauthorizedInfluencer,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Registro da desistência a ser inicializado.
+    ///
+    /// PDA derivada de `["withdrawal", campaign]`.
+    #[account(
+        init,
+        payer = influencer,
+        space = 8 + WithdrawalRecord::INIT_SPACE,
+        seeds = [b"withdrawal", campaign.key().as_ref()],
+        bump
+    )]
+    pub withdrawal_record: Account<'info, WithdrawalRecord>,
+    /// Conta do influenciador (assinante e pagador do registro).
+    #[account(mut)]
+    pub influencer: Signer<'info>,
+    /// Conta USDC da marca (destino do reembolso).
+    #[account(mut, constraint = brand_usdc_account.owner == campaign.brand @ ErrorCode::InvalidTokenAccount)]
+    pub brand_usdc_account: Account<'info, TokenAccount>,
+    /// Conta USDC do influenciador (destino da parte não renunciada dos ganhos pendentes).
+    #[account(mut, constraint = influencer_usdc_account.owner == campaign.influencer @ ErrorCode::InvalidTokenAccount)]
+    pub influencer_usdc_account: Account<'info, TokenAccount>,
+    /// Cofre USDC da campanha (fonte do reembolso).
+    #[account(mut, constraint = campaign_usdc_account.owner == campaign.key() @ ErrorCode::InvalidTokenAccount)]
+    pub campaign_usdc_account: Account<'info, TokenAccount>,
+    /// Programa SPL Token.
+    pub token_program: Program<'info, Token>,
+    /// Programa do sistema Solana.
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,75 @@
+//! # Inicializar Histórico de Métricas
+//!
+//! Este módulo define a instrução para a marca habilitar o histórico de relatórios da campanha.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{Campaign, CampaignStatus, MetricHistory};
+
+/// Cria a conta `MetricHistory` da campanha e passa a exigir o registro de cada relatório.
+///
+/// Somente a marca pode chamar, em `Draft` ou `Active`, pagando o rent da conta. A partir
+/// daqui, todo caminho de relatório exige a conta de histórico, na qual cada relatório
+/// aplicado é registrado (buffer circular de `MAX_HISTORY_ENTRIES` entradas).
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `InitializeMetricHistory`.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotActive` - Campanha já encerrada (`Completed` ou `Cancelled`).
+/// * `UnauthorizedBrand` - Assinante não é a marca da campanha.
+pub fn initialize_metric_history(ctx: Context<InitializeMetricHistory>) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    require!(
+        matches!(campaign.status, CampaignStatus::Draft | CampaignStatus::Active),
+        ErrorCode::CampaignNotActive
+    );
+
+    let history = &mut ctx.accounts.metric_history;
+    history.campaign = campaign.key();
+    history.brand = campaign.brand;
+    history.entries = Vec::new();
+    history.head = 0;
+    history.total_recorded = 0;
+    history.bump = ctx.bumps.metric_history;
+
+    campaign.history_enabled = true;
+    campaign.last_updated = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+/// Contas para a instrução `initialize_metric_history`.
+#[derive(Accounts)]
+pub struct InitializeMetricHistory<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, possuir a marca correta e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = brand @ ErrorCode::UnauthorizedBrand,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Conta do histórico a ser inicializada.
+    ///
+    /// PDA derivada de `["metric_history", campaign]`.
+    #[account(
+        init,
+        payer = brand,
+        space = 8 + MetricHistory::INIT_SPACE,
+        seeds = [b"metric_history", campaign.key().as_ref()],
+        bump
+    )]
+    pub metric_history: Account<'info, MetricHistory>,
+    /// Conta da marca (assinante e pagadora).
+    #[account(mut)]
+    pub brand: Signer<'info>,
+    /// Programa do sistema Solana.
+    pub system_program: Program<'info, System>,
+}
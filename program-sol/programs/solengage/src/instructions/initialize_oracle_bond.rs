@@ -0,0 +1,73 @@
+//! # Inicializar Caução do Oráculo
+//!
+//! Este módulo define a instrução que cria a caução e o cofre de tokens de um oráculo.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::errors::ErrorCode;
+use crate::state::{OracleBond, OracleEntry};
+
+/// Cria a caução de um oráculo registrado, denominada no token informado.
+///
+/// Somente a chave atual do oráculo pode criar sua caução. Os tokens são depositados
+/// depois, via `deposit_oracle_bond`.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `InitializeOracleBond`.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `UnauthorizedOracle` - Assinante não é a chave atual do oráculo.
+pub fn initialize_oracle_bond(ctx: Context<InitializeOracleBond>) -> Result<()> {
+    let bond = &mut ctx.accounts.oracle_bond;
+    bond.oracle = ctx.accounts.oracle.key();
+    bond.mint = ctx.accounts.mint.key();
+    bond.vault = ctx.accounts.bond_vault.key();
+    bond.slashed_amount = 0;
+    bond.slash_count = 0;
+    bond.bump = ctx.bumps.oracle_bond;
+    bond.vault_bump = ctx.bumps.bond_vault;
+
+    Ok(())
+}
+
+/// Contas para a instrução `initialize_oracle_bond`.
+#[derive(Accounts)]
+pub struct InitializeOracleBond<'info> {
+    /// Entrada do oráculo no registro global.
+    pub oracle: Account<'info, OracleEntry>,
+    /// Caução a ser inicializada.
+    ///
+    /// PDA derivada de `["oracle_bond", oracle]`.
+    #[account(
+        init,
+        payer = oracle_authority,
+        space = 8 + OracleBond::INIT_SPACE,
+        seeds = [b"oracle_bond", oracle.key().as_ref()],
+        bump
+    )]
+    pub oracle_bond: Account<'info, OracleBond>,
+    /// Cofre de tokens da caução (autoridade = `oracle_bond`).
+    ///
+    /// PDA derivada de `["oracle_bond_vault", oracle]`.
+    #[account(
+        init,
+        payer = oracle_authority,
+        token::mint = mint,
+        token::authority = oracle_bond,
+        seeds = [b"oracle_bond_vault", oracle.key().as_ref()],
+        bump
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+    /// Mint do token caucionado.
+    pub mint: Account<'info, Mint>,
+    /// Chave atual do oráculo (assinante e pagador).
+    #[account(mut, address = oracle.authority @ ErrorCode::UnauthorizedOracle)]
+    pub oracle_authority: Signer<'info>,
+    /// Programa SPL Token.
+    pub token_program: Program<'info, Token>,
+    /// Programa do sistema Solana.
+    pub system_program: Program<'info, System>,
+}
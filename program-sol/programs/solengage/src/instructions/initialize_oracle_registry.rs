@@ -0,0 +1,58 @@
+//! # Inicializar Registro de Oráculos
+//!
+//! Este módulo define a instrução que cria o registro global de oráculos.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::program::Solengage;
+use crate::state::OracleRegistry;
+
+/// Cria o registro global de oráculos e define seu administrador.
+///
+/// Somente a autoridade de upgrade do programa pode inicializar o registro,
+/// evitando que qualquer conta se torne administradora por front-running.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `InitializeOracleRegistry`.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `UnauthorizedAdmin` - Assinante não é a autoridade de upgrade do programa.
+pub fn initialize_oracle_registry(ctx: Context<InitializeOracleRegistry>) -> Result<()> {
+    let registry = &mut ctx.accounts.oracle_registry;
+    registry.admin = ctx.accounts.admin.key();
+    registry.arbiter = ctx.accounts.admin.key();
+    registry.oracle_count = 0;
+    registry.bump = ctx.bumps.oracle_registry;
+
+    Ok(())
+}
+
+/// Contas para a instrução `initialize_oracle_registry`.
+#[derive(Accounts)]
+pub struct InitializeOracleRegistry<'info> {
+    /// Registro de oráculos a ser inicializado.
+    ///
+    /// PDA derivada de `["oracle_registry"]`.
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + OracleRegistry::INIT_SPACE,
+        seeds = [b"oracle_registry"],
+        bump
+    )]
+    pub oracle_registry: Account<'info, OracleRegistry>,
+    /// Administrador do registro (assinante e pagador).
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// Este programa, usado para localizar sua conta `ProgramData`.
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, Solengage>,
+    /// Conta `ProgramData` contendo a autoridade de upgrade.
+    #[account(constraint = program_data.upgrade_authority_address == Some(admin.key()) @ ErrorCode::UnauthorizedAdmin)]
+    pub program_data: Account<'info, ProgramData>,
+    /// Programa do sistema Solana.
+    pub system_program: Program<'info, System>,
+}
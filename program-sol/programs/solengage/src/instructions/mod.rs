@@ -7,9 +7,113 @@ pub mod brand_pay_campaign;
 pub mod update_campaign_metrics;
 pub mod cancel_campaign;
 pub mod close_campaign;
+pub mod set_payout_curve;
+pub mod initialize_oracle_registry;
+pub mod register_oracle;
+pub mod rotate_oracle_key;
+pub mod set_oracle_active;
+pub mod set_oracle_quorum;
+pub mod submit_metric_report;
+pub mod submit_signed_metrics;
+pub mod set_metric_guards;
+pub mod resolve_held_report;
+pub mod set_arbiter;
+pub mod initialize_oracle_bond;
+pub mod deposit_oracle_bond;
+pub mod withdraw_oracle_bond;
+pub mod challenge_oracle;
+pub mod set_oracle_fee;
+pub mod refund_oracle_fees;
+pub mod add_campaign_post;
+pub mod update_post_metrics;
+pub mod set_metric_targets;
+pub mod update_metric_values;
+pub mod initialize_metric_history;
+pub mod close_metric_history;
+pub mod close_campaign_post;
+pub mod close_withdrawal;
+pub mod set_challenge_window;
+pub mod challenge_pending_report;
+pub mod finalize_pending_report;
+pub mod set_metric_feed;
+pub mod apply_feed_metrics;
+pub mod set_oracle_liveness;
+pub mod request_cancellation;
+pub mod open_oracle_fallback;
+pub mod submit_fallback_metrics;
+pub mod create_offer;
+pub mod accept_offer;
+pub mod reject_offer;
+pub mod update_draft;
+pub mod propose_terms;
+pub mod accept_terms;
+pub mod set_pause_policy;
+pub mod pause_campaign;
+pub mod resume_campaign;
+pub mod amend_campaign;
+pub mod open_dispute;
+pub mod resolve_dispute;
+pub mod set_kill_fee;
+pub mod influencer_withdraw;
+pub mod settle_campaign;
+pub mod set_content_review;
+pub mod submit_content;
+pub mod review_content;
 
 pub use create_campaign::*;
 pub use brand_pay_campaign::*;
 pub use update_campaign_metrics::*;
 pub use cancel_campaign::*;
 pub use close_campaign::*;
+pub use set_payout_curve::*;
+pub use initialize_oracle_registry::*;
+pub use register_oracle::*;
+pub use rotate_oracle_key::*;
+pub use set_oracle_active::*;
+pub use set_oracle_quorum::*;
+pub use submit_metric_report::*;
+pub use submit_signed_metrics::*;
+pub use set_metric_guards::*;
+pub use resolve_held_report::*;
+pub use set_arbiter::*;
+pub use initialize_oracle_bond::*;
+pub use deposit_oracle_bond::*;
+pub use withdraw_oracle_bond::*;
+pub use challenge_oracle::*;
+pub use set_oracle_fee::*;
+pub use refund_oracle_fees::*;
+pub use add_campaign_post::*;
+pub use update_post_metrics::*;
+pub use set_metric_targets::*;
+pub use update_metric_values::*;
+pub use initialize_metric_history::*;
+pub use close_metric_history::*;
+pub use close_campaign_post::*;
+pub use close_withdrawal::*;
+pub use set_challenge_window::*;
+pub use challenge_pending_report::*;
+pub use finalize_pending_report::*;
+pub use set_metric_feed::*;
+pub use apply_feed_metrics::*;
+pub use set_oracle_liveness::*;
+pub use request_cancellation::*;
+pub use open_oracle_fallback::*;
+pub use submit_fallback_metrics::*;
+pub use create_offer::*;
+pub use accept_offer::*;
+pub use reject_offer::*;
+pub use update_draft::*;
+pub use propose_terms::*;
+pub use accept_terms::*;
+pub use set_pause_policy::*;
+pub use pause_campaign::*;
+pub use resume_campaign::*;
+pub use amend_campaign::*;
+pub use open_dispute::*;
+pub use resolve_dispute::*;
+pub use set_kill_fee::*;
+pub use influencer_withdraw::*;
+pub use settle_campaign::*;
+pub use set_content_review::*;
+pub use submit_content::*;
+pub use review_content::*;
@@ -0,0 +1,61 @@
+//! # Abrir Disputa
+//!
+//! Este módulo define a instrução para a marca ou o influenciador congelarem uma campanha em disputa.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{Campaign, CampaignStatus};
+
+/// Coloca uma campanha `Active` ou `Paused` em `Disputed`.
+///
+/// Qualquer uma das partes pode abrir a disputa. Enquanto a campanha está `Disputed`,
+/// atualizações de métricas (e portanto pagamentos) e o cancelamento pela marca são
+/// rejeitados. A disputa só termina com `resolve_dispute`, em que o árbitro do registro
+/// de oráculos decide como dividir o saldo restante do cofre.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `OpenDispute`.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `UnauthorizedParty` - Assinante não é a marca nem o influenciador da campanha.
+/// * `CampaignNotActive` - Campanha não está em `Active` nem em `Paused`.
+pub fn open_dispute(ctx: Context<OpenDispute>) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    let party = ctx.accounts.party.key();
+
+    // Validações de segurança
+    require!(party == campaign.influencer || party == campaign.brand, ErrorCode::UnauthorizedParty);
+    require!(
+        matches!(campaign.status, CampaignStatus::Active | CampaignStatus::Paused),
+        ErrorCode::CampaignNotActive
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    campaign.status = CampaignStatus::Disputed;
+    campaign.disputed_by = Some(party);
+    campaign.dispute_opened_at = now;
+    campaign.last_updated = now;
+
+    msg!("Disputa aberta por {}", party);
+    Ok(())
+}
+
+/// Contas para a instrução `open_dispute`.
+#[derive(Accounts)]
+pub struct OpenDispute<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Parte que abre a disputa (assinante): marca ou influenciador.
+    pub party: Signer<'info>,
+}
@@ -0,0 +1,58 @@
+//! # Abrir Fallback do Oráculo
+//!
+//! Este módulo define a instrução para o influenciador abrir o caminho alternativo de métricas.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{Campaign, CampaignStatus};
+
+/// Abre o fallback do oráculo, permitindo métricas co-assinadas via `submit_fallback_metrics`.
+///
+/// Somente o influenciador pode chamar, quando o oráculo perdeu o heartbeat (nenhum
+/// relatório do oráculo há `oracle_heartbeat` segundos) ou a marca deu aviso prévio de
+/// cancelamento. O fallback permanece aberto até que o oráculo volte a reportar.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `OpenOracleFallback`.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotActive` - Campanha não está em `Active`.
+/// * `UnauthorizedInfluencer` - Assinante não é o influenciador da campanha.
+/// * `OracleStillLive` - Oráculo dentro do heartbeat e sem aviso de cancelamento.
+pub fn open_oracle_fallback(ctx: Context<OpenOracleFallback>) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Validações de segurança
+    require_eq!(campaign.status, CampaignStatus::Active, ErrorCode::CampaignNotActive);
+    require!(campaign.fallback_opened_at > 0 || campaign.fallback_available(now), ErrorCode::OracleStillLive);
+
+    if campaign.fallback_opened_at == 0 {
+        campaign.fallback_opened_at = now;
+        campaign.last_updated = now;
+        msg!("Fallback do oráculo aberto pelo influenciador");
+    }
+
+    Ok(())
+}
+
+/// Contas para a instrução `open_oracle_fallback`.
+#[derive(Accounts)]
+pub struct OpenOracleFallback<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, possuir o influenciador correto e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = influencer @ ErrorCode::UnauthorizedInfluencer,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Conta do influenciador (assinante).
+    pub influencer: Signer<'info>,
+}
@@ -0,0 +1,58 @@
+//! # Pausar Campanha
+//!
+//! Este módulo define a instrução para a marca suspender temporariamente uma campanha ativa.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{Campaign, CampaignStatus};
+
+/// Pausa uma campanha `Active`.
+///
+/// Somente a marca pode chamar, e apenas se ainda houver tempo de pausa disponível
+/// (`max_pause_duration` menos o tempo já pausado). Enquanto a campanha está `Paused`,
+/// atualizações de métricas são rejeitadas. O tempo pausado é somado ao prazo em
+/// `resume_campaign`, limitado ao tempo de pausa disponível.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `PauseCampaign`.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotActive` - Campanha não está em `Active`.
+/// * `UnauthorizedBrand` - Assinante não é a marca da campanha.
+/// * `PauseNotAllowed` - Campanha sem pausa permitida ou com o tempo de pausa esgotado.
+pub fn pause_campaign(ctx: Context<PauseCampaign>) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    // Validações de segurança
+    require_eq!(campaign.status, CampaignStatus::Active, ErrorCode::CampaignNotActive);
+    require!(campaign.remaining_pause() > 0, ErrorCode::PauseNotAllowed);
+
+    let now = Clock::get()?.unix_timestamp;
+    campaign.status = CampaignStatus::Paused;
+    campaign.paused_at = now;
+    campaign.last_updated = now;
+
+    msg!("Campanha pausada; tempo de pausa disponível: {}s", campaign.remaining_pause());
+    Ok(())
+}
+
+/// Contas para a instrução `pause_campaign`.
+#[derive(Accounts)]
+pub struct PauseCampaign<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, possuir a marca correta e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = brand @ ErrorCode::UnauthorizedBrand,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Conta da marca (assinante).
+    pub brand: Signer<'info>,
+}
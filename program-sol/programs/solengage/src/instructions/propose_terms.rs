@@ -0,0 +1,82 @@
+//! # Propor Termos
+//!
+//! Este módulo define a instrução para uma das partes propor novos termos a uma campanha em rascunho.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{Campaign, CampaignStatus, MetricValues, TermsProposal};
+use super::create_campaign::validate_campaign_terms;
+
+/// Registra uma proposta de termos (metas, orçamento e prazo) para a outra parte aceitar.
+///
+/// O influenciador ou a marca podem chamar, apenas enquanto a campanha está em `Draft`.
+/// Uma nova proposta (contraproposta) substitui a pendente. Cada proposta incrementa a
+/// versão dos termos e a carrega consigo; até o aceite via `accept_terms`, a campanha não
+/// pode ser financiada. Os valores passam pelas mesmas validações de `create_campaign`.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `ProposeTerms`.
+/// * `targets` - Metas gerais propostas (likes, comentários, visualizações, compartilhamentos).
+/// * `amount_usdc` - Orçamento proposto em USDC (6 decimais).
+/// * `deadline` - Prazo proposto (timestamp Unix).
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotDraft` - Campanha não está em `Draft`.
+/// * `UnauthorizedParty` - Assinante não é o influenciador nem a marca da campanha.
+/// * `InvalidAmount` - `amount_usdc` igual a zero.
+/// * `InvalidDeadline` - `deadline` no passado.
+/// * `NoTargetsSet` - Todas as metas iguais a zero.
+pub fn propose_terms(ctx: Context<ProposeTerms>, targets: MetricValues, amount_usdc: u64, deadline: i64) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    // Validações de segurança
+    require_eq!(campaign.status, CampaignStatus::Draft, ErrorCode::CampaignNotDraft);
+    validate_campaign_terms(
+        &campaign.name,
+        &campaign.nickname,
+        &campaign.brand_name,
+        &campaign.hashtag,
+        targets.likes,
+        targets.comments,
+        targets.views,
+        targets.shares,
+        amount_usdc,
+        deadline,
+    )?;
+
+    campaign.bump_terms_version();
+    campaign.pending_terms = Some(TermsProposal {
+        proposer: ctx.accounts.proposer.key(),
+        version: campaign.terms_version,
+        targets,
+        amount_usdc,
+        deadline,
+    });
+    campaign.last_updated = Clock::get()?.unix_timestamp;
+
+    msg!("Proposta de termos registrada: versão {}", campaign.terms_version);
+    Ok(())
+}
+
+/// Contas para a instrução `propose_terms`.
+#[derive(Accounts)]
+pub struct ProposeTerms<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Influenciador ou marca da campanha (assinante).
+    #[account(
+        constraint = proposer.key() == campaign.influencer || proposer.key() == campaign.brand @ ErrorCode::UnauthorizedParty
+    )]
+    pub proposer: Signer<'info>,
+}
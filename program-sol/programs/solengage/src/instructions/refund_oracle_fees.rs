@@ -0,0 +1,86 @@
+//! # Reembolsar Reserva de Taxas do Oráculo
+//!
+//! Este módulo define a instrução para a marca recuperar a reserva de taxas não usada de uma campanha concluída.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::errors::ErrorCode;
+use crate::state::{Campaign, CampaignStatus};
+
+/// Reembolsa à marca a reserva de taxas do oráculo que não foi usada.
+///
+/// Campanhas canceladas já reembolsam a reserva em `cancel_campaign`. Campanhas
+/// concluídas com reserva restante permanecem abertas até este reembolso, depois
+/// do qual podem ser fechadas com `close_campaign`.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `RefundOracleFees`.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotInTerminalState` - Campanha não está em `Completed` ou `Cancelled`.
+/// * `UnauthorizedBrand` - Assinante não é a marca da campanha.
+/// * `InvalidTokenAccount` - Contas de token não pertencem à campanha/marca.
+pub fn refund_oracle_fees(ctx: Context<RefundOracleFees>) -> Result<()> {
+    let status = ctx.accounts.campaign.status;
+    require!(
+        status == CampaignStatus::Completed || status == CampaignStatus::Cancelled,
+        ErrorCode::CampaignNotInTerminalState
+    );
+
+    let refund_amount = ctx.accounts.campaign.oracle_fee_reserve;
+    if refund_amount > 0 {
+        let bump = ctx.bumps.campaign;
+        let seeds = &[
+            b"campaign".as_ref(),
+            ctx.accounts.campaign.influencer.as_ref(),
+            ctx.accounts.campaign.brand.as_ref(),
+            ctx.accounts.campaign.name.as_bytes(),
+            &[bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        // CPI para devolver a reserva restante do cofre da campanha à marca
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.campaign_usdc_account.to_account_info(),
+            to: ctx.accounts.brand_usdc_account.to_account_info(),
+            authority: ctx.accounts.campaign.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::transfer(cpi_ctx, refund_amount)?;
+    }
+
+    ctx.accounts.campaign.oracle_fee_reserve = 0;
+    ctx.accounts.campaign.last_updated = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+/// Contas para a instrução `refund_oracle_fees`.
+#[derive(Accounts)]
+pub struct RefundOracleFees<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, possuir a marca correta e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = brand @ ErrorCode::UnauthorizedBrand,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Conta da marca (assinante).
+    pub brand: Signer<'info>,
+    /// Conta de token USDC da marca (destino do reembolso).
+    #[account(mut, constraint = brand_usdc_account.owner == brand.key() @ ErrorCode::InvalidTokenAccount)]
+    pub brand_usdc_account: Account<'info, TokenAccount>,
+    /// Conta de token USDC do cofre da campanha (fonte do reembolso).
+    #[account(mut, constraint = campaign_usdc_account.owner == campaign.key() @ ErrorCode::InvalidTokenAccount)]
+    pub campaign_usdc_account: Account<'info, TokenAccount>,
+    /// Programa SPL Token.
+    pub token_program: Program<'info, Token>,
+}
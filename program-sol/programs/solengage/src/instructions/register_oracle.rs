@@ -0,0 +1,69 @@
+//! # Registrar Oráculo
+//!
+//! Este módulo define a instrução para adicionar um oráculo ao registro global.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{OracleEntry, OracleRegistry};
+
+/// Registra um novo oráculo com a chave de assinatura informada.
+///
+/// A entrada recebe o próximo identificador sequencial do registro e nasce ativa.
+/// Campanhas passam a referenciar o endereço da entrada, não a chave em si.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `RegisterOracle`.
+/// * `authority` - Chave que assinará os relatórios de métricas.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `UnauthorizedAdmin` - Assinante não é o administrador do registro.
+/// * `MathOverflow` - Overflow no contador de oráculos.
+pub fn register_oracle(ctx: Context<RegisterOracle>, authority: Pubkey) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let registry = &mut ctx.accounts.oracle_registry;
+
+    let entry = &mut ctx.accounts.oracle_entry;
+    entry.id = registry.oracle_count;
+    entry.authority = authority;
+    entry.active = true;
+    entry.registered_at = now;
+    entry.rotated_at = now;
+    entry.deactivated_at = 0;
+    entry.bump = ctx.bumps.oracle_entry;
+
+    registry.oracle_count = registry.oracle_count.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+
+    Ok(())
+}
+
+/// Contas para a instrução `register_oracle`.
+#[derive(Accounts)]
+pub struct RegisterOracle<'info> {
+    /// Registro global de oráculos.
+    #[account(
+        mut,
+        has_one = admin @ ErrorCode::UnauthorizedAdmin,
+        seeds = [b"oracle_registry"],
+        bump = oracle_registry.bump
+    )]
+    pub oracle_registry: Account<'info, OracleRegistry>,
+    /// Entrada do oráculo a ser inicializada.
+    ///
+    /// PDA derivada de `["oracle", oracle_registry.oracle_count]`.
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + OracleEntry::INIT_SPACE,
+        seeds = [b"oracle", oracle_registry.oracle_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub oracle_entry: Account<'info, OracleEntry>,
+    /// Administrador do registro (assinante e pagador).
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// Programa do sistema Solana.
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,87 @@
+//! # Recusar Oferta
+//!
+//! Este módulo define a instrução para o influenciador recusar uma oferta da marca.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::errors::ErrorCode;
+use crate::state::{Campaign, CampaignStatus};
+
+/// Recusa uma campanha em `Offered`, reembolsa a marca e fecha a conta.
+///
+/// Somente o influenciador pode chamar. Se a marca depositou o orçamento na oferta,
+/// `amount_usdc` é devolvido do cofre da campanha para a conta USDC da marca.
+/// O aluguel da conta da campanha volta para a marca, que a pagou.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `RejectOffer`.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotOffered` - Campanha não está em `Offered`.
+/// * `UnauthorizedInfluencer` - Assinante não é o influenciador da campanha.
+/// * `UnauthorizedBrand` - Conta da marca não corresponde à marca da campanha.
+/// * `InvalidTokenAccount` - Conta USDC de destino não pertence à marca.
+pub fn reject_offer(ctx: Context<RejectOffer>) -> Result<()> {
+    let campaign = &ctx.accounts.campaign;
+
+    // Validações de segurança
+    require_eq!(campaign.status, CampaignStatus::Offered, ErrorCode::CampaignNotOffered);
+
+    if campaign.offer_escrowed {
+        let bump = ctx.bumps.campaign;
+        let seeds = &[
+            b"campaign".as_ref(),
+            campaign.influencer.as_ref(),
+            campaign.brand.as_ref(),
+            campaign.name.as_bytes(),
+            &[bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        // CPI para devolver o depósito do cofre da campanha para a marca
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.campaign_usdc_account.to_account_info(),
+            to: ctx.accounts.brand_usdc_account.to_account_info(),
+            authority: campaign.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+        token::transfer(cpi_ctx, campaign.amount_usdc)?;
+    }
+
+    msg!("Oferta recusada pelo influenciador: conta da campanha fechada");
+    Ok(())
+}
+
+/// Contas para a instrução `reject_offer`.
+#[derive(Accounts)]
+pub struct RejectOffer<'info> {
+    /// Conta da campanha (fechada ao final, aluguel devolvido à marca).
+    ///
+    /// Deve possuir o influenciador e a marca corretos e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        close = brand,
+        has_one = influencer @ ErrorCode::UnauthorizedInfluencer,
+        has_one = brand @ ErrorCode::UnauthorizedBrand,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Conta do influenciador (assinante).
+    pub influencer: Signer<'info>,
+    /// Conta da marca (recebe o aluguel da campanha).
+    #[account(mut)]
+    pub brand: SystemAccount<'info>,
+    /// Conta de token USDC da marca (destino do reembolso).
+    #[account(mut, constraint = brand_usdc_account.owner == brand.key() @ ErrorCode::InvalidTokenAccount)]
+    pub brand_usdc_account: Account<'info, TokenAccount>,
+    /// Conta de token USDC do cofre da campanha (fonte do reembolso).
+    #[account(mut)]
+    pub campaign_usdc_account: Account<'info, TokenAccount>,
+    /// Programa SPL Token.
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,59 @@
+//! # Solicitar Cancelamento
+//!
+//! Este módulo define a instrução para a marca dar aviso prévio de cancelamento de uma campanha ativa.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{Campaign, CampaignStatus};
+
+/// Inicia o prazo de aviso prévio antes do cancelamento de uma campanha ativa.
+///
+/// Somente a marca pode chamar. Depois de `cancel_notice_period` segundos a marca pode
+/// chamar `cancel_campaign`; enquanto isso o influenciador pode abrir o fallback do
+/// oráculo e liquidar os marcos já conquistados.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `RequestCancellation`.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotActive` - Campanha não está em `Active` nem `Paused`.
+/// * `UnauthorizedBrand` - Assinante não é a marca da campanha.
+/// * `CancelAlreadyRequested` - O aviso prévio já foi dado.
+pub fn request_cancellation(ctx: Context<RequestCancellation>) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    // Validações de segurança
+    require!(
+        matches!(campaign.status, CampaignStatus::Active | CampaignStatus::Paused),
+        ErrorCode::CampaignNotActive
+    );
+    require_eq!(campaign.cancel_requested_at, 0, ErrorCode::CancelAlreadyRequested);
+
+    let now = Clock::get()?.unix_timestamp;
+    campaign.cancel_requested_at = now;
+    campaign.last_updated = now;
+
+    msg!("Cancelamento solicitado; permitido a partir de {}", now.saturating_add(campaign.cancel_notice_period));
+    Ok(())
+}
+
+/// Contas para a instrução `request_cancellation`.
+#[derive(Accounts)]
+pub struct RequestCancellation<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, possuir a marca correta e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = brand @ ErrorCode::UnauthorizedBrand,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Conta da marca (assinante).
+    pub brand: Signer<'info>,
+}
@@ -0,0 +1,123 @@
+//! # Resolver Disputa
+//!
+//! Este módulo define a instrução para o árbitro dividir o saldo de uma campanha em disputa.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::errors::ErrorCode;
+use crate::state::{Campaign, CampaignStatus, OracleRegistry, BPS_DENOMINATOR};
+
+/// Encerra uma disputa dividindo o orçamento ainda não pago entre as partes.
+///
+/// Somente o árbitro do registro de oráculos pode chamar. Do saldo restante
+/// (`amount_usdc - paid_amount`), `influencer_bps` / 10000 vai para o influenciador e o
+/// restante volta para a marca, junto com a reserva de taxas do oráculo ainda não usada.
+/// Relatórios pendentes ou retidos são descartados e a campanha passa a `Cancelled`
+/// (a conta pode então ter as taxas e o histórico fechados como em um cancelamento).
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `ResolveDispute`.
+/// * `influencer_bps` - Parcela do saldo restante devida ao influenciador, em basis points.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `UnauthorizedArbiter` - Assinante não é o árbitro do registro.
+/// * `CampaignNotDisputed` - Campanha não está em `Disputed`.
+/// * `InvalidDisputeSplit` - `influencer_bps` acima de 10000.
+/// * `InvalidTokenAccount` - Contas de token não pertencem à campanha/marca/influenciador.
+/// * `MathOverflow` - Overflow aritmético.
+pub fn resolve_dispute(ctx: Context<ResolveDispute>, influencer_bps: u16) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    // Validações de segurança
+    require_keys_eq!(ctx.accounts.arbiter.key(), ctx.accounts.oracle_registry.arbiter, ErrorCode::UnauthorizedArbiter);
+    require_eq!(campaign.status, CampaignStatus::Disputed, ErrorCode::CampaignNotDisputed);
+    require!(u64::from(influencer_bps) <= BPS_DENOMINATOR, ErrorCode::InvalidDisputeSplit);
+
+    let remaining = campaign.amount_usdc.checked_sub(campaign.paid_amount).ok_or(ErrorCode::MathOverflow)?;
+    let influencer_share = u64::try_from(
+        u128::from(remaining) * u128::from(influencer_bps) / u128::from(BPS_DENOMINATOR)
+    ).map_err(|_| ErrorCode::MathOverflow)?;
+    let brand_share = (remaining - influencer_share)
+        .checked_add(campaign.oracle_fee_reserve)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let bump = ctx.bumps.campaign;
+    let seeds = &[
+        b"campaign".as_ref(),
+        campaign.influencer.as_ref(),
+        campaign.brand.as_ref(),
+        campaign.name.as_bytes(),
+        &[bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    if influencer_share > 0 {
+        // CPI para transferir a parcela do influenciador a partir do cofre da campanha
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.campaign_usdc_account.to_account_info(),
+            to: ctx.accounts.influencer_usdc_account.to_account_info(),
+            authority: campaign.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+        token::transfer(cpi_ctx, influencer_share)?;
+    }
+
+    if brand_share > 0 {
+        // CPI para devolver à marca o restante do cofre
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.campaign_usdc_account.to_account_info(),
+            to: ctx.accounts.brand_usdc_account.to_account_info(),
+            authority: campaign.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+        token::transfer(cpi_ctx, brand_share)?;
+    }
+
+    campaign.paid_amount = campaign.paid_amount.checked_add(influencer_share).ok_or(ErrorCode::MathOverflow)?;
+    campaign.oracle_fee_reserve = 0;
+    campaign.discard_pending_values();
+    campaign.report_held = false;
+    campaign.report_challenged = false;
+    campaign.held_at = 0;
+    campaign.held_post = None;
+    campaign.report_pending_since = 0;
+    campaign.status = CampaignStatus::Cancelled;
+    campaign.last_updated = Clock::get()?.unix_timestamp;
+
+    msg!("Disputa resolvida: {} ao influenciador, {} à marca", influencer_share, brand_share);
+    Ok(())
+}
+
+/// Contas para a instrução `resolve_dispute`.
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Registro global de oráculos (fornece o árbitro autorizado a resolver disputas).
+    #[account(seeds = [b"oracle_registry"], bump = oracle_registry.bump)]
+    pub oracle_registry: Account<'info, OracleRegistry>,
+    /// Árbitro do registro (assinante).
+    pub arbiter: Signer<'info>,
+    /// Conta USDC da marca (destino do restante do cofre).
+    #[account(mut, constraint = brand_usdc_account.owner == campaign.brand @ ErrorCode::InvalidTokenAccount)]
+    pub brand_usdc_account: Account<'info, TokenAccount>,
+    /// Conta USDC do influenciador (destino da parcela decidida pelo árbitro).
+    #[account(mut, constraint = influencer_usdc_account.owner == campaign.influencer @ ErrorCode::InvalidTokenAccount)]
+    pub influencer_usdc_account: Account<'info, TokenAccount>,
+    /// Cofre USDC da campanha (fonte da divisão).
+    #[account(mut, constraint = campaign_usdc_account.owner == campaign.key() @ ErrorCode::InvalidTokenAccount)]
+    pub campaign_usdc_account: Account<'info, TokenAccount>,
+    /// Programa SPL Token.
+    pub token_program: Program<'info, Token>,
+}
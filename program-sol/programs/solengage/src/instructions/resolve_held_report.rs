@@ -0,0 +1,134 @@
+//! # Resolver Relatório Retido
+//!
+//! Este módulo define a instrução para a marca ou o admin revisar um relatório retido pelo circuit breaker.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::errors::ErrorCode;
+use crate::instructions::update_campaign_metrics::{apply_metrics, ReportAccounts};
+use crate::state::{Campaign, CampaignPost, CampaignStatus, MetricHistory, OracleEntry, OracleRegistry};
+
+/// Aprova ou descarta o relatório retido pelo circuit breaker.
+///
+/// Somente a marca da campanha ou o admin do registro de oráculos podem chamar; um
+/// relatório contestado pela marca em `challenge_pending_report` só pode ser resolvido
+/// pelo árbitro do registro.
+/// Ao aprovar, os valores retidos são aplicados pela mesma lógica de progresso e
+/// pagamento de `update_campaign_metrics` e a taxa do oráculo que enviou o relatório é
+/// paga; ao descartar, a campanha continua com as métricas anteriores e nenhuma taxa é
+/// cobrada. Em ambos os casos o oráculo volta a poder enviar relatórios.
+/// Se o relatório retido veio de `update_post_metrics`, o post correspondente deve ser
+/// informado e recebe (ou descarta) as métricas retidas junto com a campanha.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `ResolveHeldReport`.
+/// * `approve` - `true` aplica o relatório retido, `false` o descarta.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotActive` - Campanha não está em `Active`.
+/// * `NoHeldReport` - Não há relatório retido.
+/// * `UnauthorizedReviewer` - Assinante não é a marca nem o admin do registro.
+/// * `UnauthorizedArbiter` - Relatório contestado e assinante não é o árbitro do registro.
+/// * `HeldPostMismatch` - Post ausente ou diferente do post do relatório retido.
+/// * `OracleFeeAccountRequired` - Taxa devida sem conta USDC do oráculo informada.
+/// * `InvalidTokenAccount` - Contas de token não pertencem à campanha/influenciador/oráculo.
+/// * `MetricHistoryRequired` - Campanha registra histórico e a conta não foi informada.
+/// * `MathOverflow` - Overflow aritmético durante cálculos de pagamento.
+pub fn resolve_held_report(ctx: Context<ResolveHeldReport>, approve: bool) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    // Validações de segurança
+    require_eq!(campaign.status, CampaignStatus::Active, ErrorCode::CampaignNotActive);
+    let reviewer = ctx.accounts.reviewer.key();
+    require!(campaign.report_held, ErrorCode::NoHeldReport);
+    if campaign.report_challenged {
+        require_keys_eq!(reviewer, ctx.accounts.oracle_registry.arbiter, ErrorCode::UnauthorizedArbiter);
+    } else {
+        require!(
+            reviewer == campaign.brand || reviewer == ctx.accounts.oracle_registry.admin,
+            ErrorCode::UnauthorizedReviewer
+        );
+    }
+    campaign.report_held = false;
+    campaign.report_challenged = false;
+    let held_observed_at = campaign.held_at;
+    campaign.held_at = 0;
+
+    // Relatório de post: o post acompanha a decisão sobre o agregado
+    if let Some(held_post) = campaign.held_post.take() {
+        let post = ctx.accounts.campaign_post.as_mut().ok_or(ErrorCode::HeldPostMismatch)?;
+        require_keys_eq!(post.key(), held_post, ErrorCode::HeldPostMismatch);
+        let post_values = post.held_metrics.take().ok_or(ErrorCode::HeldPostMismatch)?;
+        if approve {
+            post.metrics = post_values;
+        }
+    }
+
+    if !approve {
+        campaign.discard_pending_values();
+        msg!("Relatório retido descartado por {}", reviewer);
+        campaign.last_updated = Clock::get()?.unix_timestamp;
+        return Ok(());
+    }
+
+    msg!("Relatório retido aprovado por {}", reviewer);
+    campaign.last_observed_at = held_observed_at;
+    apply_metrics(
+        campaign,
+        ReportAccounts {
+            campaign_bump: ctx.bumps.campaign,
+            campaign_usdc_account: &ctx.accounts.campaign_usdc_account,
+            influencer_usdc_account: &ctx.accounts.influencer_usdc_account,
+            oracle_usdc_account: ctx.accounts.oracle_usdc_account.as_ref(),
+            token_program: &ctx.accounts.token_program,
+            metric_history: ctx.accounts.metric_history.as_mut(),
+        },
+    )
+}
+
+/// Contas para a instrução `resolve_held_report`.
+#[derive(Accounts)]
+pub struct ResolveHeldReport<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, referenciar a entrada de oráculo informada e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = oracle @ ErrorCode::UnauthorizedOracle,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Registro global de oráculos (fornece o admin e o árbitro autorizados a revisar).
+    #[account(seeds = [b"oracle_registry"], bump = oracle_registry.bump)]
+    pub oracle_registry: Account<'info, OracleRegistry>,
+    /// Entrada do oráculo referenciada pela campanha.
+    pub oracle: Account<'info, OracleEntry>,
+    /// Chave atual do oráculo.
+    /// CHECK: Validada contra `oracle.authority`.
+    #[account(address = oracle.authority @ ErrorCode::UnauthorizedOracle)]
+    pub oracle_authority: AccountInfo<'info>,
+    /// Revisor (assinante): marca ou admin do registro, ou o árbitro se o relatório foi contestado.
+    pub reviewer: Signer<'info>,
+    /// Post do relatório retido (obrigatório quando o relatório veio de `update_post_metrics`).
+    #[account(mut, has_one = campaign @ ErrorCode::HeldPostMismatch)]
+    pub campaign_post: Option<Account<'info, CampaignPost>>,
+    /// Histórico de métricas da campanha (obrigatório quando o histórico está habilitado).
+    #[account(mut, has_one = campaign @ ErrorCode::InvalidMetricHistory)]
+    pub metric_history: Option<Account<'info, MetricHistory>>,
+    /// Cofre USDC da campanha (fonte dos pagamentos).
+    #[account(mut, constraint = campaign_usdc_account.owner == campaign.key() @ ErrorCode::InvalidTokenAccount)]
+    pub campaign_usdc_account: Account<'info, TokenAccount>,
+    /// Conta USDC do influenciador (destino dos pagamentos).
+    #[account(mut, constraint = influencer_usdc_account.owner == campaign.influencer @ ErrorCode::InvalidTokenAccount)]
+    pub influencer_usdc_account: Account<'info, TokenAccount>,
+    /// Conta USDC do oráculo que enviou o relatório (recebe a taxa; obrigatória quando há taxa devida).
+    #[account(mut)]
+    pub oracle_usdc_account: Option<Account<'info, TokenAccount>>,
+    /// Programa SPL Token.
+    pub token_program: Program<'info, Token>,
+}
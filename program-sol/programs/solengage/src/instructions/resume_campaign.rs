@@ -0,0 +1,70 @@
+//! # Retomar Campanha
+//!
+//! Este módulo define a instrução para retomar uma campanha pausada.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{Campaign, CampaignStatus};
+
+/// Retoma uma campanha `Paused`, somando o tempo pausado ao prazo.
+///
+/// A marca pode retomar a qualquer momento. O influenciador só pode retomar depois que
+/// o tempo de pausa disponível se esgotou, para que uma marca ausente não deixe a
+/// campanha parada indefinidamente. O prazo é estendido pelo tempo pausado, limitado ao
+/// tempo de pausa disponível (`max_pause_duration` menos o já usado). O mesmo tempo é
+/// descontado do heartbeat do oráculo, que não pode reportar durante a pausa.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `ResumeCampaign`.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotPaused` - Campanha não está em `Paused`.
+/// * `UnauthorizedParty` - Assinante não é o influenciador nem a marca da campanha.
+/// * `PauseNotExpired` - Influenciador tentou retomar antes de esgotar o tempo de pausa.
+/// * `MathOverflow` - Overflow ao estender o prazo.
+pub fn resume_campaign(ctx: Context<ResumeCampaign>) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Validações de segurança
+    require_eq!(campaign.status, CampaignStatus::Paused, ErrorCode::CampaignNotPaused);
+    let elapsed = now.saturating_sub(campaign.paused_at).max(0);
+    let credited = elapsed.min(campaign.remaining_pause());
+    if ctx.accounts.authority.key() != campaign.brand {
+        require!(elapsed >= campaign.remaining_pause(), ErrorCode::PauseNotExpired);
+    }
+
+    campaign.deadline = campaign.deadline.checked_add(credited).ok_or(ErrorCode::MathOverflow)?;
+    campaign.total_paused = campaign.total_paused.checked_add(credited).ok_or(ErrorCode::MathOverflow)?;
+    // O oráculo não reporta enquanto a campanha está pausada
+    campaign.last_oracle_report_at = campaign.last_oracle_report_at.saturating_add(credited);
+    campaign.paused_at = 0;
+    campaign.status = CampaignStatus::Active;
+    campaign.last_updated = now;
+
+    msg!("Campanha retomada; prazo estendido em {}s", credited);
+    Ok(())
+}
+
+/// Contas para a instrução `resume_campaign`.
+#[derive(Accounts)]
+pub struct ResumeCampaign<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Marca da campanha ou, com o tempo de pausa esgotado, o influenciador (assinante).
+    #[account(
+        constraint = authority.key() == campaign.brand || authority.key() == campaign.influencer @ ErrorCode::UnauthorizedParty
+    )]
+    pub authority: Signer<'info>,
+}
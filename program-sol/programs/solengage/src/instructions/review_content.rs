@@ -0,0 +1,66 @@
+//! # Revisar Conteúdo
+//!
+//! Este módulo define a instrução para a marca aprovar o conteúdo enviado ou pedir alterações.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{Campaign, CampaignStatus, ContentStatus};
+
+/// Aprova o conteúdo enviado ou pede alterações ao influenciador.
+///
+/// Somente a marca pode chamar, enquanto o conteúdo aguarda revisão e dentro do prazo
+/// de revisão contado a partir do envio. Depois do prazo o conteúdo já conta como
+/// aprovado e não pode mais ser recusado. Com a aprovação, as métricas da campanha
+/// passam a ser aceitas; com o pedido de alterações, o influenciador deve enviar de novo.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `ReviewContent`.
+/// * `approve` - `true` aprova o conteúdo, `false` pede alterações.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `UnauthorizedBrand` - Assinante não é a marca da campanha.
+/// * `CampaignNotActive` - Campanha não está em `Active`.
+/// * `NoContentToReview` - Nenhum conteúdo aguardando revisão.
+/// * `ContentReviewWindowClosed` - Prazo de revisão encerrado (conteúdo já aprovado).
+pub fn review_content(ctx: Context<ReviewContent>, approve: bool) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let campaign = &mut ctx.accounts.campaign;
+    let review_window = campaign.content_review_window;
+
+    // Validações de segurança
+    require_eq!(campaign.status, CampaignStatus::Active, ErrorCode::CampaignNotActive);
+    let content = campaign
+        .content
+        .as_mut()
+        .filter(|content| content.status == ContentStatus::Submitted)
+        .ok_or(ErrorCode::NoContentToReview)?;
+    require!(now < content.submitted_at.saturating_add(review_window), ErrorCode::ContentReviewWindowClosed);
+
+    content.status = if approve { ContentStatus::Approved } else { ContentStatus::ChangesRequested };
+    content.reviewed_at = now;
+    campaign.last_updated = now;
+
+    msg!("Conteúdo {}", if approve { "aprovado" } else { "devolvido para alterações" });
+    Ok(())
+}
+
+/// Contas para a instrução `review_content`.
+#[derive(Accounts)]
+pub struct ReviewContent<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, possuir a marca correta e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = brand @ ErrorCode::UnauthorizedBrand,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Conta da marca (assinante).
+    pub brand: Signer<'info>,
+}
@@ -0,0 +1,50 @@
+//! # Rotacionar Chave do Oráculo
+//!
+//! Este módulo define a instrução para substituir a chave de assinatura de um oráculo registrado.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{OracleEntry, OracleRegistry};
+
+/// Substitui a chave de assinatura de um oráculo registrado.
+///
+/// Como as campanhas referenciam a entrada do registro, a nova chave passa a valer
+/// imediatamente para todas as campanhas ativas, e a chave antiga deixa de ser aceita.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `RotateOracleKey`.
+/// * `new_authority` - Nova chave de assinatura do oráculo.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `UnauthorizedAdmin` - Assinante não é o administrador do registro.
+pub fn rotate_oracle_key(ctx: Context<RotateOracleKey>, new_authority: Pubkey) -> Result<()> {
+    let entry = &mut ctx.accounts.oracle_entry;
+    entry.authority = new_authority;
+    entry.rotated_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+/// Contas para a instrução `rotate_oracle_key`.
+#[derive(Accounts)]
+pub struct RotateOracleKey<'info> {
+    /// Registro global de oráculos.
+    #[account(
+        has_one = admin @ ErrorCode::UnauthorizedAdmin,
+        seeds = [b"oracle_registry"],
+        bump = oracle_registry.bump
+    )]
+    pub oracle_registry: Account<'info, OracleRegistry>,
+    /// Entrada do oráculo a ser atualizada.
+    #[account(
+        mut,
+        seeds = [b"oracle", oracle_entry.id.to_le_bytes().as_ref()],
+        bump = oracle_entry.bump
+    )]
+    pub oracle_entry: Account<'info, OracleEntry>,
+    /// Administrador do registro (assinante).
+    pub admin: Signer<'info>,
+}
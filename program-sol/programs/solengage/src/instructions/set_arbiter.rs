@@ -0,0 +1,39 @@
+//! # Definir Árbitro
+//!
+//! Este módulo define a instrução para trocar o árbitro do registro de oráculos.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::OracleRegistry;
+
+/// Define a chave cujas evidências assinadas sustentam contestações contra oráculos.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `SetArbiter`.
+/// * `arbiter` - Nova chave do árbitro.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `UnauthorizedAdmin` - Assinante não é o administrador do registro.
+pub fn set_arbiter(ctx: Context<SetArbiter>, arbiter: Pubkey) -> Result<()> {
+    ctx.accounts.oracle_registry.arbiter = arbiter;
+
+    Ok(())
+}
+
+/// Contas para a instrução `set_arbiter`.
+#[derive(Accounts)]
+pub struct SetArbiter<'info> {
+    /// Registro global de oráculos.
+    #[account(
+        mut,
+        has_one = admin @ ErrorCode::UnauthorizedAdmin,
+        seeds = [b"oracle_registry"],
+        bump = oracle_registry.bump
+    )]
+    pub oracle_registry: Account<'info, OracleRegistry>,
+    /// Administrador do registro (assinante).
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,61 @@
+//! # Definir Janela de Contestação
+//!
+//! Este módulo define a instrução para habilitar o modo otimista de relatórios em uma campanha em rascunho.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{Campaign, CampaignStatus, MAX_CHALLENGE_WINDOW};
+
+/// Define por quantos segundos cada relatório fica aberto à contestação da marca.
+///
+/// Com janela maior que zero, relatórios do oráculo não pagam marcos imediatamente:
+/// ficam pendentes até `finalize_pending_report`, e a marca pode contestá-los via
+/// `challenge_pending_report` enquanto a janela estiver aberta. Zero desabilita o modo otimista.
+///
+/// Somente o influenciador pode chamar, e apenas enquanto a campanha está em `Draft`.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `SetChallengeWindow`.
+/// * `challenge_window` - Duração da janela em segundos (0 a `MAX_CHALLENGE_WINDOW`).
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotDraft` - Campanha não está em `Draft`.
+/// * `UnauthorizedInfluencer` - Assinante não é o influenciador da campanha.
+/// * `InvalidChallengeWindow` - Janela negativa ou maior que `MAX_CHALLENGE_WINDOW`.
+pub fn set_challenge_window(ctx: Context<SetChallengeWindow>, challenge_window: i64) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    // Validações de segurança
+    require_eq!(campaign.status, CampaignStatus::Draft, ErrorCode::CampaignNotDraft);
+    require!(
+        (0..=MAX_CHALLENGE_WINDOW).contains(&challenge_window),
+        ErrorCode::InvalidChallengeWindow
+    );
+
+    campaign.challenge_window = challenge_window;
+    campaign.bump_terms_version();
+    campaign.last_updated = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+/// Contas para a instrução `set_challenge_window`.
+#[derive(Accounts)]
+pub struct SetChallengeWindow<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, possuir o influenciador correto e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = influencer @ ErrorCode::UnauthorizedInfluencer,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Conta do influenciador (assinante).
+    pub influencer: Signer<'info>,
+}
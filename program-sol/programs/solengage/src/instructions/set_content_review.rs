@@ -0,0 +1,63 @@
+//! # Definir Revisão de Conteúdo
+//!
+//! Este módulo define a instrução para configurar a etapa de aprovação do conteúdo pela marca.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{Campaign, CampaignStatus, MAX_CONTENT_REVIEW_WINDOW};
+
+/// Define o prazo que a marca tem para revisar o conteúdo enviado pelo influenciador.
+///
+/// Com `review_window > 0`, depois da ativação o influenciador envia o conteúdo via
+/// `submit_content` e a marca o aprova ou pede alterações via `review_content`; as
+/// métricas só são aceitas depois da aprovação. Conteúdo não revisado dentro do prazo
+/// é considerado aprovado. Como os demais termos, o valor é acordado em `Draft`: o
+/// influenciador o define e a marca o aceita ao financiar a versão correspondente.
+///
+/// Somente o influenciador pode chamar, e apenas enquanto a campanha está em `Draft`.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `SetContentReview`.
+/// * `review_window` - Prazo de revisão em segundos (0 = sem etapa de conteúdo).
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotDraft` - Campanha não está em `Draft`.
+/// * `UnauthorizedInfluencer` - Assinante não é o influenciador da campanha.
+/// * `InvalidContentReviewWindow` - Prazo negativo ou maior que `MAX_CONTENT_REVIEW_WINDOW`.
+pub fn set_content_review(ctx: Context<SetContentReview>, review_window: i64) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    // Validações de segurança
+    require_eq!(campaign.status, CampaignStatus::Draft, ErrorCode::CampaignNotDraft);
+    require!(
+        (0..=MAX_CONTENT_REVIEW_WINDOW).contains(&review_window),
+        ErrorCode::InvalidContentReviewWindow
+    );
+
+    campaign.content_review_window = review_window;
+    campaign.bump_terms_version();
+    campaign.last_updated = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+/// Contas para a instrução `set_content_review`.
+#[derive(Accounts)]
+pub struct SetContentReview<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, possuir o influenciador correto e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = influencer @ ErrorCode::UnauthorizedInfluencer,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Conta do influenciador (assinante).
+    pub influencer: Signer<'info>,
+}
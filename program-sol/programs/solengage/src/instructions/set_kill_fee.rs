@@ -0,0 +1,65 @@
+//! # Definir Kill Fee
+//!
+//! Este módulo define a instrução para configurar a compensação do influenciador quando a marca cancela uma campanha ativa.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{Campaign, CampaignStatus, BPS_DENOMINATOR};
+
+/// Define a kill fee da campanha, em basis points do orçamento ainda não pago.
+///
+/// Se a marca cancelar a campanha depois da ativação, `kill_fee_bps` do saldo não pago
+/// vai para o influenciador e o restante volta para a marca. A taxa pode crescer com o
+/// tempo: ela sobe linearmente de `kill_fee_bps` na ativação até `kill_fee_max_bps` no
+/// prazo final (valores iguais = taxa fixa), e é zero a partir do prazo final. Como os demais termos, é acordada em `Draft`:
+/// o influenciador a define e a marca a aceita ao financiar a versão correspondente.
+///
+/// Somente o influenciador pode chamar, e apenas enquanto a campanha está em `Draft`.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `SetKillFee`.
+/// * `kill_fee_bps` - Taxa na ativação, em basis points (0 = sem kill fee).
+/// * `kill_fee_max_bps` - Taxa no prazo final, em basis points.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotDraft` - Campanha não está em `Draft`.
+/// * `UnauthorizedInfluencer` - Assinante não é o influenciador da campanha.
+/// * `InvalidKillFee` - Taxa acima de 10000 ou `kill_fee_max_bps` menor que `kill_fee_bps`.
+pub fn set_kill_fee(ctx: Context<SetKillFee>, kill_fee_bps: u16, kill_fee_max_bps: u16) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    // Validações de segurança
+    require_eq!(campaign.status, CampaignStatus::Draft, ErrorCode::CampaignNotDraft);
+    require!(
+        kill_fee_bps <= kill_fee_max_bps && u64::from(kill_fee_max_bps) <= BPS_DENOMINATOR,
+        ErrorCode::InvalidKillFee
+    );
+
+    campaign.kill_fee_bps = kill_fee_bps;
+    campaign.kill_fee_max_bps = kill_fee_max_bps;
+    campaign.bump_terms_version();
+    campaign.last_updated = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+/// Contas para a instrução `set_kill_fee`.
+#[derive(Accounts)]
+pub struct SetKillFee<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, possuir o influenciador correto e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = influencer @ ErrorCode::UnauthorizedInfluencer,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Conta do influenciador (assinante).
+    pub influencer: Signer<'info>,
+}
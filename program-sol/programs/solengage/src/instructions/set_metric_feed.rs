@@ -0,0 +1,64 @@
+//! # Definir Feed de Métricas
+//!
+//! Este módulo define a instrução para associar um feed externo de métricas a uma campanha em rascunho.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{Campaign, CampaignStatus, MetricFeed};
+
+/// Associa (ou remove) o feed externo lido por `apply_feed_metrics`.
+///
+/// O feed é uma conta de uma rede de oráculos de terceiros no layout padrão
+/// (autoridade, valor, timestamp), de propriedade do programa informado em `owner_program`;
+/// seu valor é aplicado à entrada de métrica
+/// `(kind, platform)` configurada. Somente o influenciador pode chamar, e apenas
+/// enquanto a campanha está em `Draft`, para que a marca financie a fonte que revisou.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `SetMetricFeed`.
+/// * `metric_feed` - Conta do feed, programa dono, autoridade esperada, entrada de métrica e idade máxima
+///   (`None` remove o feed).
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotDraft` - Campanha não está em `Draft`.
+/// * `UnauthorizedInfluencer` - Assinante não é o influenciador da campanha.
+/// * `InvalidMetricFeed` - Idade máxima zero, programa dono ausente (ou System Program) ou
+///   métrica não suportada na plataforma.
+/// * `MetricTargetNotFound` - Campanha não tem entrada para a métrica do feed.
+pub fn set_metric_feed(ctx: Context<SetMetricFeed>, metric_feed: Option<MetricFeed>) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    // Validações de segurança
+    require_eq!(campaign.status, CampaignStatus::Draft, ErrorCode::CampaignNotDraft);
+    if let Some(feed) = &metric_feed {
+        feed.validate()?;
+    }
+
+    campaign.metric_feed = metric_feed;
+    campaign.validate_metric_feed()?;
+    campaign.bump_terms_version();
+    campaign.last_updated = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+/// Contas para a instrução `set_metric_feed`.
+#[derive(Accounts)]
+pub struct SetMetricFeed<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, possuir o influenciador correto e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = influencer @ ErrorCode::UnauthorizedInfluencer,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Conta do influenciador (assinante).
+    pub influencer: Signer<'info>,
+}
@@ -0,0 +1,55 @@
+//! # Definir Proteções de Métricas
+//!
+//! Este módulo define a instrução para configurar as proteções de métricas de uma campanha em rascunho.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{Campaign, CampaignStatus, MetricGuards};
+
+/// Define as regras de sanidade aplicadas aos relatórios de métricas.
+///
+/// Somente o influenciador pode chamar, e apenas enquanto a campanha está em `Draft`,
+/// para que a marca financie exatamente as regras que revisou.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `SetMetricGuards`.
+/// * `metric_guards` - Regra de monotonicidade e limites do circuit breaker (pontos percentuais).
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotDraft` - Campanha não está em `Draft`.
+/// * `UnauthorizedInfluencer` - Assinante não é o influenciador da campanha.
+/// * `InvalidMetricGuards` - Limites acima de 100 pontos percentuais.
+pub fn set_metric_guards(ctx: Context<SetMetricGuards>, metric_guards: MetricGuards) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    // Validações de segurança
+    require_eq!(campaign.status, CampaignStatus::Draft, ErrorCode::CampaignNotDraft);
+    metric_guards.validate()?;
+
+    campaign.metric_guards = metric_guards;
+    campaign.bump_terms_version();
+    campaign.last_updated = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+/// Contas para a instrução `set_metric_guards`.
+#[derive(Accounts)]
+pub struct SetMetricGuards<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, possuir o influenciador correto e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = influencer @ ErrorCode::UnauthorizedInfluencer,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Conta do influenciador (assinante).
+    pub influencer: Signer<'info>,
+}
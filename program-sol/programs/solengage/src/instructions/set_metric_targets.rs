@@ -0,0 +1,60 @@
+//! # Definir Metas de Métricas
+//!
+//! Este módulo define a instrução para configurar as metas e pesos de uma campanha em rascunho.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{build_metric_entries, Campaign, CampaignStatus, MetricTarget};
+
+/// Substitui as metas da campanha por uma lista de `(métrica, plataforma, meta, peso)`.
+///
+/// Somente o influenciador pode chamar, e apenas enquanto a campanha está em `Draft`,
+/// para que a marca financie exatamente as metas que revisou. Metas gerais de likes,
+/// comentários, visualizações e compartilhamentos ocupam as entradas-base; as demais
+/// (ex.: salvamentos no Instagram, cliques no link) viram entradas extras. Se a campanha
+/// tiver um feed de métricas, a métrica do feed precisa continuar entre as metas.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `SetMetricTargets`.
+/// * `targets` - Metas com métrica, plataforma opcional, valor e peso.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotDraft` - Campanha não está em `Draft`.
+/// * `UnauthorizedInfluencer` - Assinante não é o influenciador da campanha.
+/// * `NoTargetsSet` - Lista de metas vazia.
+/// * `InvalidMetricTargets` - Meta ou peso zerados, duplicados, em excesso ou métrica não suportada pela plataforma.
+/// * `MetricTargetNotFound` - Métrica do feed configurado removida das metas.
+pub fn set_metric_targets(ctx: Context<SetMetricTargets>, targets: Vec<MetricTarget>) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    // Validações de segurança
+    require_eq!(campaign.status, CampaignStatus::Draft, ErrorCode::CampaignNotDraft);
+
+    campaign.metrics = build_metric_entries(&targets)?;
+    campaign.validate_metric_feed()?;
+    campaign.bump_terms_version();
+    campaign.last_updated = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+/// Contas para a instrução `set_metric_targets`.
+#[derive(Accounts)]
+pub struct SetMetricTargets<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, possuir o influenciador correto e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = influencer @ ErrorCode::UnauthorizedInfluencer,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Conta do influenciador (assinante).
+    pub influencer: Signer<'info>,
+}
@@ -0,0 +1,56 @@
+//! # Ativar/Desativar Oráculo
+//!
+//! Este módulo define a instrução para suspender ou reativar um oráculo registrado.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{OracleEntry, OracleRegistry};
+
+/// Ativa ou desativa um oráculo registrado.
+///
+/// Um oráculo desativado não pode ser escolhido por novas campanhas nem enviar
+/// métricas, o que permite suspender uma chave comprometida antes da rotação.
+/// A desativação registra `deactivated_at`, que inicia o período de unbonding da
+/// caução; reativar o oráculo o cancela.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `SetOracleActive`.
+/// * `active` - `true` para ativar, `false` para desativar.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `UnauthorizedAdmin` - Assinante não é o administrador do registro.
+pub fn set_oracle_active(ctx: Context<SetOracleActive>, active: bool) -> Result<()> {
+    let entry = &mut ctx.accounts.oracle_entry;
+    if active {
+        entry.deactivated_at = 0;
+    } else if entry.active {
+        entry.deactivated_at = Clock::get()?.unix_timestamp;
+    }
+    entry.active = active;
+
+    Ok(())
+}
+
+/// Contas para a instrução `set_oracle_active`.
+#[derive(Accounts)]
+pub struct SetOracleActive<'info> {
+    /// Registro global de oráculos.
+    #[account(
+        has_one = admin @ ErrorCode::UnauthorizedAdmin,
+        seeds = [b"oracle_registry"],
+        bump = oracle_registry.bump
+    )]
+    pub oracle_registry: Account<'info, OracleRegistry>,
+    /// Entrada do oráculo a ser atualizada.
+    #[account(
+        mut,
+        seeds = [b"oracle", oracle_entry.id.to_le_bytes().as_ref()],
+        bump = oracle_entry.bump
+    )]
+    pub oracle_entry: Account<'info, OracleEntry>,
+    /// Administrador do registro (assinante).
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,63 @@
+//! # Definir Taxa do Oráculo
+//!
+//! Este módulo define a instrução para configurar a remuneração do oráculo de uma campanha em rascunho.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{Campaign, CampaignStatus};
+
+/// Define a taxa paga ao oráculo por relatório aplicado e o teto total de taxas.
+///
+/// Somente o influenciador pode chamar, e apenas enquanto a campanha está em `Draft`.
+/// Ao ativar a campanha, a marca deposita o teto como reserva de taxas além do
+/// orçamento; o que não for pago ao oráculo é reembolsado à marca na liquidação.
+/// A taxa é cobrada uma única vez, quando o relatório é aplicado (direto, após a janela
+/// de contestação ou após revisão), e vai para o oráculo que o enviou.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `SetOracleFee`.
+/// * `fee_per_update` - Taxa em USDC por relatório de oráculo aplicado.
+/// * `fee_cap` - Total máximo de taxas (reserva depositada pela marca).
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotDraft` - Campanha não está em `Draft`.
+/// * `UnauthorizedInfluencer` - Assinante não é o influenciador da campanha.
+/// * `InvalidOracleFee` - Teto menor que a taxa, ou apenas um dos valores é zero.
+pub fn set_oracle_fee(ctx: Context<SetOracleFee>, fee_per_update: u64, fee_cap: u64) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    // Validações de segurança
+    require_eq!(campaign.status, CampaignStatus::Draft, ErrorCode::CampaignNotDraft);
+    require!(
+        (fee_per_update == 0) == (fee_cap == 0) && fee_cap >= fee_per_update,
+        ErrorCode::InvalidOracleFee
+    );
+
+    campaign.oracle_fee_per_update = fee_per_update;
+    campaign.oracle_fee_cap = fee_cap;
+    campaign.bump_terms_version();
+    campaign.last_updated = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+/// Contas para a instrução `set_oracle_fee`.
+#[derive(Accounts)]
+pub struct SetOracleFee<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, possuir o influenciador correto e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = influencer @ ErrorCode::UnauthorizedInfluencer,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Conta do influenciador (assinante).
+    pub influencer: Signer<'info>,
+}
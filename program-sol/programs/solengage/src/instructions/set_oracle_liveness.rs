@@ -0,0 +1,65 @@
+//! # Definir Liveness do Oráculo
+//!
+//! Este módulo define a instrução para configurar o heartbeat do oráculo e o aviso prévio de cancelamento.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{Campaign, CampaignStatus, MAX_LIVENESS_PERIOD};
+
+/// Define o heartbeat do oráculo e o aviso prévio de cancelamento da campanha.
+///
+/// Se a campanha ficar `oracle_heartbeat` segundos sem relatórios do oráculo, o
+/// influenciador pode abrir o fallback (`open_oracle_fallback`) e liquidar os marcos
+/// conquistados com métricas co-assinadas pela marca ou pelo árbitro. Com aviso prévio,
+/// a marca precisa chamar `request_cancellation` e aguardar `cancel_notice_period`
+/// segundos antes de cancelar uma campanha `Active`.
+///
+/// Somente o influenciador pode chamar, e apenas enquanto a campanha está em `Draft`.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `SetOracleLiveness`.
+/// * `oracle_heartbeat` - Segundos sem relatório do oráculo até o fallback ficar disponível (0 = sem heartbeat).
+/// * `cancel_notice_period` - Prazo de aviso prévio de cancelamento em segundos (0 = sem aviso).
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotDraft` - Campanha não está em `Draft`.
+/// * `UnauthorizedInfluencer` - Assinante não é o influenciador da campanha.
+/// * `InvalidLivenessConfig` - Valores negativos ou maiores que `MAX_LIVENESS_PERIOD`.
+pub fn set_oracle_liveness(ctx: Context<SetOracleLiveness>, oracle_heartbeat: i64, cancel_notice_period: i64) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    // Validações de segurança
+    require_eq!(campaign.status, CampaignStatus::Draft, ErrorCode::CampaignNotDraft);
+    require!(
+        (0..=MAX_LIVENESS_PERIOD).contains(&oracle_heartbeat) && (0..=MAX_LIVENESS_PERIOD).contains(&cancel_notice_period),
+        ErrorCode::InvalidLivenessConfig
+    );
+
+    campaign.oracle_heartbeat = oracle_heartbeat;
+    campaign.cancel_notice_period = cancel_notice_period;
+    campaign.bump_terms_version();
+    campaign.last_updated = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+/// Contas para a instrução `set_oracle_liveness`.
+#[derive(Accounts)]
+pub struct SetOracleLiveness<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, possuir o influenciador correto e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = influencer @ ErrorCode::UnauthorizedInfluencer,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Conta do influenciador (assinante).
+    pub influencer: Signer<'info>,
+}
@@ -0,0 +1,113 @@
+//! # Definir Quórum de Oráculos
+//!
+//! Este módulo define a instrução para exigir relatórios M-de-N de oráculos independentes.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{Campaign, CampaignStatus, OracleEntry, OracleQuorum, MAX_QUORUM_ORACLES};
+
+/// Configura um quórum M-de-N de oráculos para a campanha.
+///
+/// Os oráculos do quórum são informados como `remaining_accounts` (entradas do
+/// registro global, ativas e sem conflito de interesse). A partir daqui, a campanha
+/// só aceita métricas via `submit_metric_report`; o oráculo único de
+/// `update_campaign_metrics` deixa de ser aceito.
+///
+/// Somente o influenciador pode chamar, e apenas enquanto a campanha está em `Draft`.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `SetOracleQuorum`.
+/// * `threshold` - Número mínimo de relatórios concordantes (M).
+/// * `tolerance_bps` - Desvio máximo em relação à mediana, em basis points
+///   (`MEDIAN_ONLY_TOLERANCE` aplica a mediana sem checar concordância).
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotDraft` - Campanha não está em `Draft`.
+/// * `UnauthorizedInfluencer` - Assinante não é o influenciador da campanha.
+/// * `InvalidQuorum` - Nenhum oráculo, mais de 5, duplicados, ou `threshold` fora de `1..=N`.
+/// * `InvalidOracleAccount` - Conta informada não é uma entrada do registro.
+/// * `OracleNotRegistered` - Oráculo desativado no registro global.
+/// * `OracleConflictOfInterest` - Chave do oráculo pertence ao influenciador ou à marca.
+pub fn set_oracle_quorum(ctx: Context<SetOracleQuorum>, threshold: u8, tolerance_bps: u16) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    require_eq!(campaign.status, CampaignStatus::Draft, ErrorCode::CampaignNotDraft);
+
+    let oracle_accounts = ctx.remaining_accounts;
+    require!(
+        !oracle_accounts.is_empty() && oracle_accounts.len() <= MAX_QUORUM_ORACLES,
+        ErrorCode::InvalidQuorum
+    );
+    require!(
+        threshold > 0 && threshold as usize <= oracle_accounts.len(),
+        ErrorCode::InvalidQuorum
+    );
+
+    // Valida cada entrada do registro informada
+    let mut oracles: Vec<Pubkey> = Vec::with_capacity(oracle_accounts.len());
+    for account in oracle_accounts.iter() {
+        require_keys_eq!(*account.owner, crate::ID, ErrorCode::InvalidOracleAccount);
+        let entry = OracleEntry::try_deserialize(&mut &account.try_borrow_data()?[..])
+            .map_err(|_| ErrorCode::InvalidOracleAccount)?;
+
+        require!(entry.active, ErrorCode::OracleNotRegistered);
+        require!(
+            entry.authority != campaign.influencer && entry.authority != campaign.brand,
+            ErrorCode::OracleConflictOfInterest
+        );
+        require!(!oracles.contains(&account.key()), ErrorCode::InvalidQuorum);
+
+        oracles.push(account.key());
+    }
+
+    let quorum = &mut ctx.accounts.oracle_quorum;
+    quorum.campaign = campaign.key();
+    quorum.oracles = oracles;
+    quorum.threshold = threshold;
+    quorum.tolerance_bps = tolerance_bps;
+    quorum.round = 0;
+    quorum.submissions = Vec::new();
+    quorum.bump = ctx.bumps.oracle_quorum;
+
+    campaign.quorum_enabled = true;
+    campaign.bump_terms_version();
+    campaign.last_updated = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+/// Contas para a instrução `set_oracle_quorum`.
+///
+/// As entradas `OracleEntry` do quórum são passadas em `remaining_accounts`.
+#[derive(Accounts)]
+pub struct SetOracleQuorum<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, possuir o influenciador correto e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = influencer @ ErrorCode::UnauthorizedInfluencer,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Conta do quórum a ser inicializada.
+    ///
+    /// PDA derivada de `["oracle_quorum", campaign]`.
+    #[account(
+        init,
+        payer = influencer,
+        space = 8 + OracleQuorum::INIT_SPACE,
+        seeds = [b"oracle_quorum", campaign.key().as_ref()],
+        bump
+    )]
+    pub oracle_quorum: Account<'info, OracleQuorum>,
+    /// Conta do influenciador (assinante e pagador).
+    #[account(mut)]
+    pub influencer: Signer<'info>,
+    /// Programa do sistema Solana.
+    pub system_program: Program<'info, System>,
+}
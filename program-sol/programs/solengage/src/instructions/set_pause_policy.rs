@@ -0,0 +1,59 @@
+//! # Definir Política de Pausa
+//!
+//! Este módulo define a instrução para configurar quanto tempo a marca pode pausar a campanha.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{Campaign, CampaignStatus, MAX_PAUSE_DURATION};
+
+/// Define a duração máxima (somada) das pausas da campanha.
+///
+/// Com `max_pause_duration > 0`, a marca pode pausar a campanha ativa via `pause_campaign`;
+/// o tempo pausado é somado ao prazo na retomada, até esse limite. Como os demais termos,
+/// o valor é acordado em `Draft`: o influenciador o define e a marca o aceita ao financiar
+/// a versão correspondente dos termos.
+///
+/// Somente o influenciador pode chamar, e apenas enquanto a campanha está em `Draft`.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `SetPausePolicy`.
+/// * `max_pause_duration` - Total de segundos em pausa permitido (0 = sem pausa).
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotDraft` - Campanha não está em `Draft`.
+/// * `UnauthorizedInfluencer` - Assinante não é o influenciador da campanha.
+/// * `InvalidPauseDuration` - Valor negativo ou maior que `MAX_PAUSE_DURATION`.
+pub fn set_pause_policy(ctx: Context<SetPausePolicy>, max_pause_duration: i64) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    // Validações de segurança
+    require_eq!(campaign.status, CampaignStatus::Draft, ErrorCode::CampaignNotDraft);
+    require!((0..=MAX_PAUSE_DURATION).contains(&max_pause_duration), ErrorCode::InvalidPauseDuration);
+
+    campaign.max_pause_duration = max_pause_duration;
+    campaign.bump_terms_version();
+    campaign.last_updated = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+/// Contas para a instrução `set_pause_policy`.
+#[derive(Accounts)]
+pub struct SetPausePolicy<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, possuir o influenciador correto e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = influencer @ ErrorCode::UnauthorizedInfluencer,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Conta do influenciador (assinante).
+    pub influencer: Signer<'info>,
+}
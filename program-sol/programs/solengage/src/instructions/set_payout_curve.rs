@@ -0,0 +1,55 @@
+//! # Definir Curva de Pagamento
+//!
+//! Este módulo define a instrução para escolher a curva de pagamento de uma campanha em rascunho.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{Campaign, CampaignStatus, PayoutCurve};
+
+/// Define a curva que mapeia o progresso da campanha ao valor acumulado pago.
+///
+/// Somente o influenciador pode chamar, e apenas enquanto a campanha está em `Draft`,
+/// para que a marca financie exatamente a curva que revisou.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `SetPayoutCurve`.
+/// * `payout_curve` - Curva `Linear`, `Convex`, `Concave` ou `Piecewise`.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotDraft` - Campanha não está em `Draft`.
+/// * `UnauthorizedInfluencer` - Assinante não é o influenciador da campanha.
+/// * `InvalidPayoutCurve` - Curva `Piecewise` não é monotônica ou não termina em 100%.
+pub fn set_payout_curve(ctx: Context<SetPayoutCurve>, payout_curve: PayoutCurve) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    // Validações de segurança
+    require_eq!(campaign.status, CampaignStatus::Draft, ErrorCode::CampaignNotDraft);
+    payout_curve.validate()?;
+
+    campaign.payout_curve = payout_curve;
+    campaign.bump_terms_version();
+    campaign.last_updated = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+/// Contas para a instrução `set_payout_curve`.
+#[derive(Accounts)]
+pub struct SetPayoutCurve<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, possuir o influenciador correto e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = influencer @ ErrorCode::UnauthorizedInfluencer,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Conta do influenciador (assinante).
+    pub influencer: Signer<'info>,
+}
@@ -0,0 +1,130 @@
+//! # Acordo de Encerramento
+//!
+//! Este módulo define a instrução para marca e influenciador encerrarem juntos uma campanha com uma divisão negociada.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::errors::ErrorCode;
+use crate::instructions::close_withdrawal::close_campaign_children;
+use crate::state::{Campaign, CampaignStatus};
+
+/// Encerra antecipadamente uma campanha com a assinatura das duas partes.
+///
+/// Do orçamento ainda não pago (`amount_usdc - paid_amount`), `influencer_amount` vai para
+/// o influenciador e o restante, junto com a reserva de taxas do oráculo não usada, volta
+/// para a marca. A conta da campanha é fechada em seguida e o rent é devolvido ao
+/// influenciador. Relatórios ainda não aplicados são descartados pelo acordo.
+///
+/// Os posts da campanha e o quórum de oráculos (quando habilitado) devem ser informados em
+/// `remaining_accounts` e são fechados junto com a campanha, com o rent devolvido ao
+/// influenciador. O histórico de métricas, se existir, continua com a marca e pode ser
+/// fechado depois com `close_metric_history`.
+///
+/// Permitido em `Active`, `Paused` ou `Disputed` (o acordo também encerra uma disputa).
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `SettleCampaign`.
+/// * `influencer_amount` - USDC do saldo não pago devido ao influenciador.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `UnauthorizedBrand` / `UnauthorizedInfluencer` - Assinantes não correspondem às partes.
+/// * `CampaignNotActive` - Campanha não está em `Active`, `Paused` ou `Disputed`.
+/// * `InvalidSettlementAmount` - `influencer_amount` maior que o saldo não pago.
+/// * `InvalidTokenAccount` - Contas de token não pertencem à campanha/marca/influenciador.
+/// * `CampaignChildAccountsRequired` - Posts ou quórum da campanha ausentes ou inválidos.
+/// * `MathOverflow` - Overflow aritmético.
+pub fn settle_campaign<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SettleCampaign<'info>>,
+    influencer_amount: u64,
+) -> Result<()> {
+    let campaign = &ctx.accounts.campaign;
+
+    // Validações de segurança
+    require!(
+        matches!(campaign.status, CampaignStatus::Active | CampaignStatus::Paused | CampaignStatus::Disputed),
+        ErrorCode::CampaignNotActive
+    );
+    let unpaid = campaign.amount_usdc.checked_sub(campaign.paid_amount).ok_or(ErrorCode::MathOverflow)?;
+    require!(influencer_amount <= unpaid, ErrorCode::InvalidSettlementAmount);
+    let brand_amount = (unpaid - influencer_amount)
+        .checked_add(campaign.oracle_fee_reserve)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let bump = ctx.bumps.campaign;
+    let seeds = &[
+        b"campaign".as_ref(),
+        campaign.influencer.as_ref(),
+        campaign.brand.as_ref(),
+        campaign.name.as_bytes(),
+        &[bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    if influencer_amount > 0 {
+        // CPI para pagar ao influenciador o valor acordado
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.campaign_usdc_account.to_account_info(),
+            to: ctx.accounts.influencer_usdc_account.to_account_info(),
+            authority: campaign.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+        token::transfer(cpi_ctx, influencer_amount)?;
+    }
+
+    if brand_amount > 0 {
+        // CPI para devolver à marca o restante do cofre
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.campaign_usdc_account.to_account_info(),
+            to: ctx.accounts.brand_usdc_account.to_account_info(),
+            authority: campaign.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+        token::transfer(cpi_ctx, brand_amount)?;
+    }
+
+    // Fecha os posts e o quórum junto com a campanha
+    let influencer = ctx.accounts.influencer.to_account_info();
+    close_campaign_children(campaign, ctx.remaining_accounts, &influencer)?;
+
+    msg!("Campanha encerrada por acordo: {} ao influenciador, {} à marca", influencer_amount, brand_amount);
+    Ok(())
+}
+
+/// Contas para a instrução `settle_campaign`.
+///
+/// Os posts e o quórum da campanha são passados em `remaining_accounts`.
+#[derive(Accounts)]
+pub struct SettleCampaign<'info> {
+    /// Conta da campanha, fechada ao final (rent devolvido ao influenciador).
+    ///
+    /// Deve ser mutável, possuir a marca e o influenciador corretos e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        close = influencer,
+        has_one = brand @ ErrorCode::UnauthorizedBrand,
+        has_one = influencer @ ErrorCode::UnauthorizedInfluencer,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Conta da marca (assinante).
+    pub brand: Signer<'info>,
+    /// Conta do influenciador (assinante, recebe o rent da campanha).
+    #[account(mut)]
+    pub influencer: Signer<'info>,
+    /// Conta USDC da marca (destino do restante do cofre).
+    #[account(mut, constraint = brand_usdc_account.owner == brand.key() @ ErrorCode::InvalidTokenAccount)]
+    pub brand_usdc_account: Account<'info, TokenAccount>,
+    /// Conta USDC do influenciador (destino do valor acordado).
+    #[account(mut, constraint = influencer_usdc_account.owner == influencer.key() @ ErrorCode::InvalidTokenAccount)]
+    pub influencer_usdc_account: Account<'info, TokenAccount>,
+    /// Cofre USDC da campanha (fonte da divisão).
+    #[account(mut, constraint = campaign_usdc_account.owner == campaign.key() @ ErrorCode::InvalidTokenAccount)]
+    pub campaign_usdc_account: Account<'info, TokenAccount>,
+    /// Programa SPL Token.
+    pub token_program: Program<'info, Token>,
+}
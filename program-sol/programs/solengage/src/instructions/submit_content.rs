@@ -0,0 +1,70 @@
+//! # Enviar Conteúdo
+//!
+//! Este módulo define a instrução para o influenciador registrar o conteúdo entregue para revisão da marca.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{Campaign, CampaignStatus, ContentStatus, ContentSubmission, Platform, MAX_POST_ID_LEN};
+
+/// Registra a referência do conteúdo publicado e inicia o prazo de revisão da marca.
+///
+/// Disponível em campanhas `Active` com revisão de conteúdo (`set_content_review`).
+/// Um novo envio substitui o anterior e reinicia o prazo, inclusive depois de a marca
+/// pedir alterações; conteúdo já aprovado não pode ser substituído.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `SubmitContent`.
+/// * `url_hash` - Hash SHA-256 da URL do conteúdo.
+/// * `platform` - Rede social onde o conteúdo foi publicado.
+/// * `post_id` - Identificador do post na plataforma (até `MAX_POST_ID_LEN` caracteres).
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `UnauthorizedInfluencer` - Assinante não é o influenciador da campanha.
+/// * `CampaignNotActive` - Campanha não está em `Active`.
+/// * `ContentReviewNotEnabled` - Campanha sem etapa de revisão de conteúdo.
+/// * `InvalidPostId` - Identificador vazio ou longo demais.
+/// * `ContentAlreadyApproved` - Conteúdo já aprovado (pela marca ou por decurso do prazo).
+pub fn submit_content(ctx: Context<SubmitContent>, url_hash: [u8; 32], platform: Platform, post_id: String) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let campaign = &mut ctx.accounts.campaign;
+
+    // Validações de segurança
+    require_eq!(campaign.status, CampaignStatus::Active, ErrorCode::CampaignNotActive);
+    require!(campaign.content_review_window > 0, ErrorCode::ContentReviewNotEnabled);
+    require!(!post_id.is_empty() && post_id.len() <= MAX_POST_ID_LEN, ErrorCode::InvalidPostId);
+    require!(!campaign.content_approved(now), ErrorCode::ContentAlreadyApproved);
+
+    campaign.content = Some(ContentSubmission {
+        url_hash,
+        platform,
+        post_id,
+        status: ContentStatus::Submitted,
+        submitted_at: now,
+        reviewed_at: 0,
+    });
+    campaign.last_updated = now;
+
+    msg!("Conteúdo enviado; revisão da marca até {}", now.saturating_add(campaign.content_review_window));
+    Ok(())
+}
+
+/// Contas para a instrução `submit_content`.
+#[derive(Accounts)]
+pub struct SubmitContent<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, possuir o influenciador correto e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = influencer @ ErrorCode::UnauthorizedInfluencer,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Conta do influenciador (assinante).
+    pub influencer: Signer<'info>,
+}
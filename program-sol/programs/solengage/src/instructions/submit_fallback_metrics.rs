@@ -0,0 +1,121 @@
+//! # Enviar Métricas de Fallback
+//!
+//! Este módulo define a instrução para liquidar marcos com métricas co-assinadas quando o oráculo está inativo.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::errors::ErrorCode;
+use crate::instructions::update_campaign_metrics::{apply_metrics, ReportAccounts};
+use crate::state::{Campaign, CampaignStatus, MetricHistory, MetricReading, OracleRegistry};
+
+/// Aplica métricas assinadas pelo influenciador e co-assinadas pela marca ou pelo árbitro.
+///
+/// Disponível depois que o influenciador abre o fallback (`open_oracle_fallback`). Como
+/// os valores são atestados pela marca ou pelo árbitro do registro, o relatório é aplicado
+/// diretamente (sem circuit breaker nem janela de contestação) pela mesma lógica de
+/// progresso e pagamento de `update_campaign_metrics`. Como os relatórios do oráculo,
+/// só é aceito antes do prazo; um novo relatório do oráculo fecha o fallback.
+///
+/// Não há verificação de `terms_version`: com a campanha `Active`, os termos só mudam por
+/// `amend_campaign`, assinado pelas duas partes, e as leituras são avaliadas contra os
+/// termos vigentes no momento do envio.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `SubmitFallbackMetrics`.
+/// * `readings` - Valores observados por (métrica, plataforma).
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotActive` - Campanha não está em `Active`.
+/// * `CampaignExpired` - Prazo da campanha expirou.
+/// * `ContentNotApproved` - Conteúdo da campanha ainda não aprovado pela marca.
+/// * `UnauthorizedInfluencer` - Assinante não é o influenciador da campanha.
+/// * `FallbackNotOpen` - O influenciador não abriu o fallback.
+/// * `UnauthorizedCosigner` - Co-assinante não é a marca nem o árbitro do registro.
+/// * `PostMetricsRequired` - Leitura de contador geral em campanha com posts.
+/// * `MetricHistoryRequired` - Campanha registra histórico e a conta não foi informada.
+/// * `MetricTargetNotFound` - Leitura sem entrada correspondente na campanha.
+/// * `MetricsDecreased` - Algum valor é menor que o valor atual da entrada.
+/// * `ReportHeldForReview` - Há um relatório retido aguardando revisão.
+/// * `ReportPendingChallenge` - Há um relatório em janela de contestação.
+/// * `InvalidTokenAccount` - Contas de token não pertencem à campanha/influenciador.
+/// * `MathOverflow` - Overflow aritmético durante cálculos de pagamento.
+pub fn submit_fallback_metrics(ctx: Context<SubmitFallbackMetrics>, readings: Vec<MetricReading>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let campaign = &mut ctx.accounts.campaign;
+
+    // Validações de segurança
+    require_eq!(campaign.status, CampaignStatus::Active, ErrorCode::CampaignNotActive);
+    require!(now < campaign.deadline, ErrorCode::CampaignExpired);
+    require!(campaign.content_approved(now), ErrorCode::ContentNotApproved);
+    require!(campaign.fallback_opened_at > 0, ErrorCode::FallbackNotOpen);
+    let cosigner = ctx.accounts.cosigner.key();
+    require!(
+        cosigner == campaign.brand || cosigner == ctx.accounts.oracle_registry.arbiter,
+        ErrorCode::UnauthorizedCosigner
+    );
+
+    // Em campanhas com posts, os contadores gerais são a soma dos posts
+    require!(
+        campaign.post_count == 0
+            || readings.iter().all(|reading| reading.platform.is_some() || reading.kind.base_index().is_none()),
+        ErrorCode::PostMetricsRequired
+    );
+
+    campaign.stage_readings(&readings)?;
+    campaign.last_observed_at = now;
+    // Relatório de fallback não remunera oráculo
+    campaign.report_fee_recipient = None;
+
+    apply_metrics(
+        campaign,
+        ReportAccounts {
+            campaign_bump: ctx.bumps.campaign,
+            campaign_usdc_account: &ctx.accounts.campaign_usdc_account,
+            influencer_usdc_account: &ctx.accounts.influencer_usdc_account,
+            oracle_usdc_account: None,
+            token_program: &ctx.accounts.token_program,
+            metric_history: ctx.accounts.metric_history.as_mut(),
+        },
+    )?;
+
+    msg!("Métricas de fallback aplicadas, co-assinadas por {}", cosigner);
+    Ok(())
+}
+
+/// Contas para a instrução `submit_fallback_metrics`.
+#[derive(Accounts)]
+pub struct SubmitFallbackMetrics<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, possuir o influenciador correto e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = influencer @ ErrorCode::UnauthorizedInfluencer,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Registro global de oráculos (fornece o árbitro).
+    #[account(seeds = [b"oracle_registry"], bump = oracle_registry.bump)]
+    pub oracle_registry: Account<'info, OracleRegistry>,
+    /// Conta do influenciador (assinante).
+    #[account(mut)]
+    pub influencer: Signer<'info>,
+    /// Marca da campanha ou árbitro do registro (co-assinante).
+    pub cosigner: Signer<'info>,
+    /// Histórico de métricas da campanha (obrigatório quando o histórico está habilitado).
+    #[account(mut, has_one = campaign @ ErrorCode::InvalidMetricHistory)]
+    pub metric_history: Option<Account<'info, MetricHistory>>,
+    /// Cofre USDC da campanha (fonte dos pagamentos).
+    #[account(mut, constraint = campaign_usdc_account.owner == campaign.key() @ ErrorCode::InvalidTokenAccount)]
+    pub campaign_usdc_account: Account<'info, TokenAccount>,
+    /// Conta USDC do influenciador (destino dos pagamentos).
+    #[account(mut, constraint = influencer_usdc_account.owner == campaign.influencer @ ErrorCode::InvalidTokenAccount)]
+    pub influencer_usdc_account: Account<'info, TokenAccount>,
+    /// Programa SPL Token.
+    pub token_program: Program<'info, Token>,
+}
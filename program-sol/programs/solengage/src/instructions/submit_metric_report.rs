@@ -0,0 +1,162 @@
+//! # Enviar Relatório de Métricas (Quórum)
+//!
+//! Este módulo define a instrução para um oráculo do quórum enviar métricas ao relatório pendente.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::errors::ErrorCode;
+use crate::instructions::update_campaign_metrics::{process_metric_report, ReportAccounts};
+use crate::state::{Campaign, CampaignStatus, MetricHistory, MetricValues, OracleEntry, OracleQuorum, QuorumSubmission};
+
+/// Registra o relatório de um oráculo do quórum e aplica o agregado quando M-de-N concordam.
+///
+/// Cada oráculo tem uma submissão por rodada (reenviar substitui a anterior). Quando
+/// o quórum é atingido, a mediana é aplicada pela mesma lógica de progresso e pagamento
+/// de `update_campaign_metrics` e uma nova rodada começa. A taxa por atualização, se
+/// houver, vai para o oráculo cujo relatório completou o quórum. Se todos os oráculos
+/// enviaram e não houve concordância, a rodada é descartada.
+/// O relatório agregado usa a rodada como número de sequência e a mediana inferior dos
+/// horários de observação das submissões, passando pelas mesmas verificações de ordem
+/// e de relatório antigo que os demais caminhos.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `SubmitMetricReport`.
+/// * `likes` - Número atual de curtidas.
+/// * `comments` - Número atual de comentários.
+/// * `views` - Número atual de visualizações.
+/// * `shares` - Número atual de compartilhamentos.
+/// * `observed_at` - Horário em que o oráculo observou as métricas (Unix timestamp).
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotActive` - Campanha não está em `Active`.
+/// * `ContentNotApproved` - Conteúdo da campanha ainda não aprovado pela marca.
+/// * `CampaignExpired` - Prazo da campanha expirou.
+/// * `PostMetricsRequired` - Campanha tem posts; métricas devem ser enviadas por post.
+/// * `InvalidObservationTime` - `observed_at` está no futuro.
+/// * `StaleReport` - `observed_at` é anterior à última observação aplicada.
+/// * `OracleNotInQuorum` - Oráculo não faz parte do quórum da campanha.
+/// * `UnauthorizedOracle` - Assinante não é a chave atual do oráculo.
+/// * `OracleNotRegistered` - Oráculo desativado no registro global.
+/// * `InvalidTokenAccount` - Contas de token não pertencem à campanha/influenciador.
+/// * `MetricsDecreased` - Alguma métrica é menor que o valor atual.
+/// * `ReportHeldForReview` - Há um relatório retido aguardando revisão.
+/// * `OracleFeeAccountRequired` - Taxa devida sem conta USDC do oráculo informada.
+/// * `MetricHistoryRequired` - Campanha registra histórico e a conta não foi informada.
+/// * `MathOverflow` - Overflow aritmético durante cálculos de pagamento.
+pub fn submit_metric_report(
+    ctx: Context<SubmitMetricReport>,
+    likes: u64,
+    comments: u64,
+    views: u64,
+    shares: u64,
+    observed_at: i64,
+) -> Result<()> {
+    // Valida status da campanha e deadline
+    require_eq!(ctx.accounts.campaign.status, CampaignStatus::Active, ErrorCode::CampaignNotActive);
+    let now = Clock::get()?.unix_timestamp;
+    require!(ctx.accounts.campaign.content_approved(now), ErrorCode::ContentNotApproved);
+    require!(now < ctx.accounts.campaign.deadline, ErrorCode::CampaignExpired);
+    require!(ctx.accounts.campaign.post_count == 0, ErrorCode::PostMetricsRequired);
+    require!(observed_at <= now, ErrorCode::InvalidObservationTime);
+    require!(observed_at >= ctx.accounts.campaign.last_observed_at, ErrorCode::StaleReport);
+
+    let quorum = &mut ctx.accounts.oracle_quorum;
+    quorum.record_submission(QuorumSubmission {
+        oracle: ctx.accounts.oracle.key(),
+        values: MetricValues { likes, comments, views, shares },
+        observed_at,
+        submitted_at: now,
+    });
+
+    let Some(aggregated) = quorum.aggregate() else {
+        if quorum.round_complete() {
+            msg!("Quórum não atingido na rodada {}: relatórios descartados", quorum.round);
+            quorum.start_next_round();
+        }
+        return Ok(());
+    };
+
+    // A rodada é o número de sequência do relatório agregado
+    let observed_at = quorum.observed_at();
+    ctx.accounts.campaign.record_report_sequence(quorum.round + 1, observed_at, now)?;
+    quorum.start_next_round();
+
+    let oracle_authority = ctx.accounts.oracle_authority.to_account_info();
+    process_metric_report(
+        &mut ctx.accounts.campaign,
+        ReportAccounts {
+            campaign_bump: ctx.bumps.campaign,
+            campaign_usdc_account: &ctx.accounts.campaign_usdc_account,
+            influencer_usdc_account: &ctx.accounts.influencer_usdc_account,
+            oracle_usdc_account: ctx.accounts.oracle_usdc_account.as_ref(),
+            token_program: &ctx.accounts.token_program,
+            metric_history: ctx.accounts.metric_history.as_mut(),
+        },
+        aggregated,
+        observed_at,
+        Some(oracle_authority.key()),
+    )?;
+
+    // Campanha concluída: fecha também a conta do quórum e reembolsa rent ao oráculo
+    if ctx.accounts.campaign.status == CampaignStatus::Completed {
+        let quorum_info = ctx.accounts.oracle_quorum.to_account_info();
+        let quorum_lamports = quorum_info.lamports();
+
+        **quorum_info.try_borrow_mut_lamports()? = 0;
+        **oracle_authority.try_borrow_mut_lamports()? = oracle_authority
+            .lamports()
+            .checked_add(quorum_lamports)
+            .ok_or(ErrorCode::MathOverflow)?;
+    }
+
+    Ok(())
+}
+
+/// Contas para a instrução `submit_metric_report`.
+#[derive(Accounts)]
+pub struct SubmitMetricReport<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Quórum de oráculos da campanha (relatório pendente da rodada atual).
+    #[account(
+        mut,
+        has_one = campaign,
+        seeds = [b"oracle_quorum", campaign.key().as_ref()],
+        bump = oracle_quorum.bump
+    )]
+    pub oracle_quorum: Account<'info, OracleQuorum>,
+    /// Entrada do oráculo no registro global (deve fazer parte do quórum e estar ativa).
+    #[account(
+        constraint = oracle_quorum.oracles.contains(&oracle.key()) @ ErrorCode::OracleNotInQuorum,
+        constraint = oracle.active @ ErrorCode::OracleNotRegistered
+    )]
+    pub oracle: Account<'info, OracleEntry>,
+    /// Chave atual do oráculo (assinante).
+    #[account(mut, address = oracle.authority @ ErrorCode::UnauthorizedOracle)]
+    pub oracle_authority: Signer<'info>,
+    /// Histórico de métricas da campanha (obrigatório quando o histórico está habilitado).
+    #[account(mut, has_one = campaign @ ErrorCode::InvalidMetricHistory)]
+    pub metric_history: Option<Account<'info, MetricHistory>>,
+    /// Cofre USDC da campanha (fonte dos pagamentos).
+    #[account(mut, constraint = campaign_usdc_account.owner == campaign.key() @ ErrorCode::InvalidTokenAccount)]
+    pub campaign_usdc_account: Account<'info, TokenAccount>,
+    /// Conta USDC do influenciador (destino dos pagamentos).
+    #[account(mut, constraint = influencer_usdc_account.owner == campaign.influencer @ ErrorCode::InvalidTokenAccount)]
+    pub influencer_usdc_account: Account<'info, TokenAccount>,
+    /// Conta USDC do oráculo (recebe a taxa por atualização; obrigatória quando há taxa devida).
+    #[account(mut, constraint = oracle_usdc_account.owner == oracle_authority.key() @ ErrorCode::InvalidTokenAccount)]
+    pub oracle_usdc_account: Option<Account<'info, TokenAccount>>,
+    /// Programa SPL Token.
+    pub token_program: Program<'info, Token>,
+}
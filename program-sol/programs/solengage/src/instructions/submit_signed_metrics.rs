@@ -0,0 +1,190 @@
+//! # Enviar Métricas Assinadas (Relayer)
+//!
+//! Este módulo define a instrução para qualquer relayer enviar um relatório de métricas
+//! assinado off-chain pelo oráculo, verificado via programa Ed25519.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use solana_instructions_sysvar::{load_current_index_checked, load_instruction_at_checked};
+use solana_sdk_ids::{ed25519_program, sysvar};
+use crate::errors::ErrorCode;
+use crate::instructions::update_campaign_metrics::{process_metric_report, ReportAccounts};
+use crate::state::{Campaign, CampaignStatus, MetricHistory, OracleEntry, SignedMetricReport};
+
+/// Tamanho do cabeçalho (`num_signatures` + padding) da instrução Ed25519.
+const ED25519_HEADER_LEN: usize = 2;
+
+/// Tamanho de um `Ed25519SignatureOffsets` (7 campos `u16`).
+const ED25519_OFFSETS_LEN: usize = 14;
+
+/// Índice de instrução que indica "dados na própria instrução Ed25519".
+const ED25519_INLINE_DATA: u16 = u16::MAX;
+
+/// Aplica um relatório de métricas assinado off-chain pelo oráculo.
+///
+/// O oráculo assina `report.message()` com sua chave (que pode ficar em cold storage)
+/// e qualquer conta pode retransmitir o relatório. A transação deve conter, imediatamente
+/// antes desta instrução, uma instrução do programa Ed25519 verificando essa assinatura;
+/// aqui confere-se, via sysvar de instruções, que a chave e a mensagem verificadas são
+/// exatamente a chave atual do oráculo e o relatório recebido.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `SubmitSignedMetrics`.
+/// * `report` - Relatório assinado (campanha, métricas, horário de observação, nonce).
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotActive` - Campanha não está em `Active`.
+/// * `ContentNotApproved` - Conteúdo da campanha ainda não aprovado pela marca.
+/// * `CampaignExpired` - Prazo da campanha expirou.
+/// * `QuorumRequired` - Campanha exige relatórios via quórum de oráculos.
+/// * `PostMetricsRequired` - Campanha tem posts; métricas devem ser enviadas por post.
+/// * `ReportCampaignMismatch` - Relatório assinado para outra campanha.
+/// * `InvalidObservationTime` - Horário de observação no futuro.
+/// * `ReportNonceReused` - Nonce igual à sequência do último relatório aceito.
+/// * `ReportOutOfOrder` - Nonce menor que a sequência do último relatório aceito.
+/// * `StaleReport` - Observação mais antiga que a última observação aplicada.
+/// * `InvalidSignatureInstruction` - Instrução Ed25519 ausente ou malformada.
+/// * `InvalidReportSignature` - Assinatura de outra chave ou sobre outra mensagem.
+/// * `MetricsDecreased` - Alguma métrica é menor que o valor atual.
+/// * `ReportHeldForReview` - Há um relatório retido aguardando revisão.
+/// * `OracleFeeAccountRequired` - Taxa devida sem conta USDC do oráculo informada.
+/// * `MetricHistoryRequired` - Campanha registra histórico e a conta não foi informada.
+pub fn submit_signed_metrics(ctx: Context<SubmitSignedMetrics>, report: SignedMetricReport) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let campaign = &ctx.accounts.campaign;
+
+    // Valida status da campanha, deadline e conteúdo do relatório
+    require_eq!(campaign.status, CampaignStatus::Active, ErrorCode::CampaignNotActive);
+    require!(campaign.content_approved(now), ErrorCode::ContentNotApproved);
+    require!(now < campaign.deadline, ErrorCode::CampaignExpired);
+    require!(!campaign.quorum_enabled, ErrorCode::QuorumRequired);
+    require!(campaign.post_count == 0, ErrorCode::PostMetricsRequired);
+    require_keys_eq!(report.campaign, campaign.key(), ErrorCode::ReportCampaignMismatch);
+
+    verify_ed25519_signature(
+        &ctx.accounts.instructions_sysvar,
+        &ctx.accounts.oracle.authority,
+        &report.message(),
+        ErrorCode::InvalidReportSignature,
+    )?;
+
+    // O nonce assinado é o número de sequência do relatório
+    ctx.accounts.campaign.record_report_sequence(report.nonce, report.observed_at, now)?;
+
+    let oracle_authority = ctx.accounts.oracle_authority.to_account_info();
+    process_metric_report(
+        &mut ctx.accounts.campaign,
+        ReportAccounts {
+            campaign_bump: ctx.bumps.campaign,
+            campaign_usdc_account: &ctx.accounts.campaign_usdc_account,
+            influencer_usdc_account: &ctx.accounts.influencer_usdc_account,
+            oracle_usdc_account: ctx.accounts.oracle_usdc_account.as_ref(),
+            token_program: &ctx.accounts.token_program,
+            metric_history: ctx.accounts.metric_history.as_mut(),
+        },
+        report.values,
+        report.observed_at,
+        Some(oracle_authority.key()),
+    )
+}
+
+/// Confere que a instrução anterior verificou `message` assinada por `signer`.
+///
+/// Exige uma instrução do programa Ed25519 com exatamente uma assinatura e com
+/// assinatura, chave e mensagem embutidas na própria instrução. Retorna
+/// `mismatch_error` se a chave ou a mensagem verificadas forem outras.
+pub(crate) fn verify_ed25519_signature(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+    mismatch_error: ErrorCode,
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, ErrorCode::InvalidSignatureInstruction);
+
+    let ed25519_ix = load_instruction_at_checked((current_index - 1) as usize, instructions_sysvar)?;
+    require_keys_eq!(ed25519_ix.program_id, ed25519_program::ID, ErrorCode::InvalidSignatureInstruction);
+
+    let data = &ed25519_ix.data;
+    require!(
+        data.len() >= ED25519_HEADER_LEN + ED25519_OFFSETS_LEN && data[0] == 1,
+        ErrorCode::InvalidSignatureInstruction
+    );
+
+    let read_u16 = |field: usize| {
+        let at = ED25519_HEADER_LEN + field * 2;
+        u16::from_le_bytes([data[at], data[at + 1]])
+    };
+    let signature_ix = read_u16(1);
+    let public_key_offset = read_u16(2) as usize;
+    let public_key_ix = read_u16(3);
+    let message_offset = read_u16(4) as usize;
+    let message_size = read_u16(5) as usize;
+    let message_ix = read_u16(6);
+
+    require!(
+        signature_ix == ED25519_INLINE_DATA
+            && public_key_ix == ED25519_INLINE_DATA
+            && message_ix == ED25519_INLINE_DATA,
+        ErrorCode::InvalidSignatureInstruction
+    );
+
+    let signed_key = data
+        .get(public_key_offset..public_key_offset + 32)
+        .ok_or(ErrorCode::InvalidSignatureInstruction)?;
+    let signed_message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(ErrorCode::InvalidSignatureInstruction)?;
+
+    if signed_key != signer.as_ref() || signed_message != message {
+        return Err(mismatch_error.into());
+    }
+
+    Ok(())
+}
+
+/// Contas para a instrução `submit_signed_metrics`.
+#[derive(Accounts)]
+pub struct SubmitSignedMetrics<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, referenciar a entrada de oráculo informada e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = oracle @ ErrorCode::UnauthorizedOracle,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Entrada do oráculo no registro global (deve estar ativa).
+    #[account(constraint = oracle.active @ ErrorCode::OracleNotRegistered)]
+    pub oracle: Account<'info, OracleEntry>,
+    /// Chave atual do oráculo (não assina a transação).
+    /// CHECK: Validada contra `oracle.authority`.
+    #[account(address = oracle.authority @ ErrorCode::UnauthorizedOracle)]
+    pub oracle_authority: AccountInfo<'info>,
+    /// Relayer que envia e paga a transação (qualquer conta).
+    pub relayer: Signer<'info>,
+    /// Histórico de métricas da campanha (obrigatório quando o histórico está habilitado).
+    #[account(mut, has_one = campaign @ ErrorCode::InvalidMetricHistory)]
+    pub metric_history: Option<Account<'info, MetricHistory>>,
+    /// Cofre USDC da campanha (fonte dos pagamentos).
+    #[account(mut, constraint = campaign_usdc_account.owner == campaign.key() @ ErrorCode::InvalidTokenAccount)]
+    pub campaign_usdc_account: Account<'info, TokenAccount>,
+    /// Conta USDC do influenciador (destino dos pagamentos).
+    #[account(mut, constraint = influencer_usdc_account.owner == campaign.influencer @ ErrorCode::InvalidTokenAccount)]
+    pub influencer_usdc_account: Account<'info, TokenAccount>,
+    /// Conta USDC do oráculo (recebe a taxa por atualização; obrigatória quando há taxa devida).
+    #[account(mut, constraint = oracle_usdc_account.owner == oracle_authority.key() @ ErrorCode::InvalidTokenAccount)]
+    pub oracle_usdc_account: Option<Account<'info, TokenAccount>>,
+    /// Sysvar de instruções, usada para inspecionar a instrução Ed25519.
+    /// CHECK: Endereço validado como a sysvar de instruções.
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+    /// Programa SPL Token.
+    pub token_program: Program<'info, Token>,
+}
@@ -5,13 +5,18 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::errors::ErrorCode;
-use crate::state::{Campaign, CampaignStatus};
+use crate::state::{Campaign, CampaignStatus, HistoryEntry, MetricHistory, MetricValues, OracleEntry};
 
 /// Atualiza métricas da campanha e aciona pagamentos automáticos por marcos.
 ///
-/// Apenas o oráculo autorizado pode chamar. Calcula o progresso com base
+/// Apenas a chave atual do oráculo registrado na campanha pode chamar. Calcula o progresso com base
 /// nas métricas atualizadas e paga os marcos recém-alcançados (10%, 20%, ..., 100%).
 /// A campanha é automaticamente finalizada quando o progresso atinge 100%.
+/// Se a campanha tiver taxa de oráculo, cada relatório aplicado paga a taxa ao
+/// oráculo a partir da reserva, até o teto; relatórios retidos ou pendentes só pagam
+/// quando forem aplicados.
+/// Se a campanha tiver histórico habilitado, cada relatório aplicado é registrado na
+/// `MetricHistory` (valores, progresso e valor pago).
 ///
 /// # Argumentos
 ///
@@ -20,12 +25,27 @@ use crate::state::{Campaign, CampaignStatus};
 /// * `comments` - Número atual de comentários.
 /// * `views` - Número atual de visualizações.
 /// * `shares` - Número atual de compartilhamentos.
+/// * `sequence` - Número de sequência do relatório (estritamente crescente por campanha).
+/// * `observed_at` - Horário em que o oráculo observou as métricas (timestamp Unix).
 ///
 /// # Erros
 ///
 /// Retorna `ErrorCode` se ocorrer:
 /// * `CampaignNotActive` - Campanha não está em `Active`.
+/// * `ContentNotApproved` - Conteúdo da campanha ainda não aprovado pela marca.
 /// * `CampaignExpired` - Prazo da campanha expirou.
+/// * `UnauthorizedOracle` - Assinante não é a chave atual do oráculo da campanha.
+/// * `OracleNotRegistered` - Oráculo desativado no registro global.
+/// * `QuorumRequired` - Campanha exige relatórios via quórum de oráculos.
+/// * `PostMetricsRequired` - Campanha tem posts; métricas devem ser enviadas por post.
+/// * `MetricHistoryRequired` - Campanha registra histórico e a conta não foi informada.
+/// * `InvalidObservationTime` - Horário de observação no futuro.
+/// * `ReportNonceReused` - Sequência igual à do último relatório aceito (duplicado).
+/// * `ReportOutOfOrder` - Sequência menor que a do último relatório aceito.
+/// * `StaleReport` - Observação mais antiga que a última observação aplicada.
+/// * `MetricsDecreased` - Alguma métrica é menor que o valor atual.
+/// * `ReportHeldForReview` - Há um relatório retido aguardando revisão.
+/// * `OracleFeeAccountRequired` - Taxa devida sem conta USDC do oráculo informada.
 /// * `MathOverflow` - Overflow aritmético durante cálculos de pagamento.
 pub fn update_campaign_metrics(
     ctx: Context<UpdateCampaignMetrics>,
@@ -33,54 +53,241 @@ pub fn update_campaign_metrics(
     comments: u64,
     views: u64,
     shares: u64,
+    sequence: u64,
+    observed_at: i64,
 ) -> Result<()> {
     // Valida status da campanha e deadline
+    let now = Clock::get()?.unix_timestamp;
     require_eq!(ctx.accounts.campaign.status, CampaignStatus::Active, ErrorCode::CampaignNotActive);
-    require!(Clock::get()?.unix_timestamp < ctx.accounts.campaign.deadline, ErrorCode::CampaignExpired);
+    require!(ctx.accounts.campaign.content_approved(now), ErrorCode::ContentNotApproved);
+    require!(now < ctx.accounts.campaign.deadline, ErrorCode::CampaignExpired);
+    require!(!ctx.accounts.campaign.quorum_enabled, ErrorCode::QuorumRequired);
+    require!(ctx.accounts.campaign.post_count == 0, ErrorCode::PostMetricsRequired);
 
-    let old_progress = ctx.accounts.campaign.get_progress_percentage();
+    // Rejeita relatórios duplicados, fora de ordem ou com observação antiga
+    ctx.accounts.campaign.record_report_sequence(sequence, observed_at, now)?;
+
+    let oracle_authority = ctx.accounts.oracle_authority.to_account_info();
+    process_metric_report(
+        &mut ctx.accounts.campaign,
+        ReportAccounts {
+            campaign_bump: ctx.bumps.campaign,
+            campaign_usdc_account: &ctx.accounts.campaign_usdc_account,
+            influencer_usdc_account: &ctx.accounts.influencer_usdc_account,
+            oracle_usdc_account: ctx.accounts.oracle_usdc_account.as_ref(),
+            token_program: &ctx.accounts.token_program,
+            metric_history: ctx.accounts.metric_history.as_mut(),
+        },
+        MetricValues { likes, comments, views, shares },
+        observed_at,
+        Some(oracle_authority.key()),
+    )
+}
+
+/// Contas usadas para aplicar um relatório e fazer os pagamentos correspondentes.
+pub(crate) struct ReportAccounts<'a, 'info> {
+    /// Bump da PDA da campanha (assina as transferências do cofre).
+    pub campaign_bump: u8,
+    /// Cofre USDC da campanha (fonte dos pagamentos).
+    pub campaign_usdc_account: &'a Account<'info, TokenAccount>,
+    /// Conta USDC do influenciador (destino dos pagamentos por marco).
+    pub influencer_usdc_account: &'a Account<'info, TokenAccount>,
+    /// Conta USDC do oráculo que recebe a taxa (obrigatória quando há taxa devida).
+    pub oracle_usdc_account: Option<&'a Account<'info, TokenAccount>>,
+    /// Programa SPL Token.
+    pub token_program: &'a Program<'info, Token>,
+    /// Histórico de métricas (obrigatório quando a campanha registra histórico).
+    pub metric_history: Option<&'a mut Account<'info, MetricHistory>>,
+}
+
+/// Paga a taxa por atualização ao oráculo a partir da reserva da campanha.
+///
+/// Chamado uma única vez por relatório, quando ele é aplicado. A taxa é limitada
+/// ao saldo da reserva e vai para a conta USDC de `recipient`, o oráculo que
+/// enviou o relatório.
+fn pay_oracle_fee<'info>(
+    campaign: &mut Account<'info, Campaign>,
+    accounts: &ReportAccounts<'_, 'info>,
+    recipient: Pubkey,
+) -> Result<()> {
+    let fee = campaign.oracle_fee_per_update.min(campaign.oracle_fee_reserve);
+    if fee == 0 {
+        return Ok(());
+    }
+
+    let oracle_usdc_account = accounts.oracle_usdc_account.ok_or(ErrorCode::OracleFeeAccountRequired)?;
+    require_keys_eq!(oracle_usdc_account.owner, recipient, ErrorCode::InvalidTokenAccount);
+    let seeds = &[
+        b"campaign".as_ref(),
+        campaign.influencer.as_ref(),
+        campaign.brand.as_ref(),
+        campaign.name.as_bytes(),
+        &[accounts.campaign_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    // CPI para pagar a taxa do oráculo a partir da reserva no cofre da campanha
+    let cpi_accounts = Transfer {
+        from: accounts.campaign_usdc_account.to_account_info(),
+        to: oracle_usdc_account.to_account_info(),
+        authority: campaign.to_account_info(),
+    };
+    let cpi_program = accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+    token::transfer(cpi_ctx, fee)?;
+
+    campaign.oracle_fee_reserve -= fee;
+    campaign.oracle_fees_paid = campaign.oracle_fees_paid.checked_add(fee).ok_or(ErrorCode::MathOverflow)?;
+
+    Ok(())
+}
+
+/// Registra um relatório aplicado no histórico de métricas, se informado.
+///
+/// `paid_before` é o valor pago ao influenciador antes de aplicar o relatório.
+fn record_history(campaign: &Campaign, metric_history: Option<&mut Account<MetricHistory>>, paid_before: u64) {
+    if let Some(history) = metric_history {
+        history.record(HistoryEntry {
+            observed_at: campaign.last_observed_at,
+            values: campaign.current_metrics(),
+            extra_values: campaign.current_extra_metrics(),
+            progress: campaign.get_progress_percentage() as u8,
+            amount_paid: campaign.paid_amount - paid_before,
+            total_paid: campaign.paid_amount,
+        });
+    }
+}
+
+/// Valida um relatório dos quatro contadores gerais e o aplica ou retém.
+///
+/// Ponto de entrada comum aos caminhos que reportam `MetricValues` (oráculo único,
+/// quórum, relatório assinado, posts). Os valores ficam pendentes nas entradas-base
+/// da campanha e seguem para `process_staged_report`.
+pub(crate) fn process_metric_report<'info>(
+    campaign: &mut Account<'info, Campaign>,
+    accounts: ReportAccounts<'_, 'info>,
+    values: MetricValues,
+    observed_at: i64,
+    fee_recipient: Option<Pubkey>,
+) -> Result<()> {
+    campaign.stage_metric_values(&values)?;
+
+    process_staged_report(campaign, accounts, observed_at, fee_recipient)
+}
+
+/// Avalia o relatório pendente nas entradas de métricas e o aplica ou retém.
+///
+/// Métricas que diminuem já foram rejeitadas ao registrar os valores pendentes; um salto
+/// acima dos limites do circuit breaker fica retido na campanha, sem pagamentos, até que
+/// a marca ou o admin do registro o revise via `resolve_held_report`.
+/// Com janela de contestação (modo otimista), o relatório fica pendente e só é aplicado
+/// por `finalize_pending_report` depois que a janela termina sem contestação da marca.
+/// `observed_at` é o horário em que as métricas foram observadas; `fee_recipient` é o
+/// oráculo que recebe a taxa quando o relatório for aplicado (None para feeds). Todo
+/// relatório de oráculo renova `last_oracle_report_at` e fecha o fallback do oráculo.
+pub(crate) fn process_staged_report<'info>(
+    campaign: &mut Account<'info, Campaign>,
+    accounts: ReportAccounts<'_, 'info>,
+    observed_at: i64,
+    fee_recipient: Option<Pubkey>,
+) -> Result<()> {
+    require!(
+        !campaign.history_enabled || accounts.metric_history.is_some(),
+        ErrorCode::MetricHistoryRequired
+    );
+    campaign.report_fee_recipient = fee_recipient;
+    if fee_recipient.is_some() {
+        // Relatório do oráculo: renova o heartbeat e encerra o fallback, se aberto
+        campaign.last_oracle_report_at = Clock::get()?.unix_timestamp;
+        campaign.fallback_opened_at = 0;
+    }
+
+    if campaign.check_metric_guards(observed_at) {
+        msg!(
+            "Circuit breaker acionado: progresso {}% -> {}%, relatório retido para revisão",
+            campaign.get_progress_percentage(),
+            campaign.pending_progress_percentage()
+        );
+        campaign.report_held = true;
+        campaign.held_at = observed_at;
+        campaign.last_updated = Clock::get()?.unix_timestamp;
+        return Ok(());
+    }
+
+    if campaign.challenge_window > 0 {
+        let now = Clock::get()?.unix_timestamp;
+        msg!("Relatório pendente até {} (janela de contestação)", now.saturating_add(campaign.challenge_window));
+        campaign.report_pending_since = now;
+        campaign.held_at = observed_at;
+        campaign.last_updated = now;
+        return Ok(());
+    }
+
+    campaign.last_observed_at = observed_at;
+
+    apply_metrics(campaign, accounts)
+}
+
+/// Aplica os valores pendentes à campanha e paga os marcos recém-alcançados.
+///
+/// Lógica de progresso e pagamento compartilhada por todos os caminhos de relatório
+/// (oráculo único, quórum de oráculos). O chamador é responsável por validar status,
+/// prazo e autorização antes de chamar, e por registrar `last_observed_at`.
+///
+/// Se o relatório veio de um oráculo (`report_fee_recipient`), a taxa por atualização
+/// é paga aqui, uma única vez, depois dos marcos. Todo relatório aplicado é registrado
+/// na `MetricHistory` quando a campanha tem histórico habilitado.
+/// Quando o progresso atinge 100%, a campanha é finalizada (`completed_at`) e permanece
+/// aberta por `ORACLE_CHALLENGE_PERIOD`, para que o oráculo ainda possa ser contestado;
+/// depois disso ela é fechada com `close_campaign`.
+pub(crate) fn apply_metrics<'info>(
+    campaign: &mut Account<'info, Campaign>,
+    mut accounts: ReportAccounts<'_, 'info>,
+) -> Result<()> {
+    require!(
+        !campaign.history_enabled || accounts.metric_history.is_some(),
+        ErrorCode::MetricHistoryRequired
+    );
+    let old_progress = campaign.get_progress_percentage();
+    let paid_before = campaign.paid_amount;
 
     // Atualiza métricas correntes e timestamp de última atualização
-    ctx.accounts.campaign.current_likes = likes;
-    ctx.accounts.campaign.current_comments = comments;
-    ctx.accounts.campaign.current_views = views;
-    ctx.accounts.campaign.current_shares = shares;
-    ctx.accounts.campaign.last_updated = Clock::get()?.unix_timestamp;
+    campaign.apply_pending_values();
+    campaign.last_updated = Clock::get()?.unix_timestamp;
 
-    let new_progress = ctx.accounts.campaign.get_progress_percentage();
+    let new_progress = campaign.get_progress_percentage();
 
     let old_milestones_achieved = (old_progress / 10) as usize;
     let new_milestones_achieved = (new_progress / 10) as usize;
 
     // Itera sobre marcos recém-alcançados e processa pagamentos
     for milestone_index in old_milestones_achieved..new_milestones_achieved {
-        let amount_to_transfer = ctx.accounts.campaign.calculate_safe_payment(milestone_index)?;
+        let amount_to_transfer = campaign.calculate_safe_payment(milestone_index)?;
 
         if amount_to_transfer > 0 {
-            if let Ok(_) = ctx.accounts.campaign.validate_payment_safety(milestone_index, amount_to_transfer) {
-                let bump = ctx.bumps.campaign;
+            if campaign.validate_payment_safety(milestone_index, amount_to_transfer).is_ok() {
                 let seeds = &[
                     b"campaign".as_ref(),
-                    ctx.accounts.campaign.influencer.as_ref(),
-                    ctx.accounts.campaign.brand.as_ref(),
-                    ctx.accounts.campaign.name.as_bytes(),
-                    &[bump],
+                    campaign.influencer.as_ref(),
+                    campaign.brand.as_ref(),
+                    campaign.name.as_bytes(),
+                    &[accounts.campaign_bump],
                 ];
                 let signer = &[&seeds[..]];
 
                 // CPI para transferir USDC do cofre da campanha ao influenciador
                 let cpi_accounts = Transfer {
-                    from: ctx.accounts.campaign_usdc_account.to_account_info(),
-                    to: ctx.accounts.influencer_usdc_account.to_account_info(),
-                    authority: ctx.accounts.campaign.to_account_info(),
+                    from: accounts.campaign_usdc_account.to_account_info(),
+                    to: accounts.influencer_usdc_account.to_account_info(),
+                    authority: campaign.to_account_info(),
                 };
-                let cpi_program = ctx.accounts.token_program.to_account_info();
+                let cpi_program = accounts.token_program.to_account_info();
                 let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
 
                 match token::transfer(cpi_ctx, amount_to_transfer) {
                     Ok(_) => {
-                        ctx.accounts.campaign.paid_amount = ctx.accounts.campaign.paid_amount.checked_add(amount_to_transfer).ok_or(ErrorCode::MathOverflow)?;
-                        ctx.accounts.campaign.payment_milestones[milestone_index] = true;
+                        campaign.paid_amount = campaign.paid_amount.checked_add(amount_to_transfer).ok_or(ErrorCode::MathOverflow)?;
+                        campaign.payment_milestones[milestone_index] = true;
                     },
                     Err(e) => {
                         msg!("Falha no pagamento do marco {}: {:?}", milestone_index, e);
@@ -89,24 +296,24 @@ pub fn update_campaign_metrics(
             } else {
                 msg!("Validação de pagamento falhou para o marco {}", milestone_index);
             }
+        } else {
+            // Trecho plano da curva: marco alcançado sem valor adicional a pagar
+            campaign.payment_milestones[milestone_index] = true;
         }
     }
 
-    // Se progresso atingir 100%, completa a campanha e reembolsa rent
+    // Taxa do oráculo: paga uma vez por relatório aplicado
+    if let Some(recipient) = campaign.report_fee_recipient.take() {
+        pay_oracle_fee(campaign, &accounts, recipient)?;
+    }
+
+    record_history(campaign, accounts.metric_history.take(), paid_before);
+
+    // Se progresso atingir 100%, completa a campanha; a conta é fechada depois do período
+    // de contestação (e do reembolso da reserva de taxas) via `close_campaign`
     if new_progress >= 100 {
-        ctx.accounts.campaign.status = CampaignStatus::Completed;
-
-        // Fecha a conta da campanha e reembolsa rent ao oráculo
-        let campaign_lamports = ctx.accounts.campaign.to_account_info().lamports();
-
-        **ctx.accounts.campaign.to_account_info().try_borrow_mut_lamports()? = 0;
-        **ctx.accounts.oracle.to_account_info().try_borrow_mut_lamports()? = ctx
-            .accounts
-            .oracle
-            .to_account_info()
-            .lamports()
-            .checked_add(campaign_lamports)
-            .ok_or(ErrorCode::MathOverflow)?;
+        let now = campaign.last_updated;
+        campaign.complete(now);
     }
 
     Ok(())
@@ -117,24 +324,33 @@ pub fn update_campaign_metrics(
 pub struct UpdateCampaignMetrics<'info> {
     /// Conta da campanha.
     ///
-    /// Deve ser mutável, possuir o oráculo correto e ser uma PDA derivada de
+    /// Deve ser mutável, referenciar a entrada de oráculo informada e ser uma PDA derivada de
     /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
     #[account(
         mut,
-        has_one = oracle,
+        has_one = oracle @ ErrorCode::UnauthorizedOracle,
         seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
         bump
     )]
     pub campaign: Account<'info, Campaign>,
-    /// The oracle's signer account.
-    #[account(mut)]
-    pub oracle: Signer<'info>,
+    /// The oracle's registry entry (must match campaign.oracle and be active).
+    #[account(constraint = oracle.active @ ErrorCode::OracleNotRegistered)]
+    pub oracle: Account<'info, OracleEntry>,
+    /// The oracle's current signing key.
+    #[account(mut, address = oracle.authority @ ErrorCode::UnauthorizedOracle)]
+    pub oracle_authority: Signer<'info>,
     /// The campaign's USDC vault token account (source for payments).
     #[account(mut)]
     pub campaign_usdc_account: Account<'info, TokenAccount>,
     /// The influencer's USDC token account (destination for payments).
     #[account(mut)]
     pub influencer_usdc_account: Account<'info, TokenAccount>,
+    /// The oracle's USDC token account (receives the per-update fee; required when a fee is due).
+    #[account(mut, constraint = oracle_usdc_account.owner == oracle_authority.key() @ ErrorCode::InvalidTokenAccount)]
+    pub oracle_usdc_account: Option<Account<'info, TokenAccount>>,
+    /// The campaign's metric history (required when `campaign.history_enabled`).
+    #[account(mut, has_one = campaign @ ErrorCode::InvalidMetricHistory)]
+    pub metric_history: Option<Account<'info, MetricHistory>>,
     /// The SPL Token program.
     pub token_program: Program<'info, Token>,
     /// The Solana system program.
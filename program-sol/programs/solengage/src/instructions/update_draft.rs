@@ -0,0 +1,99 @@
+//! # Atualizar Rascunho
+//!
+//! Este módulo define a instrução para o influenciador alterar os termos de uma campanha em rascunho.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{set_base_targets, Campaign, CampaignStatus};
+use super::create_campaign::validate_campaign_terms;
+
+/// Substitui os termos de uma campanha em `Draft`.
+///
+/// Somente o influenciador pode chamar. Os campos descritivos, as metas gerais, o
+/// orçamento e o prazo passam pelas mesmas validações de `create_campaign`. O nome não
+/// pode mudar, pois faz parte das seeds da PDA. Apenas as metas das quatro entradas-base
+/// são substituídas: as metas extras e os pesos definidos via `set_metric_targets` são
+/// mantidos, assim como o feed de métricas. A versão dos termos é incrementada, invalidando a versão
+/// revisada pela marca em `brand_pay_campaign`.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `UpdateDraft`.
+/// * `nickname` - Apelido/handle do influenciador (máx. 50 caracteres).
+/// * `brand_name` - Nome da marca (máx. 50 caracteres).
+/// * `hashtag` - Hashtag da campanha (máx. 50 caracteres).
+/// * `target_likes` - Meta de curtidas.
+/// * `target_comments` - Meta de comentários.
+/// * `target_views` - Meta de visualizações.
+/// * `target_shares` - Meta de compartilhamentos.
+/// * `amount_usdc` - Orçamento total da campanha em USDC (6 decimais).
+/// * `deadline` - Timestamp Unix de expiração da campanha.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotDraft` - Campanha não está em `Draft`.
+/// * `UnauthorizedInfluencer` - Assinante não é o influenciador da campanha.
+/// * Qualquer erro de validação de `create_campaign` (`NicknameTooLong` ... `NoTargetsSet`).
+/// * `MetricTargetNotFound` - Métrica do feed configurado sem entrada correspondente.
+#[allow(clippy::too_many_arguments)]
+pub fn update_draft(
+    ctx: Context<UpdateDraft>,
+    nickname: String,
+    brand_name: String,
+    hashtag: String,
+    target_likes: u64,
+    target_comments: u64,
+    target_views: u64,
+    target_shares: u64,
+    amount_usdc: u64,
+    deadline: i64,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    // Validações de segurança
+    require_eq!(campaign.status, CampaignStatus::Draft, ErrorCode::CampaignNotDraft);
+    validate_campaign_terms(
+        &campaign.name,
+        &nickname,
+        &brand_name,
+        &hashtag,
+        target_likes,
+        target_comments,
+        target_views,
+        target_shares,
+        amount_usdc,
+        deadline,
+    )?;
+
+    campaign.nickname = nickname;
+    campaign.brand_name = brand_name;
+    campaign.hashtag = hashtag;
+    set_base_targets(&mut campaign.metrics, [target_likes, target_comments, target_views, target_shares]);
+    campaign.validate_metric_feed()?;
+    campaign.amount_usdc = amount_usdc;
+    campaign.deadline = deadline;
+    campaign.bump_terms_version();
+    campaign.last_updated = Clock::get()?.unix_timestamp;
+
+    msg!("Termos da campanha atualizados: versão {}", campaign.terms_version);
+    Ok(())
+}
+
+/// Contas para a instrução `update_draft`.
+#[derive(Accounts)]
+pub struct UpdateDraft<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, possuir o influenciador correto e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = influencer @ ErrorCode::UnauthorizedInfluencer,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Conta do influenciador (assinante).
+    pub influencer: Signer<'info>,
+}
@@ -0,0 +1,124 @@
+//! # Atualizar Valores de Métricas
+//!
+//! Este módulo define a instrução para o oráculo reportar valores de qualquer entrada de métrica da campanha.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::errors::ErrorCode;
+use crate::instructions::update_campaign_metrics::{process_staged_report, ReportAccounts};
+use crate::state::{Campaign, CampaignStatus, MetricHistory, MetricReading, OracleEntry};
+
+/// Atualiza os valores de entradas de métricas e reprocessa o progresso da campanha.
+///
+/// Apenas a chave atual do oráculo registrado na campanha pode chamar. Cada leitura
+/// identifica a entrada por `(métrica, plataforma)`, então novas métricas não exigem novos
+/// argumentos. Os valores ficam pendentes nas entradas e o relatório passa pelas mesmas
+/// proteções, pagamentos por marco e taxa de oráculo de `update_campaign_metrics`;
+/// entradas não informadas não mudam. Se o relatório for retido pelo circuit breaker,
+/// os valores continuam pendentes até a revisão.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `UpdateMetricValues`.
+/// * `readings` - Valores observados por (métrica, plataforma).
+/// * `sequence` - Número de sequência do relatório (estritamente crescente por campanha).
+/// * `observed_at` - Horário em que o oráculo observou as métricas (timestamp Unix).
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotActive` - Campanha não está em `Active`.
+/// * `ContentNotApproved` - Conteúdo da campanha ainda não aprovado pela marca.
+/// * `CampaignExpired` - Prazo da campanha expirou.
+/// * `UnauthorizedOracle` - Assinante não é a chave atual do oráculo da campanha.
+/// * `OracleNotRegistered` - Oráculo desativado no registro global.
+/// * `QuorumRequired` - Campanha exige relatórios via quórum de oráculos.
+/// * `PostMetricsRequired` - Leitura de contador geral em campanha com posts.
+/// * `InvalidTokenAccount` - Contas de token não pertencem à campanha/influenciador.
+/// * `InvalidObservationTime` - Horário de observação no futuro.
+/// * `ReportNonceReused` - Sequência igual à do último relatório aceito (duplicado).
+/// * `ReportOutOfOrder` - Sequência menor que a do último relatório aceito.
+/// * `StaleReport` - Observação mais antiga que a última observação aplicada.
+/// * `MetricTargetNotFound` - Leitura sem entrada correspondente na campanha.
+/// * `MetricsDecreased` - Algum valor é menor que o valor atual da entrada.
+/// * `ReportHeldForReview` - Há um relatório retido aguardando revisão.
+/// * `OracleFeeAccountRequired` - Taxa devida sem conta USDC do oráculo informada.
+/// * `MetricHistoryRequired` - Campanha registra histórico e a conta não foi informada.
+/// * `MathOverflow` - Overflow aritmético durante cálculos de pagamento.
+pub fn update_metric_values(
+    ctx: Context<UpdateMetricValues>,
+    readings: Vec<MetricReading>,
+    sequence: u64,
+    observed_at: i64,
+) -> Result<()> {
+    // Valida status da campanha e deadline
+    let now = Clock::get()?.unix_timestamp;
+    let campaign = &mut ctx.accounts.campaign;
+    require_eq!(campaign.status, CampaignStatus::Active, ErrorCode::CampaignNotActive);
+    require!(campaign.content_approved(now), ErrorCode::ContentNotApproved);
+    require!(now < campaign.deadline, ErrorCode::CampaignExpired);
+    require!(!campaign.quorum_enabled, ErrorCode::QuorumRequired);
+
+    // Em campanhas com posts, os contadores gerais são a soma dos posts
+    require!(
+        campaign.post_count == 0
+            || readings.iter().all(|reading| reading.platform.is_some() || reading.kind.base_index().is_none()),
+        ErrorCode::PostMetricsRequired
+    );
+
+    // Rejeita relatórios duplicados, fora de ordem ou com observação antiga
+    campaign.record_report_sequence(sequence, observed_at, now)?;
+
+    campaign.stage_readings(&readings)?;
+
+    let oracle_authority = ctx.accounts.oracle_authority.to_account_info();
+    process_staged_report(
+        &mut ctx.accounts.campaign,
+        ReportAccounts {
+            campaign_bump: ctx.bumps.campaign,
+            campaign_usdc_account: &ctx.accounts.campaign_usdc_account,
+            influencer_usdc_account: &ctx.accounts.influencer_usdc_account,
+            oracle_usdc_account: ctx.accounts.oracle_usdc_account.as_ref(),
+            token_program: &ctx.accounts.token_program,
+            metric_history: ctx.accounts.metric_history.as_mut(),
+        },
+        observed_at,
+        Some(oracle_authority.key()),
+    )
+}
+
+/// Contas para a instrução `update_metric_values`.
+#[derive(Accounts)]
+pub struct UpdateMetricValues<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, referenciar a entrada de oráculo informada e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = oracle @ ErrorCode::UnauthorizedOracle,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Entrada do oráculo no registro (deve coincidir com `campaign.oracle` e estar ativa).
+    #[account(constraint = oracle.active @ ErrorCode::OracleNotRegistered)]
+    pub oracle: Account<'info, OracleEntry>,
+    /// Chave atual do oráculo.
+    #[account(mut, address = oracle.authority @ ErrorCode::UnauthorizedOracle)]
+    pub oracle_authority: Signer<'info>,
+    /// Histórico de métricas da campanha (obrigatório quando o histórico está habilitado).
+    #[account(mut, has_one = campaign @ ErrorCode::InvalidMetricHistory)]
+    pub metric_history: Option<Account<'info, MetricHistory>>,
+    /// Cofre USDC da campanha (fonte dos pagamentos).
+    #[account(mut, constraint = campaign_usdc_account.owner == campaign.key() @ ErrorCode::InvalidTokenAccount)]
+    pub campaign_usdc_account: Account<'info, TokenAccount>,
+    /// Conta USDC do influenciador (destino dos pagamentos).
+    #[account(mut, constraint = influencer_usdc_account.owner == campaign.influencer @ ErrorCode::InvalidTokenAccount)]
+    pub influencer_usdc_account: Account<'info, TokenAccount>,
+    /// Conta USDC do oráculo (recebe a taxa por atualização; obrigatória quando há taxa devida).
+    #[account(mut, constraint = oracle_usdc_account.owner == oracle_authority.key() @ ErrorCode::InvalidTokenAccount)]
+    pub oracle_usdc_account: Option<Account<'info, TokenAccount>>,
+    /// Programa SPL Token.
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,144 @@
+//! # Atualizar Métricas de um Post
+//!
+//! Este módulo define a instrução para o oráculo atualizar as métricas de um post da campanha.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::errors::ErrorCode;
+use crate::instructions::update_campaign_metrics::{process_staged_report, ReportAccounts};
+use crate::state::{Campaign, CampaignPost, CampaignStatus, MetricHistory, MetricValues, OracleEntry};
+
+/// Atualiza as métricas de um post e reprocessa o agregado da campanha.
+///
+/// Apenas a chave atual do oráculo registrado na campanha pode chamar. As métricas do post
+/// substituem as anteriores na soma da campanha e, quando a campanha tem meta do mesmo
+/// contador na plataforma do post, na soma dessa plataforma. O agregado passa pelas mesmas
+/// proteções, pagamentos por marco e taxa de oráculo de `update_campaign_metrics`. Se o
+/// agregado for retido pelo circuit breaker, as métricas do post também ficam retidas até
+/// a revisão.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `UpdatePostMetrics`.
+/// * `likes` - Número atual de curtidas do post.
+/// * `comments` - Número atual de comentários do post.
+/// * `views` - Número atual de visualizações do post.
+/// * `shares` - Número atual de compartilhamentos do post.
+/// * `sequence` - Número de sequência do relatório (estritamente crescente por campanha).
+/// * `observed_at` - Horário em que o oráculo observou as métricas (timestamp Unix).
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `CampaignNotActive` - Campanha não está em `Active`.
+/// * `ContentNotApproved` - Conteúdo da campanha ainda não aprovado pela marca.
+/// * `CampaignExpired` - Prazo da campanha expirou.
+/// * `UnauthorizedOracle` - Assinante não é a chave atual do oráculo da campanha.
+/// * `OracleNotRegistered` - Oráculo desativado no registro global.
+/// * `QuorumRequired` - Campanha exige relatórios via quórum de oráculos.
+/// * `InvalidTokenAccount` - Contas de token não pertencem à campanha/influenciador.
+/// * `InvalidObservationTime` - Horário de observação no futuro.
+/// * `ReportNonceReused` - Sequência igual à do último relatório aceito (duplicado).
+/// * `ReportOutOfOrder` - Sequência menor que a do último relatório aceito.
+/// * `StaleReport` - Observação mais antiga que a última observação aplicada.
+/// * `MetricsDecreased` - Alguma métrica do post é menor que o valor atual.
+/// * `ReportHeldForReview` - Há um relatório retido aguardando revisão.
+/// * `OracleFeeAccountRequired` - Taxa devida sem conta USDC do oráculo informada.
+/// * `MetricHistoryRequired` - Campanha registra histórico e a conta não foi informada.
+/// * `MathOverflow` - Overflow aritmético no agregado ou nos pagamentos.
+pub fn update_post_metrics(
+    ctx: Context<UpdatePostMetrics>,
+    likes: u64,
+    comments: u64,
+    views: u64,
+    shares: u64,
+    sequence: u64,
+    observed_at: i64,
+) -> Result<()> {
+    // Valida status da campanha e deadline
+    let now = Clock::get()?.unix_timestamp;
+    require_eq!(ctx.accounts.campaign.status, CampaignStatus::Active, ErrorCode::CampaignNotActive);
+    require!(ctx.accounts.campaign.content_approved(now), ErrorCode::ContentNotApproved);
+    require!(now < ctx.accounts.campaign.deadline, ErrorCode::CampaignExpired);
+    require!(!ctx.accounts.campaign.quorum_enabled, ErrorCode::QuorumRequired);
+
+    // Rejeita relatórios duplicados, fora de ordem ou com observação antiga
+    ctx.accounts.campaign.record_report_sequence(sequence, observed_at, now)?;
+
+    // Substitui as métricas anteriores do post na soma da campanha e na soma da plataforma
+    let values = MetricValues { likes, comments, views, shares };
+    let readings = ctx.accounts.campaign_post.readings_with(&ctx.accounts.campaign, &values)?;
+    ctx.accounts.campaign.stage_readings(&readings)?;
+
+    let oracle_authority = ctx.accounts.oracle_authority.to_account_info();
+    process_staged_report(
+        &mut ctx.accounts.campaign,
+        ReportAccounts {
+            campaign_bump: ctx.bumps.campaign,
+            campaign_usdc_account: &ctx.accounts.campaign_usdc_account,
+            influencer_usdc_account: &ctx.accounts.influencer_usdc_account,
+            oracle_usdc_account: ctx.accounts.oracle_usdc_account.as_ref(),
+            token_program: &ctx.accounts.token_program,
+            metric_history: ctx.accounts.metric_history.as_mut(),
+        },
+        observed_at,
+        Some(oracle_authority.key()),
+    )?;
+
+    // Agregado retido ou pendente: as métricas do post aguardam a mesma decisão
+    let post = &mut ctx.accounts.campaign_post;
+    if ctx.accounts.campaign.has_unapplied_report() {
+        post.held_metrics = Some(values);
+        ctx.accounts.campaign.held_post = Some(post.key());
+    } else {
+        post.metrics = values;
+    }
+
+    Ok(())
+}
+
+/// Contas para a instrução `update_post_metrics`.
+#[derive(Accounts)]
+pub struct UpdatePostMetrics<'info> {
+    /// Conta da campanha.
+    ///
+    /// Deve ser mutável, referenciar a entrada de oráculo informada e ser uma PDA derivada de
+    /// `["campaign", campaign.influencer, campaign.brand, campaign.name]`.
+    #[account(
+        mut,
+        has_one = oracle @ ErrorCode::UnauthorizedOracle,
+        seeds = [b"campaign", campaign.influencer.key().as_ref(), campaign.brand.key().as_ref(), campaign.name.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    /// Post da campanha a ser atualizado.
+    ///
+    /// PDA derivada de `["campaign_post", campaign, campaign_post.url_hash]`.
+    #[account(
+        mut,
+        has_one = campaign,
+        seeds = [b"campaign_post", campaign.key().as_ref(), campaign_post.url_hash.as_ref()],
+        bump = campaign_post.bump
+    )]
+    pub campaign_post: Account<'info, CampaignPost>,
+    /// Entrada do oráculo no registro (deve coincidir com `campaign.oracle` e estar ativa).
+    #[account(constraint = oracle.active @ ErrorCode::OracleNotRegistered)]
+    pub oracle: Account<'info, OracleEntry>,
+    /// Chave atual do oráculo.
+    #[account(mut, address = oracle.authority @ ErrorCode::UnauthorizedOracle)]
+    pub oracle_authority: Signer<'info>,
+    /// Histórico de métricas da campanha (obrigatório quando o histórico está habilitado).
+    #[account(mut, has_one = campaign @ ErrorCode::InvalidMetricHistory)]
+    pub metric_history: Option<Account<'info, MetricHistory>>,
+    /// Cofre USDC da campanha (fonte dos pagamentos).
+    #[account(mut, constraint = campaign_usdc_account.owner == campaign.key() @ ErrorCode::InvalidTokenAccount)]
+    pub campaign_usdc_account: Account<'info, TokenAccount>,
+    /// Conta USDC do influenciador (destino dos pagamentos).
+    #[account(mut, constraint = influencer_usdc_account.owner == campaign.influencer @ ErrorCode::InvalidTokenAccount)]
+    pub influencer_usdc_account: Account<'info, TokenAccount>,
+    /// Conta USDC do oráculo (recebe a taxa por atualização; obrigatória quando há taxa devida).
+    #[account(mut, constraint = oracle_usdc_account.owner == oracle_authority.key() @ ErrorCode::InvalidTokenAccount)]
+    pub oracle_usdc_account: Option<Account<'info, TokenAccount>>,
+    /// Programa SPL Token.
+    pub token_program: Program<'info, Token>,
+}
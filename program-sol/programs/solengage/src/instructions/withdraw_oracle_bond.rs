@@ -0,0 +1,82 @@
+//! # Sacar Caução do Oráculo
+//!
+//! Este módulo define a instrução para um oráculo aposentado recuperar sua caução.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::errors::ErrorCode;
+use crate::state::{OracleBond, OracleEntry, ORACLE_UNBONDING_PERIOD};
+
+/// Saca tokens da caução de um oráculo desativado.
+///
+/// Enquanto o oráculo está ativo ele pode reportar métricas, então a caução
+/// fica travada; o admin precisa desativá-lo no registro antes do saque.
+/// Depois da desativação, a caução continua travada por `ORACLE_UNBONDING_PERIOD`
+/// (no mínimo a maior janela de contestação), período em que contestações ainda
+/// executam o slashing.
+///
+/// # Argumentos
+///
+/// * `ctx` - Contexto da instrução `WithdrawOracleBond`.
+/// * `amount` - Quantidade de tokens a sacar.
+///
+/// # Erros
+///
+/// Retorna `ErrorCode` se ocorrer:
+/// * `InvalidAmount` - Quantidade igual a zero.
+/// * `OracleStillActive` - Oráculo ainda está ativo no registro.
+/// * `OracleBondUnbonding` - Período de unbonding após a desativação ainda em curso.
+/// * `InsufficientBond` - Quantidade maior que o saldo da caução.
+/// * `UnauthorizedOracle` - Assinante não é a chave atual do oráculo.
+/// * `InvalidTokenAccount` - Conta de destino não pertence ao oráculo.
+pub fn withdraw_oracle_bond(ctx: Context<WithdrawOracleBond>, amount: u64) -> Result<()> {
+    // Validações de segurança
+    require!(amount > 0, ErrorCode::InvalidAmount);
+    require!(!ctx.accounts.oracle.active, ErrorCode::OracleStillActive);
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now >= ctx.accounts.oracle.deactivated_at.saturating_add(ORACLE_UNBONDING_PERIOD),
+        ErrorCode::OracleBondUnbonding
+    );
+    require!(amount <= ctx.accounts.bond_vault.amount, ErrorCode::InsufficientBond);
+
+    let oracle_key = ctx.accounts.oracle.key();
+    let seeds = &[b"oracle_bond".as_ref(), oracle_key.as_ref(), &[ctx.accounts.oracle_bond.bump]];
+    let signer = &[&seeds[..]];
+
+    // CPI para transferir tokens do cofre da caução ao oráculo
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.bond_vault.to_account_info(),
+        to: ctx.accounts.authority_token_account.to_account_info(),
+        authority: ctx.accounts.oracle_bond.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+    token::transfer(cpi_ctx, amount)?;
+
+    Ok(())
+}
+
+/// Contas para a instrução `withdraw_oracle_bond`.
+#[derive(Accounts)]
+pub struct WithdrawOracleBond<'info> {
+    /// Entrada do oráculo no registro global.
+    pub oracle: Account<'info, OracleEntry>,
+    /// Caução do oráculo.
+    #[account(
+        has_one = oracle,
+        seeds = [b"oracle_bond", oracle.key().as_ref()],
+        bump = oracle_bond.bump
+    )]
+    pub oracle_bond: Account<'info, OracleBond>,
+    /// Cofre de tokens da caução.
+    #[account(mut, address = oracle_bond.vault @ ErrorCode::InvalidTokenAccount)]
+    pub bond_vault: Account<'info, TokenAccount>,
+    /// Conta de tokens do oráculo (destino do saque).
+    #[account(mut, constraint = authority_token_account.owner == oracle_authority.key() @ ErrorCode::InvalidTokenAccount)]
+    pub authority_token_account: Account<'info, TokenAccount>,
+    /// Chave atual do oráculo (assinante).
+    #[account(address = oracle.authority @ ErrorCode::UnauthorizedOracle)]
+    pub oracle_authority: Signer<'info>,
+    /// Programa SPL Token.
+    pub token_program: Program<'info, Token>,
+}
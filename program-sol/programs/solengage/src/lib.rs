@@ -6,16 +6,60 @@
 //! ## Architecture
 //!
 //! - **Campaign PDA**: Stores campaign state, derived from `["campaign", influencer, brand, name]`
-//! - **Oracle**: Authorized signer that updates campaign metrics and triggers payments
-//! - **Progressive Payments**: Automatic 10% milestone payments (10%, 20%, ..., 100%)
+//! - **Oracle Registry**: Admin-managed registry of oracles, seeds `["oracle_registry"]`
+//! - **Oracle**: Registered signer that updates campaign metrics and triggers payments;
+//!   campaigns reference its registry entry, so its key can be rotated
+//! - **Progressive Payments**: Automatic milestone payments at 10%, 20%, ..., 100% progress,
+//!   priced by a configurable payout curve (linear by default)
+//! - **Oracle Bonds**: Oracles lock tokens that are slashed to compensate brands or
+//!   influencers when an arbiter upholds a challenge against their reports
+//! - **Metric Guards**: Reports must be monotonic; abnormal jumps are held for brand/admin review
+//! - **Campaign Posts**: Optional per-post PDAs `["campaign_post", campaign, url_hash]`; the oracle
+//!   reports each post and campaign progress is computed from the sum across posts
+//! - **Metric Entries**: Targets and values stored as weighted `(kind, platform)` entries, so new
+//!   metric kinds (saves, watch minutes, link clicks, conversions...) need no layout change
+//! - **Optimistic Reports**: With a challenge window, reports stay pending so the brand can
+//!   freeze them for the registry arbiter; unchallenged reports are applied by a permissionless crank
+//! - **External Feeds**: A campaign can pin a third-party feed account (owner program,
+//!   authority, value, timestamp) whose fresh values are applied to one metric entry by a permissionless crank
+//! - **Oracle Liveness**: If the oracle misses its heartbeat, the influencer can settle earned
+//!   tranches with metrics co-signed by the brand or the arbiter; Active campaigns can require
+//!   a cancellation notice period
+//! - **Brand Offers**: Brands can propose a campaign (`Offered`), optionally escrowing the budget;
+//!   the influencer accepts or rejects it
+//! - **Terms Negotiation**: While in Draft, either party proposes budget, targets and deadline;
+//!   the other accepts the exact proposal version, and only accepted terms can be funded
+//! - **Amendments**: Brand and influencer can jointly extend the deadline, change targets or
+//!   top up the budget of an Active campaign; reached milestones are re-settled without double pay
+//! - **Content Review**: Optionally, the influencer submits the content (URL hash and post id)
+//!   and metrics only count once the brand approves it or its review window elapses
+//! - **Kill Fee**: An agreed share of the unpaid budget, optionally growing toward the deadline,
+//!   is paid to the influencer when the brand cancels after activation
+//! - **Influencer Withdrawal**: The influencer can walk away from a running campaign (`Withdrawn`);
+//!   the unpaid budget is refunded and a permanent `["withdrawal", campaign]` record is kept
+//! - **Settlements**: Brand and influencer can jointly end a campaign early with a negotiated
+//!   split of the unpaid budget; the campaign account is closed
+//! - **Disputes**: Either party can freeze an Active or Paused campaign (`Disputed`); the registry
+//!   arbiter splits the unpaid budget between influencer and brand and the campaign is cancelled
+//! - **Metric History**: Optional ring buffer `["metric_history", campaign]` of applied reports,
+//!   so brands can audit how progress evolved and why each tranche was paid
 //! - **USDC Integration**: All payments handled via SPL Token (USDC)
 //!
 //! ## Campaign Lifecycle
 //!
 //! 1. **Draft** → Influencer creates campaign via `create_campaign`
+//!    (or **Offered** → brand proposes it via `create_offer`, influencer answers via `accept_offer`/`reject_offer`)
 //! 2. **Active** → Brand funds campaign via `brand_pay_campaign`
+//!    (with a content review window, metrics count only after `submit_content`/`review_content`)
 //! 3. **Completed** → Auto-closes when metrics reach 100% via `update_campaign_metrics`
-//! 4. **Cancelled** → Brand can cancel anytime via `cancel_campaign`
+//! 4. **Cancelled** → Brand can cancel anytime via `cancel_campaign` (paying the agreed kill fee once Active)
+//!
+//! An Active campaign can be **Paused** by the brand (`pause_campaign`/`resume_campaign`)
+//! within a pause budget agreed in Draft; the paused time extends the deadline.
+//! Either party can move an Active or Paused campaign to **Disputed** (`open_dispute`);
+//! the arbiter ends it with `resolve_dispute`, which cancels the campaign.
+//! The influencer can end an Active or Paused campaign with `influencer_withdraw` (**Withdrawn**),
+//! and both parties together can settle and close it with `settle_campaign`.
 //!
 //! ## Security Features
 //!
@@ -26,7 +70,7 @@
 
 use anchor_lang::prelude::*;
 
-/// Declares the program ID for the Solengage program.
+// Declares the program ID for the Solengage program.
 declare_id!("2e3n681eydMY7t35bHD53eLfaifH3yQzQEsmgfhKV7E5");
 
 pub mod errors;
@@ -34,6 +78,7 @@ pub mod state;
 pub mod instructions;
 
 use instructions::*;
+use state::{ChallengeEvidence, MetricFeed, MetricGuards, MetricReading, MetricTarget, MetricValues, PayoutCurve, Platform, SignedMetricReport};
 
 #[program]
 pub mod solengage {
@@ -59,7 +104,7 @@ pub mod solengage {
     /// * `campaign` - PDA to initialize, seeds: ["campaign", influencer, brand, name]
     /// * `influencer` - Signer and payer (campaign creator)
     /// * `brand` - Brand public key (not signer yet)
-    /// * `oracle` - Authorized oracle public key for metric updates
+    /// * `oracle` - Active `OracleEntry` from the oracle registry
     ///
     /// # Errors
     ///
@@ -70,6 +115,8 @@ pub mod solengage {
     /// * `InvalidAmount` - Amount is 0
     /// * `InvalidDeadline` - Deadline is in the past
     /// * `NoTargetsSet` - All target metrics are 0
+    /// * `OracleNotRegistered` - Oracle entry is deactivated
+    /// * `OracleConflictOfInterest` - Oracle authority is the influencer or the brand
     ///
     /// # Example
     ///
@@ -89,6 +136,7 @@ pub mod solengage {
     ///     .accounts({...})
     ///     .rpc();
     /// ```
+    #[allow(clippy::too_many_arguments)]
     pub fn create_campaign(
         ctx: Context<CreateCampaign>,
         name: String,
@@ -120,7 +168,12 @@ pub mod solengage {
     /// Activates a campaign by transferring USDC from brand to campaign vault.
     ///
     /// Transitions campaign from Draft → Active status. The full campaign amount
-    /// must be transferred at once (no partial funding).
+    /// must be transferred at once (no partial funding), plus the oracle fee
+    /// reserve (`oracle_fee_cap`) when an oracle fee is configured.
+    ///
+    /// # Arguments
+    ///
+    /// * `terms_version` - Terms version reviewed by the brand (must equal campaign.terms_version)
     ///
     /// # Accounts
     ///
@@ -128,6 +181,8 @@ pub mod solengage {
     /// * `brand` - Brand signer (must match campaign.brand)
     /// * `brand_usdc_account` - Brand's USDC token account (source)
     /// * `campaign_usdc_account` - Campaign vault token account (destination, owned by campaign PDA)
+    /// * `oracle_bond` - Bond of `campaign.oracle`, seeds: ["oracle_bond", campaign.oracle]
+    /// * `bond_vault` - Bond token account, seeds: ["oracle_bond_vault", campaign.oracle]
     /// * `token_program` - SPL Token program
     ///
     /// # State Transitions
@@ -138,20 +193,26 @@ pub mod solengage {
     ///
     /// * `CampaignNotDraft` - Campaign is not in Draft status
     /// * `CampaignExpired` - Deadline has passed
+    /// * `TermsProposalPending` - A terms proposal awaits acceptance
+    /// * `TermsVersionMismatch` - Terms changed after the reviewed version
+    /// * `OracleBondMintMismatch` - The oracle's bond is not in the vault's mint
+    /// * `OracleBondTooLow` - The oracle's bond holds less than `MIN_ORACLE_BOND`
     /// * Anchor errors if token transfer fails (insufficient balance, etc.)
     ///
     /// # Security
     ///
     /// - Validates brand signature matches campaign.brand
     /// - Validates deadline hasn't expired
-    /// - Transfers exact amount_usdc (no partial funding)
-    pub fn brand_pay_campaign(ctx: Context<BrandPayCampaign>) -> Result<()> {
-        instructions::brand_pay_campaign::brand_pay_campaign(ctx)
+    /// - Funds only the terms version the brand reviewed
+    /// - Requires the oracle to be bonded in the campaign's USDC (slashing compensates in kind)
+    /// - Transfers exact amount_usdc + oracle_fee_cap (no partial funding)
+    pub fn brand_pay_campaign(ctx: Context<BrandPayCampaign>, terms_version: u32) -> Result<()> {
+        instructions::brand_pay_campaign::brand_pay_campaign(ctx, terms_version)
     }
 
     /// Updates campaign metrics and triggers automatic milestone payments.
     ///
-    /// Only callable by the authorized oracle. Calculates progress based on updated
+    /// Only callable by the current key of the campaign's registered oracle. Calculates progress based on updated
     /// metrics and pays out any newly achieved milestones (10%, 20%, ..., 100%).
     /// Automatically closes the campaign when 100% is reached.
     ///
@@ -161,15 +222,20 @@ pub mod solengage {
     /// * `comments` - Current number of comments
     /// * `views` - Current number of views
     /// * `shares` - Current number of shares
+    /// * `sequence` - Report sequence number (strictly increasing per campaign)
+    /// * `observed_at` - When the oracle observed the values (Unix timestamp)
     ///
     /// # Accounts
     ///
     /// * `campaign` - Campaign PDA (must be Active, oracle validated via has_one)
-    /// * `oracle` - Oracle signer (must match campaign.oracle)
+    /// * `oracle` - Oracle registry entry (must match campaign.oracle and be active)
+    /// * `oracle_authority` - Oracle signer (must match oracle.authority)
     /// * `campaign_usdc_account` - Campaign vault (source for payments)
     /// * `influencer_usdc_account` - Influencer's USDC account (payment destination)
+    /// * `oracle_usdc_account` - Optional oracle USDC account (receives the per-update fee)
+    /// * `metric_history` - Campaign's metric history (required when history is enabled)
     /// * `token_program` - SPL Token program
-    /// * `system_program` - System program (for account closure)
+    /// * `system_program` - System program
     ///
     /// # State Transitions
     ///
@@ -178,8 +244,20 @@ pub mod solengage {
     /// # Errors
     ///
     /// * `CampaignNotActive` - Campaign is not in Active status
+    /// * `ContentNotApproved` - Content review enabled and content not yet approved
     /// * `CampaignExpired` - Deadline has passed
-    /// * `UnauthorizedOracle` - Oracle signer doesn't match campaign.oracle
+    /// * `UnauthorizedOracle` - Signer doesn't match the oracle entry's current authority
+    /// * `OracleNotRegistered` - Oracle entry is deactivated
+    /// * `QuorumRequired` - Campaign requires reports through `submit_metric_report`
+    /// * `PostMetricsRequired` - Campaign has posts, report through `update_post_metrics`
+    /// * `MetricHistoryRequired` - History is enabled but `metric_history` is missing
+    /// * `InvalidObservationTime` - `observed_at` is in the future
+    /// * `ReportNonceReused` - `sequence` equals `campaign.report_sequence` (duplicate)
+    /// * `ReportOutOfOrder` - `sequence` is lower than `campaign.report_sequence`
+    /// * `StaleReport` - `observed_at` is older than `campaign.last_observed_at`
+    /// * `MetricsDecreased` - A counter is lower than its current value
+    /// * `ReportHeldForReview` - A previous report is awaiting review
+    /// * `ReportPendingChallenge` - A previous report is still in its challenge window
     ///
     /// # Payment Logic
     ///
@@ -188,18 +266,21 @@ pub mod solengage {
     /// - Each milestone = 10% of total budget
     /// - payment_milestones[i] prevents double-payment
     ///
-    /// # Auto-Close
+    /// # Completion
     ///
-    /// When progress reaches 100%, the campaign account is closed and rent
-    /// is refunded to the oracle as compensation.
+    /// When progress reaches 100%, the campaign becomes Completed and stays open
+    /// for `ORACLE_CHALLENGE_PERIOD`, so the completing report can still be
+    /// challenged; `close_campaign` then refunds the rent to the oracle authority.
     pub fn update_campaign_metrics(
         ctx: Context<UpdateCampaignMetrics>,
         likes: u64,
         comments: u64,
         views: u64,
         shares: u64,
+        sequence: u64,
+        observed_at: i64,
     ) -> Result<()> {
-        instructions::update_campaign_metrics::update_campaign_metrics(ctx, likes, comments, views, shares)
+        instructions::update_campaign_metrics::update_campaign_metrics(ctx, likes, comments, views, shares, sequence, observed_at)
     }
 
     /// Cancels a campaign and refunds remaining USDC to the brand.
@@ -209,26 +290,36 @@ pub mod solengage {
     ///
     /// # Accounts
     ///
-    /// * `campaign` - Campaign PDA (cannot be Completed)
+    /// * `campaign` - Campaign PDA (must be Draft, Offered, Active or Paused)
     /// * `brand` - Brand signer (must match campaign.brand)
     /// * `brand_usdc_account` - Brand's USDC account (refund destination)
     /// * `campaign_usdc_account` - Campaign vault (refund source)
+    /// * `influencer_usdc_account` - Influencer's USDC account (optional; required when a kill fee is owed)
     /// * `token_program` - SPL Token program
     ///
     /// # State Transitions
     ///
     /// * `Draft` → `Cancelled` (no refund needed)
-    /// * `Active` → `Cancelled` (refunds remaining USDC)
+    /// * `Offered` → `Cancelled` (refunds the escrow, if any)
+    /// * `Active` / `Paused` → `Cancelled` (pays the kill fee, refunds remaining USDC; after
+    ///   `request_cancellation` and the notice period when the campaign has one)
     ///
     /// # Errors
     ///
     /// * `CampaignAlreadyCompleted` - Cannot cancel completed campaigns
+    /// * `CampaignDisputed` - Cannot cancel while a dispute is open
+    /// * `CampaignNotActive` - Campaign is already Cancelled or Withdrawn
     /// * `UnauthorizedBrand` - Signer doesn't match campaign.brand
+    /// * `ReportHeldForReview` - A held report must be resolved first
+    /// * `ReportPendingChallenge` - A pending report must be finalized first
+    /// * `CancelNoticePending` - Active campaign with a notice period not yet elapsed (or not requested)
+    /// * `KillFeeAccountRequired` - A kill fee is owed but no influencer account was given
     ///
     /// # Security
     ///
     /// - Only brand can cancel (influencer cannot)
-    /// - Refund amount = amount_usdc - paid_amount (ensures fairness)
+    /// - Kill fee = (amount_usdc - paid_amount) * current kill fee bps, paid to the influencer
+    /// - Refund amount = amount_usdc - paid_amount - kill fee + unused oracle fee reserve (ensures fairness)
     /// - Uses PDA signer pattern for vault authority
     pub fn cancel_campaign(ctx: Context<CancelCampaign>) -> Result<()> {
         instructions::cancel_campaign::cancel_campaign(ctx)
@@ -236,21 +327,26 @@ pub mod solengage {
 
     /// Closes a completed campaign account and refunds rent to oracle.
     ///
-    /// This instruction is typically called automatically by update_campaign_metrics
-    /// when progress reaches 100%. Can also be called manually if needed.
+    /// Completed campaigns stay open for `ORACLE_CHALLENGE_PERIOD` after reaching
+    /// 100%, so the completing report can still be challenged; anyone can close
+    /// them afterwards.
     ///
     /// # Accounts
     ///
     /// * `campaign` - Campaign PDA (must be Completed, will be closed)
-    /// * `oracle` - Oracle account (receives rent refund)
+    /// * `oracle` - Oracle registry entry (must match campaign.oracle)
+    /// * `oracle_authority` - Oracle's current key (receives rent refund)
     ///
     /// # State Requirements
     ///
     /// * Campaign status must be `Completed`
+    /// * `ORACLE_CHALLENGE_PERIOD` must have elapsed since `completed_at`
     ///
     /// # Errors
     ///
     /// * `CampaignNotInTerminalState` - Campaign is not Completed
+    /// * `ChallengePeriodActive` - The challenge period after completion is still running
+    /// * `OracleFeeReserveNotRefunded` - Unused oracle fee reserve not yet refunded
     ///
     /// # Rent Refund
     ///
@@ -259,4 +355,1460 @@ pub mod solengage {
     pub fn close_campaign(ctx: Context<CloseCampaign>) -> Result<()> {
         instructions::close_campaign::close_campaign(ctx)
     }
+
+    /// Sets the payout curve of a Draft campaign.
+    ///
+    /// The curve maps progress (basis points) to the cumulative share of
+    /// `amount_usdc` released at each 10% milestone. Campaigns default to
+    /// `Linear`, which keeps the legacy 10%-per-10% schedule.
+    ///
+    /// # Arguments
+    ///
+    /// * `payout_curve` - `Linear`, `Convex`, `Concave`, or `Piecewise { points }`
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be in Draft status)
+    /// * `influencer` - Influencer signer (must match campaign.influencer)
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotDraft` - Campaign is not in Draft status
+    /// * `UnauthorizedInfluencer` - Signer doesn't match campaign.influencer
+    /// * `InvalidPayoutCurve` - Piecewise points are not monotonic or don't end at (10_000, 10_000)
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Back-loaded: 20% of the budget at 50% progress, everything at 100%
+    /// program.methods
+    ///     .setPayoutCurve({ piecewise: { points: [
+    ///         { progressBps: 5000, payoutBps: 2000 },
+    ///         { progressBps: 10000, payoutBps: 10000 },
+    ///     ] } })
+    ///     .accounts({ campaign, influencer })
+    ///     .rpc();
+    /// ```
+    pub fn set_payout_curve(ctx: Context<SetPayoutCurve>, payout_curve: PayoutCurve) -> Result<()> {
+        instructions::set_payout_curve::set_payout_curve(ctx, payout_curve)
+    }
+
+    /// Initializes the global oracle registry.
+    ///
+    /// Only the program upgrade authority can initialize the registry and
+    /// becomes its admin.
+    ///
+    /// # Accounts
+    ///
+    /// * `oracle_registry` - PDA to initialize, seeds: ["oracle_registry"]
+    /// * `admin` - Signer and payer (must be the program upgrade authority)
+    /// * `program` - This program
+    /// * `program_data` - This program's ProgramData account
+    ///
+    /// # Errors
+    ///
+    /// * `UnauthorizedAdmin` - Signer is not the upgrade authority
+    pub fn initialize_oracle_registry(ctx: Context<InitializeOracleRegistry>) -> Result<()> {
+        instructions::initialize_oracle_registry::initialize_oracle_registry(ctx)
+    }
+
+    /// Registers a new oracle in the registry.
+    ///
+    /// # Arguments
+    ///
+    /// * `authority` - Signing key the oracle will use for metric reports
+    ///
+    /// # Accounts
+    ///
+    /// * `oracle_registry` - Registry PDA
+    /// * `oracle_entry` - PDA to initialize, seeds: ["oracle", oracle_registry.oracle_count (u64 LE)]
+    /// * `admin` - Registry admin signer and payer
+    ///
+    /// # Errors
+    ///
+    /// * `UnauthorizedAdmin` - Signer is not the registry admin
+    pub fn register_oracle(ctx: Context<RegisterOracle>, authority: Pubkey) -> Result<()> {
+        instructions::register_oracle::register_oracle(ctx, authority)
+    }
+
+    /// Rotates the signing key of a registered oracle.
+    ///
+    /// Takes effect immediately for every campaign referencing the entry;
+    /// the previous key can no longer submit reports.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_authority` - New signing key
+    ///
+    /// # Errors
+    ///
+    /// * `UnauthorizedAdmin` - Signer is not the registry admin
+    pub fn rotate_oracle_key(ctx: Context<RotateOracleKey>, new_authority: Pubkey) -> Result<()> {
+        instructions::rotate_oracle_key::rotate_oracle_key(ctx, new_authority)
+    }
+
+    /// Activates or deactivates a registered oracle.
+    ///
+    /// Deactivated oracles cannot be chosen by new campaigns nor submit reports.
+    /// Deactivation starts the bond's unbonding period; reactivation cancels it.
+    ///
+    /// # Errors
+    ///
+    /// * `UnauthorizedAdmin` - Signer is not the registry admin
+    pub fn set_oracle_active(ctx: Context<SetOracleActive>, active: bool) -> Result<()> {
+        instructions::set_oracle_active::set_oracle_active(ctx, active)
+    }
+
+    /// Requires M-of-N oracle reports for a Draft campaign.
+    ///
+    /// The quorum's `OracleEntry` accounts are passed as remaining accounts.
+    /// Once set, `update_campaign_metrics` is rejected and metrics are only
+    /// accepted through `submit_metric_report`.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - Number of agreeing reports required (M)
+    /// * `tolerance_bps` - Maximum deviation from the median in basis points
+    ///   (`MEDIAN_ONLY_TOLERANCE` applies the median without an agreement check)
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be in Draft status)
+    /// * `oracle_quorum` - PDA to initialize, seeds: ["oracle_quorum", campaign]
+    /// * `influencer` - Influencer signer and payer
+    /// * remaining accounts - 1 to 5 active `OracleEntry` accounts (N)
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotDraft` - Campaign is not in Draft status
+    /// * `InvalidQuorum` - Empty, too many or duplicate oracles, or threshold outside 1..=N
+    /// * `InvalidOracleAccount` - A remaining account is not an `OracleEntry`
+    /// * `OracleNotRegistered` - An oracle entry is deactivated
+    /// * `OracleConflictOfInterest` - An oracle authority is the influencer or the brand
+    pub fn set_oracle_quorum(ctx: Context<SetOracleQuorum>, threshold: u8, tolerance_bps: u16) -> Result<()> {
+        instructions::set_oracle_quorum::set_oracle_quorum(ctx, threshold, tolerance_bps)
+    }
+
+    /// Submits one oracle's metrics to the campaign's pending quorum report.
+    ///
+    /// When at least `threshold` reports agree within tolerance of the median,
+    /// the median values are applied through the same progress and payout
+    /// logic as `update_campaign_metrics`, and a new round starts. The oracle fee,
+    /// if any, goes to the oracle whose report completed the quorum. The aggregate
+    /// uses the round as its report sequence and the lower median of the
+    /// submissions' observation times, like any other report.
+    ///
+    /// # Arguments
+    ///
+    /// * `likes` - Current number of likes
+    /// * `comments` - Current number of comments
+    /// * `views` - Current number of views
+    /// * `shares` - Current number of shares
+    /// * `observed_at` - When the oracle observed the metrics (Unix timestamp)
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Active)
+    /// * `oracle_quorum` - Quorum PDA, seeds: ["oracle_quorum", campaign]
+    /// * `oracle` - Oracle registry entry (must be in the quorum and active)
+    /// * `oracle_authority` - Oracle signer (must match oracle.authority)
+    /// * `metric_history` - Campaign's metric history (required when history is enabled)
+    /// * `campaign_usdc_account` - Campaign vault (owned by the campaign PDA)
+    /// * `influencer_usdc_account` - Influencer's USDC account (owned by campaign.influencer)
+    /// * `oracle_usdc_account` - Optional oracle USDC account (receives the per-update fee)
+    /// * `token_program` - SPL Token program
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotActive` - Campaign is not in Active status
+    /// * `ContentNotApproved` - Content review enabled and content not yet approved
+    /// * `CampaignExpired` - Deadline has passed
+    /// * `PostMetricsRequired` - Campaign has posts, report through `update_post_metrics`
+    /// * `InvalidObservationTime` - `observed_at` is in the future
+    /// * `StaleReport` - `observed_at` is older than `campaign.last_observed_at`
+    /// * `OracleNotInQuorum` - Oracle entry is not part of the quorum
+    /// * `UnauthorizedOracle` - Signer doesn't match the oracle entry's current authority
+    /// * `InvalidTokenAccount` - Token accounts don't belong to the campaign / influencer
+    /// * `MetricsDecreased` - Aggregated counters are lower than the current values
+    /// * `ReportHeldForReview` - A previous report is awaiting review
+    /// * `OracleFeeAccountRequired` - A fee is owed but no oracle USDC account was given
+    /// * `MetricHistoryRequired` - History is enabled but `metric_history` is missing
+    pub fn submit_metric_report(
+        ctx: Context<SubmitMetricReport>,
+        likes: u64,
+        comments: u64,
+        views: u64,
+        shares: u64,
+        observed_at: i64,
+    ) -> Result<()> {
+        instructions::submit_metric_report::submit_metric_report(ctx, likes, comments, views, shares, observed_at)
+    }
+
+    /// Applies a metric report signed off-chain by the oracle and relayed by anyone.
+    ///
+    /// The oracle signs `report.message()` with Ed25519 (its key can stay in
+    /// cold storage). The relayer must place an Ed25519 program instruction
+    /// verifying that signature immediately before this instruction; the
+    /// program checks through the instructions sysvar that the verified key
+    /// and message match the oracle's current authority and `report`.
+    ///
+    /// # Arguments
+    ///
+    /// * `report` - Signed report (campaign, metrics, observation time, nonce)
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Active, oracle validated via has_one)
+    /// * `oracle` - Oracle registry entry (must match campaign.oracle and be active)
+    /// * `oracle_authority` - Oracle's current key (not a signer)
+    /// * `relayer` - Any signer paying for the transaction
+    /// * `metric_history` - Campaign's metric history (required when history is enabled)
+    /// * `campaign_usdc_account` - Campaign vault (owned by the campaign PDA)
+    /// * `influencer_usdc_account` - Influencer's USDC account (owned by campaign.influencer)
+    /// * `oracle_usdc_account` - Optional USDC account of the oracle authority (receives the per-update fee)
+    /// * `instructions_sysvar` - Instructions sysvar
+    /// * `token_program` - SPL Token program
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotActive` - Campaign is not in Active status
+    /// * `ContentNotApproved` - Content review enabled and content not yet approved
+    /// * `CampaignExpired` - Deadline has passed
+    /// * `QuorumRequired` - Campaign requires reports through `submit_metric_report`
+    /// * `PostMetricsRequired` - Campaign has posts, report through `update_post_metrics`
+    /// * `ReportCampaignMismatch` - Report was signed for another campaign
+    /// * `InvalidObservationTime` - `observed_at` is in the future
+    /// * `ReportNonceReused` - Nonce equals `campaign.report_sequence` (replay)
+    /// * `ReportOutOfOrder` - Nonce is lower than `campaign.report_sequence`
+    /// * `StaleReport` - `observed_at` is older than `campaign.last_observed_at`
+    /// * `InvalidSignatureInstruction` - Missing or malformed Ed25519 instruction
+    /// * `InvalidReportSignature` - Signed by another key or over another message
+    /// * `MetricsDecreased` - A counter is lower than its current value
+    /// * `ReportHeldForReview` - A previous report is awaiting review
+    /// * `OracleFeeAccountRequired` - A fee is owed but no oracle USDC account was given
+    /// * `MetricHistoryRequired` - History is enabled but `metric_history` is missing
+    pub fn submit_signed_metrics(ctx: Context<SubmitSignedMetrics>, report: SignedMetricReport) -> Result<()> {
+        instructions::submit_signed_metrics::submit_signed_metrics(ctx, report)
+    }
+
+    /// Configures the sanity rules applied to metric reports (Draft only).
+    ///
+    /// Reports that decrease any counter are rejected when `enforce_monotonic` is set.
+    /// Reports whose progress grows more than `max_progress_per_update` percentage
+    /// points, or faster than `max_progress_per_hour` points per hour since the last
+    /// update, trip the circuit breaker and are held for review instead of paying.
+    ///
+    /// # Arguments
+    ///
+    /// * `metric_guards` - Monotonic flag and growth thresholds (0 disables a threshold)
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be in Draft status)
+    /// * `influencer` - Influencer signer (must match campaign.influencer)
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotDraft` - Campaign is not in Draft status
+    /// * `UnauthorizedInfluencer` - Signer doesn't match campaign.influencer
+    /// * `InvalidMetricGuards` - A threshold exceeds 100 percentage points
+    pub fn set_metric_guards(ctx: Context<SetMetricGuards>, metric_guards: MetricGuards) -> Result<()> {
+        instructions::set_metric_guards::set_metric_guards(ctx, metric_guards)
+    }
+
+    /// Approves or discards a report held by the circuit breaker or challenged by the brand.
+    ///
+    /// Approving applies the held metrics, pays any newly reached milestones and
+    /// charges the oracle fee; discarding keeps the previous metrics and charges no fee. Either way the oracle can report again.
+    /// Challenged reports can only be resolved by the registry arbiter.
+    ///
+    /// # Arguments
+    ///
+    /// * `approve` - `true` to apply the held report, `false` to discard it
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Active with a held report)
+    /// * `oracle_registry` - Oracle registry (provides the admin and the arbiter)
+    /// * `oracle` - Oracle registry entry referenced by the campaign
+    /// * `oracle_authority` - Oracle's current key
+    /// * `reviewer` - Brand or registry admin signer (registry arbiter for challenged reports)
+    /// * `campaign_post` - Optional post whose report is held (required for post reports)
+    /// * `metric_history` - Campaign's metric history (required when history is enabled)
+    /// * `campaign_usdc_account` - Campaign vault (owned by the campaign PDA)
+    /// * `influencer_usdc_account` - Influencer's USDC account (owned by campaign.influencer)
+    /// * `oracle_usdc_account` - Optional USDC account of the reporting oracle (receives the per-update fee)
+    /// * `token_program` - SPL Token program
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotActive` - Campaign is not in Active status
+    /// * `NoHeldReport` - There is no held report
+    /// * `UnauthorizedReviewer` - Signer is neither the brand nor the registry admin
+    /// * `UnauthorizedArbiter` - Report was challenged and signer is not the registry arbiter
+    /// * `HeldPostMismatch` - Post missing or different from the held report's post
+    /// * `OracleFeeAccountRequired` - A fee is owed but no oracle USDC account was given
+    /// * `MetricHistoryRequired` - History is enabled but `metric_history` is missing
+    pub fn resolve_held_report(ctx: Context<ResolveHeldReport>, approve: bool) -> Result<()> {
+        instructions::resolve_held_report::resolve_held_report(ctx, approve)
+    }
+
+    /// Sets the key whose signed evidence upholds challenges against oracles.
+    ///
+    /// # Arguments
+    ///
+    /// * `arbiter` - New arbiter key
+    ///
+    /// # Accounts
+    ///
+    /// * `oracle_registry` - Oracle registry PDA
+    /// * `admin` - Registry admin signer
+    ///
+    /// # Errors
+    ///
+    /// * `UnauthorizedAdmin` - Signer is not the registry admin
+    pub fn set_arbiter(ctx: Context<SetArbiter>, arbiter: Pubkey) -> Result<()> {
+        instructions::set_arbiter::set_arbiter(ctx, arbiter)
+    }
+
+    /// Creates the bond of a registered oracle, denominated in `mint`.
+    ///
+    /// # Accounts
+    ///
+    /// * `oracle` - Oracle registry entry
+    /// * `oracle_bond` - Bond PDA to initialize, seeds: ["oracle_bond", oracle]
+    /// * `bond_vault` - Token account PDA to initialize, seeds: ["oracle_bond_vault", oracle]
+    /// * `mint` - Mint of the bonded token
+    /// * `oracle_authority` - Oracle's current key (signer and payer)
+    ///
+    /// # Errors
+    ///
+    /// * `UnauthorizedOracle` - Signer is not the oracle's current authority
+    pub fn initialize_oracle_bond(ctx: Context<InitializeOracleBond>) -> Result<()> {
+        instructions::initialize_oracle_bond::initialize_oracle_bond(ctx)
+    }
+
+    /// Locks tokens in the oracle's bond.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Amount of tokens to lock
+    ///
+    /// # Accounts
+    ///
+    /// * `oracle` - Oracle registry entry
+    /// * `oracle_bond` - Oracle bond PDA
+    /// * `bond_vault` - Bond token account
+    /// * `authority_token_account` - Oracle's token account (source)
+    /// * `oracle_authority` - Oracle's current key (signer)
+    /// * `token_program` - SPL Token program
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidAmount` - Amount is 0
+    /// * `UnauthorizedOracle` - Signer is not the oracle's current authority
+    /// * `InvalidTokenAccount` - Token accounts don't belong to the bond / oracle
+    pub fn deposit_oracle_bond(ctx: Context<DepositOracleBond>, amount: u64) -> Result<()> {
+        instructions::deposit_oracle_bond::deposit_oracle_bond(ctx, amount)
+    }
+
+    /// Withdraws tokens from the bond of a deactivated oracle.
+    ///
+    /// The bond stays locked, and can still be slashed, for `ORACLE_UNBONDING_PERIOD`
+    /// after deactivation.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Amount of tokens to withdraw
+    ///
+    /// # Accounts
+    ///
+    /// * `oracle` - Oracle registry entry (must be deactivated)
+    /// * `oracle_bond` - Oracle bond PDA
+    /// * `bond_vault` - Bond token account
+    /// * `authority_token_account` - Oracle's token account (destination)
+    /// * `oracle_authority` - Oracle's current key (signer)
+    /// * `token_program` - SPL Token program
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidAmount` - Amount is 0
+    /// * `OracleStillActive` - Oracle entry is still active
+    /// * `OracleBondUnbonding` - Unbonding period after deactivation not over
+    /// * `InsufficientBond` - Amount exceeds the bond balance
+    /// * `UnauthorizedOracle` - Signer is not the oracle's current authority
+    /// * `InvalidTokenAccount` - Token accounts don't belong to the bond / oracle
+    pub fn withdraw_oracle_bond(ctx: Context<WithdrawOracleBond>, amount: u64) -> Result<()> {
+        instructions::withdraw_oracle_bond::withdraw_oracle_bond(ctx, amount)
+    }
+
+    /// Upholds a challenge against the campaign's oracle and slashes its bond.
+    ///
+    /// The brand or influencer submits evidence signed by the registry arbiter
+    /// stating the values the oracle reported and the correct values. An Ed25519
+    /// program instruction verifying the arbiter's signature over
+    /// `evidence.message()` must immediately precede this instruction. The slash
+    /// (capped at the bond balance) is paid to the challenger. Completed campaigns
+    /// stay open for `ORACLE_CHALLENGE_PERIOD`, so completing reports can be challenged too.
+    ///
+    /// # Arguments
+    ///
+    /// * `evidence` - Arbiter ruling (campaign, oracle, challenger, values, slash, nonce)
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (its `oracle` selects the bond)
+    /// * `oracle_registry` - Oracle registry (provides the arbiter)
+    /// * `oracle_bond` - Bond of `campaign.oracle`
+    /// * `bond_vault` - Bond token account
+    /// * `oracle_challenge` - Challenge record PDA to initialize, seeds: ["oracle_challenge", campaign, nonce]
+    /// * `challenger_token_account` - Challenger's token account of the bond mint
+    /// * `challenger` - Brand or influencer signer (pays for the record)
+    /// * `instructions_sysvar` - Instructions sysvar
+    ///
+    /// # Errors
+    ///
+    /// * `UnauthorizedChallenger` - Signer is neither the brand nor the influencer
+    /// * `ChallengeMismatch` - Evidence issued for another campaign, oracle, or challenger
+    /// * `ChallengeNotContradicting` - Observed values equal the reported values
+    /// * `InvalidSignatureInstruction` - Missing or malformed Ed25519 instruction
+    /// * `InvalidArbiterSignature` - Evidence not signed by the arbiter
+    pub fn challenge_oracle(ctx: Context<ChallengeOracle>, evidence: ChallengeEvidence) -> Result<()> {
+        instructions::challenge_oracle::challenge_oracle(ctx, evidence)
+    }
+
+    /// Sets the oracle fee paid per applied oracle report (Draft only).
+    ///
+    /// The fee is charged once, when a report is applied (directly, after its challenge
+    /// window or after review), to the oracle that submitted it. The brand deposits `fee_cap` as a fee reserve on top of the budget when
+    /// activating the campaign. Unused reserve is refunded on cancellation or,
+    /// for completed campaigns, via `refund_oracle_fees`.
+    ///
+    /// # Arguments
+    ///
+    /// * `fee_per_update` - Fee in USDC (6 decimals) per applied report
+    /// * `fee_cap` - Maximum total fees (size of the reserve)
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be in Draft status)
+    /// * `influencer` - Influencer signer (must match campaign.influencer)
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotDraft` - Campaign is not in Draft status
+    /// * `UnauthorizedInfluencer` - Signer doesn't match campaign.influencer
+    /// * `InvalidOracleFee` - Cap lower than the fee, or only one of them is zero
+    pub fn set_oracle_fee(ctx: Context<SetOracleFee>, fee_per_update: u64, fee_cap: u64) -> Result<()> {
+        instructions::set_oracle_fee::set_oracle_fee(ctx, fee_per_update, fee_cap)
+    }
+
+    /// Refunds the unused oracle fee reserve of a settled campaign to the brand.
+    ///
+    /// Completed campaigns holding unused reserve stay open until this is called,
+    /// after which they can be closed with `close_campaign`.
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Completed or Cancelled)
+    /// * `brand` - Brand signer (must match campaign.brand)
+    /// * `brand_usdc_account` - Brand's USDC account (refund destination)
+    /// * `campaign_usdc_account` - Campaign vault (refund source)
+    /// * `token_program` - SPL Token program
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotInTerminalState` - Campaign is not Completed or Cancelled
+    /// * `UnauthorizedBrand` - Signer doesn't match campaign.brand
+    /// * `InvalidTokenAccount` - Token accounts don't belong to the campaign / brand
+    pub fn refund_oracle_fees(ctx: Context<RefundOracleFees>) -> Result<()> {
+        instructions::refund_oracle_fees::refund_oracle_fees(ctx)
+    }
+
+    /// Registers a post delivered for the campaign.
+    ///
+    /// Once a campaign has posts, metrics are reported per post via
+    /// `update_post_metrics` and progress is computed from their sum. Posts can
+    /// only be added before any metrics are reported.
+    ///
+    /// # Arguments
+    ///
+    /// * `url_hash` - SHA-256 hash of the post URL (or platform post id), part of the PDA seed
+    /// * `platform` - Network where the post is published
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Draft or Active, with no metrics reported yet)
+    /// * `campaign_post` - Post PDA to create, seeds: ["campaign_post", campaign, url_hash]
+    /// * `influencer` - Influencer signer and payer (must match campaign.influencer)
+    /// * `system_program` - System program
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotActive` - Campaign is neither Draft nor Active
+    /// * `MetricsAlreadyReported` - The campaign has already received metric reports
+    /// * `UnauthorizedInfluencer` - Signer doesn't match campaign.influencer
+    /// * `TooManyPosts` - Campaign already has `MAX_CAMPAIGN_POSTS` posts
+    pub fn add_campaign_post(ctx: Context<AddCampaignPost>, url_hash: [u8; 32], platform: Platform) -> Result<()> {
+        instructions::add_campaign_post::add_campaign_post(ctx, url_hash, platform)
+    }
+
+    /// Updates the metrics of one post and reprocesses the campaign aggregate.
+    ///
+    /// The post's new counters replace its previous ones in the campaign sum and,
+    /// when the campaign targets a counter on the post's platform, in that
+    /// platform's sum; the aggregate then goes through the same guards, milestone payments and oracle
+    /// fee as `update_campaign_metrics`. A held aggregate also holds the post metrics.
+    ///
+    /// # Arguments
+    ///
+    /// * `likes` - Current number of likes of the post
+    /// * `comments` - Current number of comments of the post
+    /// * `views` - Current number of views of the post
+    /// * `shares` - Current number of shares of the post
+    /// * `sequence` - Report sequence number (strictly increasing per campaign)
+    /// * `observed_at` - When the oracle observed the metrics (Unix timestamp)
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Active, oracle validated via has_one)
+    /// * `campaign_post` - Post PDA belonging to the campaign
+    /// * `oracle` - Oracle registry entry (must match campaign.oracle and be active)
+    /// * `oracle_authority` - Oracle signer (must match oracle.authority)
+    /// * `metric_history` - Campaign's metric history (required when history is enabled)
+    /// * `campaign_usdc_account` - Campaign vault (owned by the campaign PDA)
+    /// * `influencer_usdc_account` - Influencer's USDC account (owned by campaign.influencer)
+    /// * `oracle_usdc_account` - Optional oracle USDC account (receives the per-update fee)
+    /// * `token_program` - SPL Token program
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotActive` - Campaign is not in Active status
+    /// * `ContentNotApproved` - Content review enabled and content not yet approved
+    /// * `CampaignExpired` - Deadline has passed
+    /// * `UnauthorizedOracle` - Signer doesn't match the oracle entry's current authority
+    /// * `QuorumRequired` - Campaign requires reports through `submit_metric_report`
+    /// * `ReportNonceReused` / `ReportOutOfOrder` / `StaleReport` - Report sequencing violations
+    /// * `MetricsDecreased` - A post counter is lower than its current value
+    /// * `ReportHeldForReview` - A previous report is awaiting review
+    /// * `MetricHistoryRequired` - History is enabled but `metric_history` is missing
+    pub fn update_post_metrics(
+        ctx: Context<UpdatePostMetrics>,
+        likes: u64,
+        comments: u64,
+        views: u64,
+        shares: u64,
+        sequence: u64,
+        observed_at: i64,
+    ) -> Result<()> {
+        instructions::update_post_metrics::update_post_metrics(ctx, likes, comments, views, shares, sequence, observed_at)
+    }
+
+    /// Replaces the campaign's metric targets with `(kind, platform, target, weight)` entries.
+    ///
+    /// Campaign-wide likes, comments, views and shares fill the base entries;
+    /// any other kind or platform-specific target becomes an extra entry. A configured
+    /// metric feed must still match one of the new entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `targets` - Metric targets, up to `MAX_METRIC_ENTRIES` entries in total
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be in Draft status)
+    /// * `influencer` - Influencer signer (must match campaign.influencer)
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotDraft` - Campaign is not in Draft status
+    /// * `UnauthorizedInfluencer` - Signer doesn't match campaign.influencer
+    /// * `NoTargetsSet` - `targets` is empty
+    /// * `InvalidMetricTargets` - Zero, duplicated, too many or unsupported (kind, platform) targets
+    /// * `MetricTargetNotFound` - The configured metric feed's entry was removed
+    pub fn set_metric_targets(ctx: Context<SetMetricTargets>, targets: Vec<MetricTarget>) -> Result<()> {
+        instructions::set_metric_targets::set_metric_targets(ctx, targets)
+    }
+
+    /// Reports values for any of the campaign's metric entries.
+    ///
+    /// Each reading names its entry by `(kind, platform)`, so new metric kinds need
+    /// no new arguments. The report goes through the same guards, milestone payments
+    /// and oracle fee as `update_campaign_metrics`; entries not reported are unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `readings` - Observed (kind, platform, value) entries
+    /// * `sequence` - Report sequence number (strictly increasing per campaign)
+    /// * `observed_at` - When the oracle observed the metrics (Unix timestamp)
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Active, oracle validated via has_one)
+    /// * `oracle` - Oracle registry entry (must match campaign.oracle and be active)
+    /// * `oracle_authority` - Oracle signer (must match oracle.authority)
+    /// * `metric_history` - Campaign's metric history (required when history is enabled)
+    /// * `campaign_usdc_account` - Campaign vault (owned by the campaign PDA)
+    /// * `influencer_usdc_account` - Influencer's USDC account (owned by campaign.influencer)
+    /// * `oracle_usdc_account` - Optional oracle USDC account (receives the per-update fee)
+    /// * `token_program` - SPL Token program
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotActive` - Campaign is not in Active status
+    /// * `ContentNotApproved` - Content review enabled and content not yet approved
+    /// * `CampaignExpired` - Deadline has passed
+    /// * `UnauthorizedOracle` - Signer doesn't match the oracle entry's current authority
+    /// * `QuorumRequired` - Campaign requires reports through `submit_metric_report`
+    /// * `PostMetricsRequired` - Campaign-wide base counters reported on a campaign with posts
+    /// * `ReportNonceReused` / `ReportOutOfOrder` / `StaleReport` - Report sequencing violations
+    /// * `MetricTargetNotFound` - A reading has no matching metric entry
+    /// * `MetricsDecreased` - A value is lower than the entry's current value
+    /// * `ReportHeldForReview` - A previous report is awaiting review
+    /// * `MetricHistoryRequired` - History is enabled but `metric_history` is missing
+    pub fn update_metric_values(
+        ctx: Context<UpdateMetricValues>,
+        readings: Vec<MetricReading>,
+        sequence: u64,
+        observed_at: i64,
+    ) -> Result<()> {
+        instructions::update_metric_values::update_metric_values(ctx, readings, sequence, observed_at)
+    }
+
+    /// Creates the campaign's metric history and enables report recording.
+    ///
+    /// From then on every report path requires the history account, and every
+    /// applied report appends its observation time, base and extra metric values,
+    /// progress and amount paid to a ring buffer of
+    /// `MAX_HISTORY_ENTRIES` entries, overwriting the oldest once full.
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Draft or Active)
+    /// * `metric_history` - History PDA to create, seeds: ["metric_history", campaign]
+    /// * `brand` - Brand signer and payer (must match campaign.brand)
+    /// * `system_program` - System program
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotActive` - Campaign is Completed or Cancelled
+    /// * `UnauthorizedBrand` - Signer doesn't match campaign.brand
+    pub fn initialize_metric_history(ctx: Context<InitializeMetricHistory>) -> Result<()> {
+        instructions::initialize_metric_history::initialize_metric_history(ctx)
+    }
+
+    /// Closes a metric history and refunds its rent to the brand.
+    ///
+    /// Allowed once the campaign is Completed or Cancelled, or after the campaign
+    /// account itself has been closed.
+    ///
+    /// # Accounts
+    ///
+    /// * `metric_history` - History PDA (will be closed)
+    /// * `campaign` - The history's campaign (may already be closed)
+    /// * `brand` - Brand signer (must match metric_history.brand, receives the rent)
+    ///
+    /// # Errors
+    ///
+    /// * `UnauthorizedBrand` - Signer doesn't match metric_history.brand
+    /// * `InvalidMetricHistory` - `campaign` is not the history's campaign
+    /// * `CampaignNotInTerminalState` - Campaign is still open and not Completed or Cancelled
+    pub fn close_metric_history(ctx: Context<CloseMetricHistory>) -> Result<()> {
+        instructions::close_metric_history::close_metric_history(ctx)
+    }
+
+    /// Closes a campaign post and refunds its rent to the influencer.
+    ///
+    /// Allowed once the campaign is Completed or Cancelled, or after the campaign
+    /// account itself has been closed. Withdrawn campaigns close their posts in
+    /// `close_withdrawal`.
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign_post` - Post PDA (will be closed)
+    /// * `campaign` - The post's campaign (may already be closed)
+    /// * `influencer` - Influencer signer (must match campaign_post.influencer, receives the rent)
+    ///
+    /// # Errors
+    ///
+    /// * `UnauthorizedInfluencer` - Signer doesn't match campaign_post.influencer
+    /// * `InvalidCampaignPost` - `campaign` is not the post's campaign
+    /// * `CampaignNotInTerminalState` - Campaign is still open and not Completed or Cancelled
+    pub fn close_campaign_post(ctx: Context<CloseCampaignPost>) -> Result<()> {
+        instructions::close_campaign_post::close_campaign_post(ctx)
+    }
+
+    /// Sets how long each oracle report stays open to brand challenges.
+    ///
+    /// With a non-zero window, reports no longer pay milestones immediately: they
+    /// stay pending until `finalize_pending_report`, and the brand can freeze them
+    /// for arbitration via `challenge_pending_report` while the window is open.
+    ///
+    /// # Arguments
+    ///
+    /// * `challenge_window` - Window length in seconds (0 disables optimistic mode)
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be in Draft status)
+    /// * `influencer` - Influencer signer (must match campaign.influencer)
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotDraft` - Campaign is not in Draft status
+    /// * `UnauthorizedInfluencer` - Signer doesn't match campaign.influencer
+    /// * `InvalidChallengeWindow` - Window is negative or longer than `MAX_CHALLENGE_WINDOW`
+    pub fn set_challenge_window(ctx: Context<SetChallengeWindow>, challenge_window: i64) -> Result<()> {
+        instructions::set_challenge_window::set_challenge_window(ctx, challenge_window)
+    }
+
+    /// Challenges the pending report and freezes it for arbitration.
+    ///
+    /// The report becomes a challenged held report: no payments are made and new
+    /// reports are rejected until the registry arbiter resolves it via `resolve_held_report`.
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Active with a pending report)
+    /// * `brand` - Brand signer (must match campaign.brand)
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotActive` - Campaign is not in Active status
+    /// * `UnauthorizedBrand` - Signer doesn't match campaign.brand
+    /// * `NoPendingReport` - No report is in its challenge window
+    /// * `ChallengeWindowClosed` - The challenge window has ended
+    pub fn challenge_pending_report(ctx: Context<ChallengePendingReport>) -> Result<()> {
+        instructions::challenge_pending_report::challenge_pending_report(ctx)
+    }
+
+    /// Applies a pending report whose challenge window ended unchallenged.
+    ///
+    /// Permissionless crank: anyone can call it. The pending values go through the
+    /// same progress and milestone payment logic as `update_campaign_metrics`, and
+    /// the fee of the oracle that submitted the report is charged at this point.
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Active with a pending report)
+    /// * `oracle` - Oracle registry entry referenced by the campaign
+    /// * `oracle_authority` - Oracle's current key
+    /// * `campaign_post` - Optional post whose report is pending (required for post reports)
+    /// * `metric_history` - Campaign's metric history (required when history is enabled)
+    /// * `campaign_usdc_account` - Campaign vault (owned by the campaign PDA)
+    /// * `influencer_usdc_account` - Influencer's USDC account (owned by campaign.influencer)
+    /// * `oracle_usdc_account` - Optional USDC account of the reporting oracle (receives the per-update fee)
+    /// * `token_program` - SPL Token program
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotActive` - Campaign is not in Active status
+    /// * `NoPendingReport` - No report is in its challenge window
+    /// * `ChallengeWindowOpen` - The challenge window has not ended yet
+    /// * `HeldPostMismatch` - Post missing or different from the pending report's post
+    /// * `MetricHistoryRequired` - History is enabled but `metric_history` is missing
+    /// * `OracleFeeAccountRequired` - A fee is owed but no oracle USDC account was given
+    pub fn finalize_pending_report(ctx: Context<FinalizePendingReport>) -> Result<()> {
+        instructions::finalize_pending_report::finalize_pending_report(ctx)
+    }
+
+    /// Sets (or removes) the external feed applied by `apply_feed_metrics`.
+    ///
+    /// The feed is a third-party oracle network account in the standard layout
+    /// (authority, value, timestamp), owned by `owner_program`; its value is applied
+    /// to the configured `(kind, platform)` metric entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `metric_feed` - Feed account, owner program, expected authority, metric entry and max age (`None` removes it)
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be in Draft status)
+    /// * `influencer` - Influencer signer (must match campaign.influencer)
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotDraft` - Campaign is not in Draft status
+    /// * `UnauthorizedInfluencer` - Signer doesn't match campaign.influencer
+    /// * `InvalidMetricFeed` - Zero max age, missing (or System Program) owner program, or metric not supported on the platform
+    /// * `MetricTargetNotFound` - Campaign has no entry for the feed's metric
+    pub fn set_metric_feed(ctx: Context<SetMetricFeed>, metric_feed: Option<MetricFeed>) -> Result<()> {
+        instructions::set_metric_feed::set_metric_feed(ctx, metric_feed)
+    }
+
+    /// Applies the campaign's external feed value to its metric entry.
+    ///
+    /// Permissionless crank: anyone can call it. The feed value goes through the
+    /// same guards and milestone payments as `update_campaign_metrics`; no oracle
+    /// fee is paid.
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Active with a feed configured)
+    /// * `feed` - Feed account (must match campaign.metric_feed.feed and be owned by its owner_program)
+    /// * `oracle` - Oracle registry entry referenced by the campaign
+    /// * `oracle_authority` - Oracle's current key
+    /// * `metric_history` - Campaign's metric history (required when history is enabled)
+    /// * `campaign_usdc_account` - Campaign vault (owned by the campaign PDA)
+    /// * `influencer_usdc_account` - Influencer's USDC account (owned by campaign.influencer)
+    /// * `token_program` - SPL Token program
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotActive` - Campaign is not in Active status
+    /// * `ContentNotApproved` - Content review enabled and content not yet approved
+    /// * `CampaignExpired` - Deadline has passed
+    /// * `MetricFeedNotSet` - Campaign has no feed
+    /// * `InvalidFeedAccount` - Account is not the configured feed or is malformed
+    /// * `FeedOwnerMismatch` - Account is not owned by the configured feed program
+    /// * `UnauthorizedFeedAuthority` - Feed names a different authority
+    /// * `StaleFeed` - Feed timestamp is older than the configured max age
+    /// * `StaleReport` - Feed timestamp is not newer than the last applied observation
+    /// * `MetricsDecreased` - Feed value is lower than the entry's current value
+    pub fn apply_feed_metrics(ctx: Context<ApplyFeedMetrics>) -> Result<()> {
+        instructions::apply_feed_metrics::apply_feed_metrics(ctx)
+    }
+
+    /// Sets the oracle heartbeat and the cancellation notice period.
+    ///
+    /// After `oracle_heartbeat` seconds without oracle reports, the influencer can
+    /// open the oracle fallback. With a notice period, the brand must call
+    /// `request_cancellation` and wait before cancelling an Active campaign.
+    ///
+    /// # Arguments
+    ///
+    /// * `oracle_heartbeat` - Seconds without oracle reports before the fallback opens (0 = no heartbeat)
+    /// * `cancel_notice_period` - Cancellation notice in seconds (0 = immediate cancellation)
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be in Draft status)
+    /// * `influencer` - Influencer signer (must match campaign.influencer)
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotDraft` - Campaign is not in Draft status
+    /// * `UnauthorizedInfluencer` - Signer doesn't match campaign.influencer
+    /// * `InvalidLivenessConfig` - Negative values or longer than `MAX_LIVENESS_PERIOD`
+    pub fn set_oracle_liveness(ctx: Context<SetOracleLiveness>, oracle_heartbeat: i64, cancel_notice_period: i64) -> Result<()> {
+        instructions::set_oracle_liveness::set_oracle_liveness(ctx, oracle_heartbeat, cancel_notice_period)
+    }
+
+    /// Gives notice that the brand will cancel an Active campaign.
+    ///
+    /// Starts the notice period and lets the influencer open the oracle fallback
+    /// to settle earned tranches before the refund.
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Active)
+    /// * `brand` - Brand signer (must match campaign.brand)
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotActive` - Campaign is not in Active status
+    /// * `UnauthorizedBrand` - Signer doesn't match campaign.brand
+    /// * `CancelAlreadyRequested` - Notice was already given
+    pub fn request_cancellation(ctx: Context<RequestCancellation>) -> Result<()> {
+        instructions::request_cancellation::request_cancellation(ctx)
+    }
+
+    /// Opens the oracle fallback path for the influencer.
+    ///
+    /// Allowed once the oracle missed its heartbeat or the brand gave cancellation
+    /// notice. The fallback stays open until the oracle reports again.
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Active)
+    /// * `influencer` - Influencer signer (must match campaign.influencer)
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotActive` - Campaign is not in Active status
+    /// * `UnauthorizedInfluencer` - Signer doesn't match campaign.influencer
+    /// * `OracleStillLive` - Heartbeat not missed and no cancellation notice
+    pub fn open_oracle_fallback(ctx: Context<OpenOracleFallback>) -> Result<()> {
+        instructions::open_oracle_fallback::open_oracle_fallback(ctx)
+    }
+
+    /// Applies metrics signed by the influencer and co-signed by the brand or the arbiter.
+    ///
+    /// Only while the oracle fallback is open and before the deadline. The values are applied directly,
+    /// without circuit breaker or challenge window, through the same progress and
+    /// milestone payment logic as `update_campaign_metrics`. Readings are evaluated
+    /// against the current terms, which only change while Active through `amend_campaign`.
+    ///
+    /// # Arguments
+    ///
+    /// * `readings` - Observed (kind, platform, value) entries
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Active with the fallback open)
+    /// * `oracle_registry` - Oracle registry (provides the arbiter)
+    /// * `influencer` - Influencer signer
+    /// * `cosigner` - Brand or registry arbiter signer
+    /// * `metric_history` - Campaign's metric history (required when history is enabled)
+    /// * `campaign_usdc_account` - Campaign vault (owned by the campaign PDA)
+    /// * `influencer_usdc_account` - Influencer's USDC account (owned by campaign.influencer)
+    /// * `token_program` - SPL Token program
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotActive` - Campaign is not in Active status
+    /// * `CampaignExpired` - Campaign deadline has passed
+    /// * `ContentNotApproved` - Content review enabled and content not yet approved
+    /// * `FallbackNotOpen` - The influencer has not opened the fallback
+    /// * `UnauthorizedCosigner` - Co-signer is neither the brand nor the registry arbiter
+    /// * `MetricTargetNotFound` - A reading has no matching metric entry
+    /// * `MetricsDecreased` - A value is lower than the entry's current value
+    pub fn submit_fallback_metrics(ctx: Context<SubmitFallbackMetrics>, readings: Vec<MetricReading>) -> Result<()> {
+        instructions::submit_fallback_metrics::submit_fallback_metrics(ctx, readings)
+    }
+
+    /// Creates a campaign proposed by the brand, in Offered status.
+    ///
+    /// Same terms and validations as `create_campaign`, but the brand signs and
+    /// pays rent. With `escrow`, `amount_usdc` is deposited into the campaign vault
+    /// up front, and acceptance activates the campaign directly.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` ... `deadline` - Same as `create_campaign`
+    /// * `escrow` - Deposit the budget into the vault now
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - PDA to initialize, seeds: ["campaign", influencer, brand, name]
+    /// * `brand` - Signer and payer
+    /// * `influencer` - Influencer public key (not signer)
+    /// * `oracle` - Active `OracleEntry` from the oracle registry
+    /// * `brand_usdc_account` - Brand's USDC account (escrow source)
+    /// * `campaign_usdc_account` - Campaign vault (owned by the campaign PDA)
+    /// * `oracle_bond` / `bond_vault` - Oracle's bond and its vault (required with `escrow`)
+    /// * `token_program` - SPL Token program
+    ///
+    /// # Errors
+    ///
+    /// * Same validation errors as `create_campaign`
+    /// * `InvalidTokenAccount` - Vault is not owned by the campaign PDA
+    /// * `OracleBondMintMismatch` - The oracle's bond is not in the vault's mint
+    /// * `OracleBondTooLow` - Bond missing or below `MIN_ORACLE_BOND` (with `escrow`)
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_offer(
+        ctx: Context<CreateOffer>,
+        name: String,
+        nickname: String,
+        brand_name: String,
+        hashtag: String,
+        target_likes: u64,
+        target_comments: u64,
+        target_views: u64,
+        target_shares: u64,
+        amount_usdc: u64,
+        deadline: i64,
+        escrow: bool,
+    ) -> Result<()> {
+        instructions::create_offer::create_offer(
+            ctx,
+            name,
+            nickname,
+            brand_name,
+            hashtag,
+            target_likes,
+            target_comments,
+            target_views,
+            target_shares,
+            amount_usdc,
+            deadline,
+            escrow,
+        )
+    }
+
+    /// Accepts a brand offer.
+    ///
+    /// An escrowed offer becomes Active; otherwise the campaign moves to Draft
+    /// and waits for `brand_pay_campaign`.
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Offered)
+    /// * `influencer` - Influencer signer
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotOffered` - Campaign is not in Offered status
+    /// * `CampaignExpired` - Deadline has passed
+    /// * `UnauthorizedInfluencer` - Signer doesn't match campaign.influencer
+    pub fn accept_offer(ctx: Context<AcceptOffer>) -> Result<()> {
+        instructions::accept_offer::accept_offer(ctx)
+    }
+
+    /// Rejects a brand offer, refunding any escrow and closing the campaign account.
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Offered; closed, rent to the brand)
+    /// * `influencer` - Influencer signer
+    /// * `brand` - Campaign brand (receives rent)
+    /// * `brand_usdc_account` - Brand's USDC account (owned by the brand)
+    /// * `campaign_usdc_account` - Campaign vault
+    /// * `token_program` - SPL Token program
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotOffered` - Campaign is not in Offered status
+    /// * `UnauthorizedInfluencer` - Signer doesn't match campaign.influencer
+    /// * `UnauthorizedBrand` - Brand account doesn't match campaign.brand
+    /// * `InvalidTokenAccount` - Refund account is not owned by the brand
+    pub fn reject_offer(ctx: Context<RejectOffer>) -> Result<()> {
+        instructions::reject_offer::reject_offer(ctx)
+    }
+
+    /// Updates the terms of a Draft campaign.
+    ///
+    /// Replaces the descriptive fields, the campaign-wide targets, the budget and
+    /// the deadline with the same validations as `create_campaign`, and bumps
+    /// `terms_version`. The name is part of the PDA seeds and cannot change. Extra
+    /// entries and weights set via `set_metric_targets` are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `nickname`, `brand_name`, `hashtag` - Descriptive fields (max 50 characters each)
+    /// * `target_likes` ... `target_shares` - Campaign-wide targets (replace the base entry targets)
+    /// * `amount_usdc` - Total campaign budget in USDC (6 decimals)
+    /// * `deadline` - Unix timestamp when campaign expires
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Draft)
+    /// * `influencer` - Influencer signer
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotDraft` - Campaign is not in Draft status
+    /// * `UnauthorizedInfluencer` - Signer doesn't match campaign.influencer
+    /// * Same validation errors as `create_campaign`
+    /// * `MetricTargetNotFound` - The configured metric feed has no matching entry
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_draft(
+        ctx: Context<UpdateDraft>,
+        nickname: String,
+        brand_name: String,
+        hashtag: String,
+        target_likes: u64,
+        target_comments: u64,
+        target_views: u64,
+        target_shares: u64,
+        amount_usdc: u64,
+        deadline: i64,
+    ) -> Result<()> {
+        instructions::update_draft::update_draft(
+            ctx,
+            nickname,
+            brand_name,
+            hashtag,
+            target_likes,
+            target_comments,
+            target_views,
+            target_shares,
+            amount_usdc,
+            deadline,
+        )
+    }
+
+    /// Proposes new terms (targets, budget, deadline) for a Draft campaign.
+    ///
+    /// Callable by the influencer or the brand; a new proposal replaces the pending
+    /// one (counter-proposal). Bumps `terms_version`, which the proposal carries.
+    /// The campaign cannot be funded until the other party accepts.
+    ///
+    /// # Arguments
+    ///
+    /// * `targets` - Campaign-wide likes, comments, views and shares targets
+    /// * `amount_usdc` - Proposed budget in USDC (6 decimals)
+    /// * `deadline` - Proposed deadline (Unix timestamp)
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Draft)
+    /// * `proposer` - Influencer or brand signer
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotDraft` - Campaign is not in Draft status
+    /// * `UnauthorizedParty` - Signer is neither the influencer nor the brand
+    /// * `InvalidAmount` / `InvalidDeadline` / `NoTargetsSet` - Same validations as `create_campaign`
+    pub fn propose_terms(ctx: Context<ProposeTerms>, targets: MetricValues, amount_usdc: u64, deadline: i64) -> Result<()> {
+        instructions::propose_terms::propose_terms(ctx, targets, amount_usdc, deadline)
+    }
+
+    /// Accepts the pending terms proposal and applies it to the campaign.
+    ///
+    /// The proposed targets replace the base entry targets; extra entries, weights set
+    /// via `set_metric_targets` and the metric feed are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - Version of the reviewed proposal
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Draft with a pending proposal)
+    /// * `acceptor` - Influencer or brand signer, other than the proposer
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotDraft` - Campaign is not in Draft status
+    /// * `UnauthorizedParty` - Signer is neither the influencer nor the brand
+    /// * `NoPendingTerms` - No proposal is pending
+    /// * `CannotAcceptOwnProposal` - Signer made the proposal
+    /// * `TermsVersionMismatch` - Version doesn't match the pending proposal
+    /// * `InvalidDeadline` - Proposed deadline has passed
+    /// * `MetricTargetNotFound` - The configured metric feed has no matching entry
+    pub fn accept_terms(ctx: Context<AcceptTerms>, version: u32) -> Result<()> {
+        instructions::accept_terms::accept_terms(ctx, version)
+    }
+
+    /// Sets the total time the brand may keep the campaign paused.
+    ///
+    /// Part of the Draft terms (bumps `terms_version`), so the brand agrees to it
+    /// when funding.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_pause_duration` - Total seconds the campaign may spend paused (0 = no pause)
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Draft)
+    /// * `influencer` - Influencer signer
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotDraft` - Campaign is not in Draft status
+    /// * `UnauthorizedInfluencer` - Signer doesn't match campaign.influencer
+    /// * `InvalidPauseDuration` - Negative or longer than `MAX_PAUSE_DURATION`
+    pub fn set_pause_policy(ctx: Context<SetPausePolicy>, max_pause_duration: i64) -> Result<()> {
+        instructions::set_pause_policy::set_pause_policy(ctx, max_pause_duration)
+    }
+
+    /// Pauses an Active campaign; metric updates are rejected until it resumes.
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Active)
+    /// * `brand` - Brand signer
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotActive` - Campaign is not in Active status
+    /// * `UnauthorizedBrand` - Signer doesn't match campaign.brand
+    /// * `PauseNotAllowed` - No pause time left
+    pub fn pause_campaign(ctx: Context<PauseCampaign>) -> Result<()> {
+        instructions::pause_campaign::pause_campaign(ctx)
+    }
+
+    /// Resumes a Paused campaign, extending the deadline by the paused time.
+    ///
+    /// The extension is capped by the remaining pause budget. The influencer can
+    /// also resume once that budget is used up.
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Paused)
+    /// * `authority` - Brand signer, or the influencer once the pause budget is used up
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotPaused` - Campaign is not in Paused status
+    /// * `UnauthorizedParty` - Signer is neither the brand nor the influencer
+    /// * `PauseNotExpired` - Influencer resumed before the pause budget was used up
+    pub fn resume_campaign(ctx: Context<ResumeCampaign>) -> Result<()> {
+        instructions::resume_campaign::resume_campaign(ctx)
+    }
+
+    /// Amends an Active campaign with both the brand and influencer signatures.
+    ///
+    /// Can extend the deadline, replace the metric targets (keeping reported values)
+    /// and top up the budget. Milestones reached under the amended terms are then
+    /// settled: the influencer receives the cumulative amount owed minus what was
+    /// already paid, so no milestone is paid twice. Reaching 100% completes the
+    /// campaign as in `update_campaign_metrics`.
+    ///
+    /// # Arguments
+    ///
+    /// * `deadline` - New deadline (must not be earlier than the current one)
+    /// * `targets` - New metric targets, as in `set_metric_targets`
+    /// * `top_up_amount` - Extra USDC deposited by the brand (0 = none)
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Active)
+    /// * `brand` - Brand signer
+    /// * `influencer` - Influencer signer
+    /// * `brand_usdc_account` - Brand's USDC account (top-up source; optional without a top-up)
+    /// * `campaign_usdc_account` - Campaign vault (owned by the campaign PDA)
+    /// * `influencer_usdc_account` - Influencer's USDC account (milestone payments)
+    /// * `token_program` - SPL Token program
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotActive` - Campaign is not in Active status
+    /// * `EmptyAmendment` - Nothing to change
+    /// * `ReportHeldForReview` / `ReportPendingChallenge` - A report is not yet applied
+    /// * `InvalidDeadline` - New deadline is earlier than the current one
+    /// * `AmendmentUndoesMilestones` - New targets bring progress below paid milestones
+    /// * `MetricTargetNotFound` - New targets drop the configured metric feed's entry
+    /// * `TopUpAccountRequired` - A top-up was requested without the brand's USDC account
+    pub fn amend_campaign(
+        ctx: Context<AmendCampaign>,
+        deadline: Option<i64>,
+        targets: Option<Vec<MetricTarget>>,
+        top_up_amount: u64,
+    ) -> Result<()> {
+        instructions::amend_campaign::amend_campaign(ctx, deadline, targets, top_up_amount)
+    }
+
+    /// Opens a dispute on an Active or Paused campaign.
+    ///
+    /// Either party can call it. While Disputed, metric updates (and therefore
+    /// payouts) and cancellation are rejected until the arbiter resolves it.
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Active or Paused)
+    /// * `party` - Brand or influencer signer
+    ///
+    /// # Errors
+    ///
+    /// * `UnauthorizedParty` - Signer is neither the brand nor the influencer
+    /// * `CampaignNotActive` - Campaign is not Active or Paused
+    pub fn open_dispute(ctx: Context<OpenDispute>) -> Result<()> {
+        instructions::open_dispute::open_dispute(ctx)
+    }
+
+    /// Resolves a dispute by splitting the unpaid budget.
+    ///
+    /// The registry arbiter decides the influencer's share of `amount_usdc - paid_amount`
+    /// in basis points; the rest, plus the unused oracle fee reserve, is refunded to
+    /// the brand. Pending or held reports are discarded and the campaign is Cancelled.
+    ///
+    /// # Arguments
+    ///
+    /// * `influencer_bps` - Influencer share of the remaining balance (0-10000)
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Disputed)
+    /// * `oracle_registry` - Global registry (provides the arbiter)
+    /// * `arbiter` - Registry arbiter signer
+    /// * `brand_usdc_account` - Brand's USDC account
+    /// * `influencer_usdc_account` - Influencer's USDC account
+    /// * `campaign_usdc_account` - Campaign vault (owned by the campaign PDA)
+    /// * `token_program` - SPL Token program
+    ///
+    /// # Errors
+    ///
+    /// * `UnauthorizedArbiter` - Signer is not the registry arbiter
+    /// * `CampaignNotDisputed` - Campaign is not in Disputed status
+    /// * `InvalidDisputeSplit` - `influencer_bps` is above 10000
+    pub fn resolve_dispute(ctx: Context<ResolveDispute>, influencer_bps: u16) -> Result<()> {
+        instructions::resolve_dispute::resolve_dispute(ctx, influencer_bps)
+    }
+
+    /// Sets the kill fee owed to the influencer if the brand cancels after activation.
+    ///
+    /// The fee is a share of the unpaid budget that grows linearly from `kill_fee_bps`
+    /// at activation to `kill_fee_max_bps` at the deadline (equal values = flat fee).
+    /// No fee is owed once the deadline has passed.
+    /// Like other Draft terms, it bumps the terms version the brand funds against.
+    ///
+    /// # Arguments
+    ///
+    /// * `kill_fee_bps` - Fee at activation, in basis points (0 = no kill fee)
+    /// * `kill_fee_max_bps` - Fee at the deadline, in basis points
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Draft)
+    /// * `influencer` - Influencer signer
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotDraft` - Campaign is not in Draft status
+    /// * `UnauthorizedInfluencer` - Signer is not the campaign influencer
+    /// * `InvalidKillFee` - Fee above 10000 bps or decreasing over time
+    pub fn set_kill_fee(ctx: Context<SetKillFee>, kill_fee_bps: u16, kill_fee_max_bps: u16) -> Result<()> {
+        instructions::set_kill_fee::set_kill_fee(ctx, kill_fee_bps, kill_fee_max_bps)
+    }
+
+    /// Lets the influencer walk away from an Active or Paused campaign.
+    ///
+    /// The influencer keeps what was already paid; the unpaid budget and the unused
+    /// oracle fee reserve are refunded to the brand and the campaign becomes Withdrawn.
+    /// Earnings of an unapplied (held or pending) report are split: the `forfeit_bps`
+    /// share goes to the brand and the rest is paid to the influencer. A held report, or one
+    /// still inside its challenge window, can still be contested by the brand, so it must be
+    /// resolved (or finalized) first or forfeited in full.
+    /// A permanent `WithdrawalRecord` is created for reputation purposes.
+    ///
+    /// # Arguments
+    ///
+    /// * `forfeit_bps` - Share of the unapplied report's earnings given up, in basis points (≤ 10000)
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Active or Paused)
+    /// * `withdrawal_record` - Record PDA `["withdrawal", campaign]` (initialized here)
+    /// * `influencer` - Influencer signer (pays the record rent)
+    /// * `brand_usdc_account` - Brand's USDC account (refund destination)
+    /// * `influencer_usdc_account` - Influencer's USDC account (receives the kept pending earnings)
+    /// * `campaign_usdc_account` - Campaign vault (owned by the campaign PDA)
+    /// * `token_program` - SPL Token program
+    /// * `system_program` - System program
+    ///
+    /// # Errors
+    ///
+    /// * `UnauthorizedInfluencer` - Signer is not the campaign influencer
+    /// * `CampaignNotActive` - Campaign is not Active or Paused
+    /// * `InvalidForfeitBps` - `forfeit_bps` above 10000
+    /// * `ReportHeldForReview` - Held report without a full forfeit
+    /// * `ReportPendingChallenge` - Report inside its challenge window without a full forfeit
+    pub fn influencer_withdraw(ctx: Context<InfluencerWithdraw>, forfeit_bps: u16) -> Result<()> {
+        instructions::influencer_withdraw::influencer_withdraw(ctx, forfeit_bps)
+    }
+
+    /// Closes a Withdrawn campaign.
+    ///
+    /// The `WithdrawalRecord` is left in place as a permanent reputation record.
+    /// The campaign's posts and oracle quorum (if enabled) must be passed as
+    /// `remaining_accounts` and are closed as well. All rent goes to the influencer.
+    /// A metric history stays with the brand (see `close_metric_history`).
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Withdrawn; closed)
+    /// * `influencer` - Influencer signer (receives the rent)
+    /// * `remaining_accounts` - Every `CampaignPost` of the campaign, plus its `OracleQuorum` when enabled
+    ///
+    /// # Errors
+    ///
+    /// * `UnauthorizedInfluencer` - Signer is not the campaign influencer
+    /// * `CampaignNotInTerminalState` - Campaign is not Withdrawn
+    /// * `CampaignChildAccountsRequired` - Missing or foreign posts / quorum in `remaining_accounts`
+    pub fn close_withdrawal<'info>(ctx: Context<'_, '_, 'info, 'info, CloseWithdrawal<'info>>) -> Result<()> {
+        instructions::close_withdrawal::close_withdrawal(ctx)
+    }
+
+    /// Ends a campaign early with a split agreed by the brand and the influencer.
+    ///
+    /// Pays `influencer_amount` of the unpaid budget to the influencer, refunds the
+    /// rest plus the unused oracle fee reserve to the brand, and closes the campaign
+    /// account (rent goes to the influencer). Also ends an open dispute.
+    /// The campaign's posts and oracle quorum (if enabled) must be passed as
+    /// `remaining_accounts` and are closed as well; a metric history stays with the
+    /// brand (see `close_metric_history`).
+    ///
+    /// # Arguments
+    ///
+    /// * `influencer_amount` - Share of `amount_usdc - paid_amount` paid to the influencer
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Active, Paused or Disputed; closed)
+    /// * `brand` - Brand signer
+    /// * `influencer` - Influencer signer (receives the rent)
+    /// * `brand_usdc_account` - Brand's USDC account
+    /// * `influencer_usdc_account` - Influencer's USDC account
+    /// * `campaign_usdc_account` - Campaign vault (owned by the campaign PDA)
+    /// * `token_program` - SPL Token program
+    /// * `remaining_accounts` - Every `CampaignPost` of the campaign, plus its `OracleQuorum` when enabled
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotActive` - Campaign is not Active, Paused or Disputed
+    /// * `InvalidSettlementAmount` - `influencer_amount` exceeds the unpaid budget
+    /// * `CampaignChildAccountsRequired` - Missing or foreign posts / quorum in `remaining_accounts`
+    pub fn settle_campaign<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SettleCampaign<'info>>,
+        influencer_amount: u64,
+    ) -> Result<()> {
+        instructions::settle_campaign::settle_campaign(ctx, influencer_amount)
+    }
+
+    /// Sets how long the brand has to review the influencer's content.
+    ///
+    /// With a non-zero window, metric reports are rejected until the content submitted
+    /// via `submit_content` is approved by the brand or left unreviewed for the whole
+    /// window. Like other Draft terms, it bumps the terms version the brand funds against.
+    ///
+    /// # Arguments
+    ///
+    /// * `review_window` - Review window in seconds (0 = no content stage)
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Draft)
+    /// * `influencer` - Influencer signer
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotDraft` - Campaign is not in Draft status
+    /// * `UnauthorizedInfluencer` - Signer is not the campaign influencer
+    /// * `InvalidContentReviewWindow` - Window is negative or above 14 days
+    pub fn set_content_review(ctx: Context<SetContentReview>, review_window: i64) -> Result<()> {
+        instructions::set_content_review::set_content_review(ctx, review_window)
+    }
+
+    /// Submits the campaign content for the brand's review.
+    ///
+    /// Replaces any previous submission and restarts the review window; approved
+    /// content cannot be replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `url_hash` - SHA-256 hash of the content URL
+    /// * `platform` - Network where the content is published
+    /// * `post_id` - Post identifier on the platform (max 64 chars)
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Active)
+    /// * `influencer` - Influencer signer
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotActive` - Campaign is not in Active status
+    /// * `ContentReviewNotEnabled` - Campaign has no content review window
+    /// * `InvalidPostId` - Post id is empty or too long
+    /// * `ContentAlreadyApproved` - Content was approved (or its review window elapsed)
+    pub fn submit_content(ctx: Context<SubmitContent>, url_hash: [u8; 32], platform: Platform, post_id: String) -> Result<()> {
+        instructions::submit_content::submit_content(ctx, url_hash, platform, post_id)
+    }
+
+    /// Approves the submitted content or requests changes.
+    ///
+    /// Only possible within the review window; afterwards the content already counts
+    /// as approved.
+    ///
+    /// # Arguments
+    ///
+    /// * `approve` - `true` approves the content, `false` requests changes
+    ///
+    /// # Accounts
+    ///
+    /// * `campaign` - Campaign PDA (must be Active)
+    /// * `brand` - Brand signer
+    ///
+    /// # Errors
+    ///
+    /// * `CampaignNotActive` - Campaign is not in Active status
+    /// * `NoContentToReview` - No content is awaiting review
+    /// * `ContentReviewWindowClosed` - The review window elapsed
+    pub fn review_content(ctx: Context<ReviewContent>, approve: bool) -> Result<()> {
+        instructions::review_content::review_content(ctx, approve)
+    }
 }
@@ -1,7 +1,23 @@
 use anchor_lang::prelude::*;
 use super::campaign_status::CampaignStatus;
+use super::content_submission::ContentSubmission;
+use super::metric_feed::MetricFeed;
+use super::metric_guards::MetricGuards;
+use super::metric_entry::{MetricEntry, MetricReading, BASE_METRIC_ENTRIES, EXTRA_METRIC_ENTRIES, MAX_METRIC_ENTRIES};
+use super::metric_values::MetricValues;
+use super::payout_curve::{PayoutCurve, BPS_DENOMINATOR};
+use super::terms_proposal::TermsProposal;
 use crate::errors::ErrorCode;
 
+/// Longest challenge window a campaign can use (7 days, in seconds).
+pub const MAX_CHALLENGE_WINDOW: i64 = 7 * 24 * 3_600;
+
+/// Longest oracle heartbeat or cancellation notice a campaign can use (30 days, in seconds).
+pub const MAX_LIVENESS_PERIOD: i64 = 30 * 24 * 3_600;
+
+/// Longest total pause a campaign can allow (90 days, in seconds).
+pub const MAX_PAUSE_DURATION: i64 = 90 * 24 * 3_600;
+
 /// Campaign account storing all campaign state.
 ///
 /// # PDA Derivation
@@ -12,14 +28,15 @@ use crate::errors::ErrorCode;
 ///
 /// The following must always hold:
 /// - `paid_amount <= amount_usdc` (never overpay)
-/// - `payment_milestones[i] == true` implies at least `(i+1)*10%` paid
-/// - `status` transitions are one-way (no reverse)
-/// - `oracle` never changes after creation
+/// - `payment_milestones[i] == true` implies at least `payout_curve((i+1)*10%)` paid
+/// - `status` transitions are one-way (no reverse), except Paused -> Active on resume
+/// - `oracle` never changes after creation (its signing key can be rotated in the registry)
 /// - At least one target metric > 0
+/// - `metrics[0..4]` are the campaign-wide Likes, Comments, Views, Shares entries
 ///
 /// # Space Calculation
 ///
-/// Total: 474 bytes
+/// Total: 1369 bytes
 /// - Account discriminator: 8 bytes
 /// - influencer: 32 bytes (Pubkey)
 /// - brand: 32 bytes (Pubkey)
@@ -27,15 +44,43 @@ use crate::errors::ErrorCode;
 /// - nickname: 4 + 50 bytes
 /// - brand_name: 4 + 50 bytes
 /// - hashtag: 4 + 50 bytes
-/// - Metrics (8 fields): 8 * 8 = 64 bytes (u64 each)
+/// - metrics: 4 + 10 * 36 = 364 bytes (Vec of up to 10 MetricEntry)
 /// - amount_usdc: 8 bytes (u64)
 /// - deadline: 8 bytes (i64)
 /// - status: 1 + 1 = 2 bytes (enum discriminator + variant)
 /// - paid_amount: 8 bytes (u64)
 /// - oracle: 32 bytes (Pubkey)
+/// - quorum_enabled: 1 byte (bool)
+/// - report_sequence: 8 bytes (u64)
+/// - metric_guards: 3 bytes (bool + 2 * u8)
+/// - report_held: 1 byte (bool)
+/// - held_at: 8 bytes (i64)
+/// - held_post: 1 + 32 bytes (Option<Pubkey>)
+/// - challenge_window: 8 bytes (i64)
+/// - report_pending_since: 8 bytes (i64)
+/// - report_challenged: 1 byte (bool)
+/// - post_count: 1 byte (u8)
+/// - history_enabled: 1 byte (bool)
+/// - metric_feed: 1 + 107 bytes (Option<MetricFeed>)
+/// - offer_escrowed: 1 byte (bool)
+/// - terms_version: 4 bytes (u32)
+/// - pending_terms: 1 + 84 bytes (Option<TermsProposal>)
+/// - Oracle liveness (5 fields): 5 * 8 = 40 bytes (i64 each)
+/// - Pause (3 fields): 3 * 8 = 24 bytes (i64 each)
+/// - disputed_by: 1 + 32 bytes (Option<Pubkey>)
+/// - dispute_opened_at: 8 bytes (i64)
+/// - kill_fee_bps, kill_fee_max_bps: 2 * 2 = 4 bytes (u16 each)
+/// - activated_at: 8 bytes (i64)
+/// - content_review_window: 8 bytes (i64)
+/// - content: 1 + 118 bytes (Option<ContentSubmission>)
+/// - Oracle fee (4 fields): 4 * 8 = 32 bytes (u64 each)
+/// - report_fee_recipient: 1 + 32 bytes (Option<Pubkey>)
 /// - created_at: 8 bytes (i64)
 /// - last_updated: 8 bytes (i64)
+/// - last_observed_at: 8 bytes (i64)
+/// - completed_at: 8 bytes (i64)
 /// - payment_milestones: 10 bytes (array of 10 bools)
+/// - payout_curve: 1 + 4 + 8 * 4 = 37 bytes (enum with up to 8 curve points)
 #[account]
 #[derive(InitSpace)]
 pub struct Campaign {
@@ -61,33 +106,13 @@ pub struct Campaign {
     #[max_len(50)]
     pub hashtag: String,
 
-    // ===== Target Metrics (set at creation) =====
-
-    /// Target number of likes to achieve
-    pub target_likes: u64,
-
-    /// Target number of comments to achieve
-    pub target_comments: u64,
-
-    /// Target number of views to achieve
-    pub target_views: u64,
-
-    /// Target number of shares to achieve
-    pub target_shares: u64,
-
-    // ===== Current Metrics (updated by oracle) =====
-
-    /// Current number of likes (updated via update_campaign_metrics)
-    pub current_likes: u64,
+    // ===== Metrics =====
 
-    /// Current number of comments
-    pub current_comments: u64,
-
-    /// Current number of views
-    pub current_views: u64,
-
-    /// Current number of shares
-    pub current_shares: u64,
+    /// Targets, reported values and weights, one entry per (kind, platform)
+    /// The first four entries are the campaign-wide likes, comments, views and shares
+    /// Set at creation, replaced while in Draft via set_metric_targets
+    #[max_len(10)]
+    pub metrics: Vec<MetricEntry>,
 
     // ===== Financial Fields =====
 
@@ -102,6 +127,10 @@ pub struct Campaign {
     /// [0] = 10%, [1] = 20%, ..., [9] = 100%
     pub payment_milestones: [bool; 10],
 
+    /// Curve mapping progress to cumulative payout (Linear by default)
+    /// Set while in Draft via set_payout_curve
+    pub payout_curve: PayoutCurve,
+
     // ===== Campaign Metadata =====
 
     /// Campaign deadline (Unix timestamp, UTC)
@@ -110,32 +139,244 @@ pub struct Campaign {
     /// Current campaign status (Draft/Active/Completed/Cancelled)
     pub status: CampaignStatus,
 
-    /// Authorized oracle pubkey (validates via has_one constraint)
-    /// This is the only account that can call update_campaign_metrics
+    /// Oracle registry entry (`OracleEntry` PDA) validated via has_one constraint
+    /// Only the entry's current `authority` can call update_campaign_metrics
     pub oracle: Pubkey,
 
+    /// Whether metric reports must go through an `OracleQuorum` (M-of-N)
+    /// instead of the single oracle above
+    pub quorum_enabled: bool,
+
+    /// Sequence number of the last accepted oracle report
+    /// (`update_campaign_metrics` sequence, `submit_signed_metrics` nonce or quorum round)
+    /// Each new report must carry a strictly greater sequence
+    pub report_sequence: u64,
+
+    // ===== Metric Guards (circuit breaker) =====
+
+    /// Sanity rules applied to every metric report
+    pub metric_guards: MetricGuards,
+
+    /// Whether a report tripped the circuit breaker and awaits brand/admin review
+    /// Its values stay pending on `metrics`; new reports are rejected and no payments are made
+    pub report_held: bool,
+
+    /// When the held or pending report was observed (Unix timestamp, 0 if none)
+    pub held_at: i64,
+
+    /// `CampaignPost` whose report is held or pending (None for whole-campaign reports)
+    pub held_post: Option<Pubkey>,
+
+    // ===== Optimistic Reports =====
+
+    /// Seconds a report waits, open to brand challenges, before it can be finalized
+    /// 0 = reports are applied immediately; set while in Draft via set_challenge_window
+    pub challenge_window: i64,
+
+    /// When the report awaiting its challenge window was recorded (Unix timestamp, 0 if none)
+    /// Its values stay pending on `metrics` until `finalize_pending_report`
+    pub report_pending_since: i64,
+
+    /// Whether the held report was challenged by the brand and awaits the registry arbiter
+    pub report_challenged: bool,
+
+    // ===== Posts =====
+
+    /// Number of registered `CampaignPost` accounts
+    /// When > 0, the base metric entries hold the sum across posts and are reported per post
+    pub post_count: u8,
+
+    // ===== History =====
+
+    /// Whether a `MetricHistory` account records every applied report (required on every report path)
+    pub history_enabled: bool,
+
+    // ===== External Feed =====
+
+    /// Third-party feed account applied to one metric entry by `apply_feed_metrics`
+    /// Set while in Draft via set_metric_feed (None = no feed)
+    pub metric_feed: Option<MetricFeed>,
+
+    // ===== Offer =====
+
+    /// Whether the brand escrowed `amount_usdc` when offering the campaign via `create_offer`
+    pub offer_escrowed: bool,
+
+    // ===== Terms =====
+
+    /// Incremented on every change to the terms while in Draft (update_draft and the Draft setters)
+    /// `brand_pay_campaign` must be called with this value, so the brand funds the terms it reviewed
+    pub terms_version: u32,
+
+    /// Terms proposed by one party and awaiting the other's acceptance (None = no proposal)
+    /// Blocks `brand_pay_campaign` until accepted; discarded by any other change to the terms
+    pub pending_terms: Option<TermsProposal>,
+
+    // ===== Oracle Liveness =====
+
+    /// Seconds without oracle reports after which the influencer can open the oracle fallback
+    /// 0 = no heartbeat; set while in Draft via set_oracle_liveness
+    pub oracle_heartbeat: i64,
+
+    /// When the oracle last reported (Unix timestamp); starts at activation and moves
+    /// forward by the time credited on resume, so a pause does not count against the oracle
+    pub last_oracle_report_at: i64,
+
+    /// Seconds the brand must wait between `request_cancellation` and cancelling an Active campaign
+    /// 0 = immediate cancellation; set while in Draft via set_oracle_liveness
+    pub cancel_notice_period: i64,
+
+    /// When the influencer opened the oracle fallback (Unix timestamp, 0 if not open)
+    /// Reset when the oracle reports again
+    pub fallback_opened_at: i64,
+
+    /// When the brand gave cancellation notice (Unix timestamp, 0 if none)
+    pub cancel_requested_at: i64,
+
+    // ===== Pause =====
+
+    /// Total seconds the campaign may spend paused, agreed in Draft via set_pause_policy
+    /// 0 = pausing not allowed
+    pub max_pause_duration: i64,
+
+    /// When the brand paused the campaign (Unix timestamp, 0 if not paused)
+    pub paused_at: i64,
+
+    /// Seconds spent paused so far (already added to `deadline`)
+    pub total_paused: i64,
+
+    // ===== Dispute =====
+
+    /// Party that opened the current or last dispute (None = never disputed)
+    pub disputed_by: Option<Pubkey>,
+
+    /// When the dispute was opened (Unix timestamp, 0 if never disputed)
+    pub dispute_opened_at: i64,
+
+    // ===== Kill Fee =====
+
+    /// Share of the unpaid budget owed to the influencer if the brand cancels right after
+    /// activation, in basis points (agreed in Draft via set_kill_fee)
+    pub kill_fee_bps: u16,
+
+    /// Kill fee reached at the deadline, in basis points; the fee grows linearly from
+    /// `kill_fee_bps` between activation and the deadline (equal values = flat fee)
+    pub kill_fee_max_bps: u16,
+
+    /// When the campaign became Active (Unix timestamp, 0 if never activated)
+    pub activated_at: i64,
+
+    // ===== Content Review =====
+
+    /// Seconds the brand has to review submitted content, agreed in Draft via set_content_review
+    /// 0 = no content stage (metrics count as soon as the campaign is Active)
+    pub content_review_window: i64,
+
+    /// Content submitted by the influencer (None = nothing submitted yet)
+    pub content: Option<ContentSubmission>,
+
+    // ===== Oracle Fee =====
+
+    /// Fee paid to the oracle per applied oracle report (USDC, 6 decimals)
+    pub oracle_fee_per_update: u64,
+
+    /// Maximum total oracle fees; funded by the brand on top of `amount_usdc` at activation
+    pub oracle_fee_cap: u64,
+
+    /// Fee reserve still held in the campaign vault (refunded to the brand on settlement)
+    pub oracle_fee_reserve: u64,
+
+    /// Total fees paid to the oracle so far
+    pub oracle_fees_paid: u64,
+
+    /// Oracle key owed the fee for the staged report once it is applied
+    /// (None for feed and fallback reports, or when no report is staged)
+    pub report_fee_recipient: Option<Pubkey>,
+
     /// Campaign creation timestamp (Unix timestamp)
     pub created_at: i64,
 
-    /// Last update timestamp (updated on any state change)
+    /// Last on-chain write (Unix timestamp, updated on any state change)
     pub last_updated: i64,
+
+    /// Observation time of the last applied metrics (Unix timestamp, 0 if none)
+    pub last_observed_at: i64,
+
+    /// When the campaign reached 100% progress (Unix timestamp, 0 if not completed);
+    /// the account stays open for `ORACLE_CHALLENGE_PERIOD` after it
+    pub completed_at: i64,
 }
 
 impl Campaign {
     /// Space required to initialize a Campaign account.
     ///
     /// See struct doc comment for detailed breakdown.
-    pub const INIT_SPACE: usize = 8 + 32 + 32 + (4 + 50) + (4 + 50) + (4 + 50) + (4 + 50) + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + (1 + 1) + 8 + 32 + 8 + 8 + (1 * 10);
+    pub const INIT_SPACE: usize = 8 + 32 + 32 + (4 + 50) + (4 + 50) + (4 + 50) + (4 + 50) + (4 + MAX_METRIC_ENTRIES * MetricEntry::INIT_SPACE) + 8 + 8 + (1 + 1) + 8 + 32 + 1 + 8 + 8 + 8 + 10 + PayoutCurve::INIT_SPACE
+        + MetricGuards::INIT_SPACE + 1 + 8 + (1 + 32) + 8 + 8 + 1 + 1 + 1 + (1 + MetricFeed::INIT_SPACE) + 1 + 4 + (1 + TermsProposal::INIT_SPACE) + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + (1 + 32) + 8 + 2 + 2 + 8 + 8 + (1 + ContentSubmission::INIT_SPACE) + 8 + 8 + 8 + 8 + 8 + (1 + 32) + 8;
+
+    /// Records a change to the campaign terms, invalidating the version the brand reviewed
+    /// and discarding any pending proposal.
+    pub fn bump_terms_version(&mut self) {
+        self.terms_version = self.terms_version.wrapping_add(1);
+        self.pending_terms = None;
+    }
+
+    /// Pause time still available to the brand, in seconds.
+    pub fn remaining_pause(&self) -> i64 {
+        self.max_pause_duration.saturating_sub(self.total_paused).max(0)
+    }
+
+    /// Whether metric reports are accepted at `now` under the content review stage.
+    ///
+    /// Always true without a review window; otherwise the submitted content must be
+    /// approved by the brand or left unreviewed for the whole window.
+    pub fn content_approved(&self, now: i64) -> bool {
+        if self.content_review_window == 0 {
+            return true;
+        }
+        self.content
+            .as_ref()
+            .is_some_and(|content| content.is_approved(self.content_review_window, now))
+    }
+
+    /// Marks the campaign Completed at `now`.
+    ///
+    /// Every completion path goes through here so `completed_at` always starts
+    /// the `ORACLE_CHALLENGE_PERIOD` that `close_campaign` waits for.
+    pub fn complete(&mut self, now: i64) {
+        self.status = CampaignStatus::Completed;
+        self.completed_at = now;
+    }
+
+    /// Kill fee in basis points if the brand cancels at `now`.
+    ///
+    /// Interpolates linearly from `kill_fee_bps` at activation to `kill_fee_max_bps`
+    /// at the deadline. Zero from the deadline on: the influencer failed to deliver in
+    /// time, so cancelling an expired campaign costs the brand nothing.
+    pub fn kill_fee_bps_at(&self, now: i64) -> u64 {
+        if now >= self.deadline {
+            return 0;
+        }
+        let (start, end) = (u64::from(self.kill_fee_bps), u64::from(self.kill_fee_max_bps));
+        let duration = self.deadline.saturating_sub(self.activated_at);
+        if end <= start || duration <= 0 {
+            return start;
+        }
+        let elapsed = now.saturating_sub(self.activated_at).clamp(0, duration);
+        start + (end - start) * elapsed as u64 / duration as u64
+    }
 
     /// Calculates current campaign progress as a percentage (0-100).
     ///
     /// # Algorithm
     ///
-    /// Progress = (sum of current metrics / sum of target metrics) * 100
+    /// Progress = weighted average of each entry's completion (current / target)
     ///
-    /// Only metrics with non-zero targets are included in the calculation.
+    /// Only entries with non-zero targets are included in the calculation.
     /// Current values are **capped at their targets** to prevent any single
-    /// metric from pushing progress above 100%.
+    /// metric from pushing progress above 100%. Entries created by
+    /// `create_campaign` are weighted by their target, which makes progress
+    /// the ratio of summed counters to summed targets.
     ///
     /// # Returns
     ///
@@ -149,7 +390,7 @@ impl Campaign {
     /// // Target: 1000 likes, Current: 500 likes
     /// // Progress: (500 / 1000) * 100 = 50%
     ///
-    /// // Example 2: Multi-metric average
+    /// // Example 2: Multi-metric average (weights = targets)
     /// // Targets: 1000 likes, 100 comments
     /// // Current: 500 likes, 50 comments
     /// // Progress: ((500 + 50) / (1000 + 100)) * 100 = 50%
@@ -158,9 +399,10 @@ impl Campaign {
     /// // Target: 1000 likes, Current: 1500 likes (over-performed)
     /// // Progress: (1000 / 1000) * 100 = 100% (not 150%)
     ///
-    /// // Example 4: Mixed progress
-    /// // Targets: 1000 likes, 100 comments, Current: 1000 likes, 50 comments
-    /// // Progress: ((1000 + 50) / (1000 + 100)) * 100 = 95%
+    /// // Example 4: Explicit weights
+    /// // Targets: 1000 likes (weight 1), 100 saves (weight 3)
+    /// // Current: 1000 likes, 50 saves
+    /// // Progress: (1 * 100% + 3 * 50%) / 4 = 62%
     /// ```
     ///
     /// # Safety
@@ -168,31 +410,207 @@ impl Campaign {
     /// - Returns 0 if all targets are zero (prevents division by zero)
     /// - Final result capped at 100 (prevents overflow from rounding)
     pub fn get_progress_percentage(&self) -> u64 {
-        let mut total_target = 0;
-        let mut total_current = 0;
+        self.progress_with(false)
+    }
+
+    /// Progress percentage (0-100) the campaign would have once pending values are applied.
+    ///
+    /// Same algorithm as `get_progress_percentage`, used to evaluate a staged
+    /// report before applying it.
+    pub fn pending_progress_percentage(&self) -> u64 {
+        self.progress_with(true)
+    }
 
-        if self.target_likes > 0 {
-            total_target += self.target_likes;
-            total_current += self.current_likes.min(self.target_likes);
+    fn progress_with(&self, include_pending: bool) -> u64 {
+        let mut total_weight: u128 = 0;
+        let mut weighted_bps: u128 = 0;
+
+        for entry in self.metrics.iter().filter(|entry| entry.target > 0) {
+            let value = match entry.pending {
+                Some(pending) if include_pending => pending,
+                _ => entry.current,
+            };
+            // weight * min(value, target) * BPS / target, split to stay within u128
+            let (target, bps) = (entry.target as u128, BPS_DENOMINATOR as u128);
+            let weighted = entry.weight as u128 * value.min(entry.target) as u128;
+            total_weight += entry.weight as u128;
+            weighted_bps += (weighted / target) * bps + (weighted % target) * bps / target;
         }
-        if self.target_comments > 0 {
-            total_target += self.target_comments;
-            total_current += self.current_comments.min(self.target_comments);
+
+        if total_weight == 0 {
+            return 0;
         }
-        if self.target_views > 0 {
-            total_target += self.target_views;
-            total_current += self.current_views.min(self.target_views);
+
+        ((weighted_bps / total_weight) as u64 / 100).min(100)
+    }
+
+    /// Current values of the four base counters as a `MetricValues` snapshot.
+    pub fn current_metrics(&self) -> MetricValues {
+        let current = |index: usize| self.metrics.get(index).map_or(0, |entry| entry.current);
+        MetricValues {
+            likes: current(0),
+            comments: current(1),
+            views: current(2),
+            shares: current(3),
+        }
+    }
+
+    /// Current values of the extra entries (`metrics[4..]`), 0 for unused slots.
+    pub fn current_extra_metrics(&self) -> [u64; EXTRA_METRIC_ENTRIES] {
+        let mut values = [0; EXTRA_METRIC_ENTRIES];
+        for (value, entry) in values.iter_mut().zip(self.metrics.iter().skip(BASE_METRIC_ENTRIES)) {
+            *value = entry.current;
         }
-        if self.target_shares > 0 {
-            total_target += self.target_shares;
-            total_current += self.current_shares.min(self.target_shares);
+        values
+    }
+
+    /// Stages a report as pending values of the matching metric entries.
+    ///
+    /// Pending values are evaluated by `check_metric_guards` and become current
+    /// when the report is applied.
+    ///
+    /// # Errors
+    ///
+    /// * `ReportHeldForReview` - A previous report is still held
+    /// * `ReportPendingChallenge` - A previous report is still in its challenge window
+    /// * `MetricTargetNotFound` - A reading has no matching (kind, platform) entry
+    /// * `MetricsDecreased` - A value is lower than its current value (monotonic rule)
+    pub fn stage_readings(&mut self, readings: &[MetricReading]) -> Result<()> {
+        require!(!self.report_held, ErrorCode::ReportHeldForReview);
+        require!(self.report_pending_since == 0, ErrorCode::ReportPendingChallenge);
+
+        let enforce_monotonic = self.metric_guards.enforce_monotonic;
+        for reading in readings {
+            let entry = self
+                .metrics
+                .iter_mut()
+                .find(|entry| entry.matches(reading.kind, reading.platform))
+                .ok_or(ErrorCode::MetricTargetNotFound)?;
+            require!(!enforce_monotonic || reading.value >= entry.current, ErrorCode::MetricsDecreased);
+            entry.pending = Some(reading.value);
         }
 
-        if total_target == 0 {
-            return 0;
+        Ok(())
+    }
+
+    /// Checks that the configured metric feed still targets one of the campaign's entries.
+    ///
+    /// Called whenever the entries are replaced, so `apply_feed_metrics` never reads
+    /// a feed whose metric the brand no longer funds.
+    ///
+    /// # Errors
+    ///
+    /// * `MetricTargetNotFound` - The feed's (kind, platform) has no matching entry
+    pub fn validate_metric_feed(&self) -> Result<()> {
+        if let Some(feed) = &self.metric_feed {
+            require!(
+                self.metrics.iter().any(|entry| entry.matches(feed.kind, feed.platform)),
+                ErrorCode::MetricTargetNotFound
+            );
         }
+        Ok(())
+    }
+
+    /// Stages the four base counters of a `MetricValues` report.
+    ///
+    /// See `stage_readings`.
+    pub fn stage_metric_values(&mut self, values: &MetricValues) -> Result<()> {
+        require!(self.metrics.len() >= BASE_METRIC_ENTRIES, ErrorCode::MetricTargetNotFound);
+        self.stage_readings(&MetricReading::from_values(values))
+    }
 
-        ((total_current * 100) / total_target).min(100)
+    /// Whether a staged report is not yet applied (held for review or in its challenge window).
+    pub fn has_unapplied_report(&self) -> bool {
+        self.report_held || self.report_pending_since > 0
+    }
+
+    /// USDC the unapplied (held or pending) report would pay the influencer if applied.
+    pub fn pending_payout(&self) -> Result<u64> {
+        if !self.has_unapplied_report() {
+            return Ok(0);
+        }
+        let milestones_achieved = (self.pending_progress_percentage() / 10) as usize;
+        if milestones_achieved == 0 {
+            return Ok(0);
+        }
+        Ok(self
+            .cumulative_payout_for_milestone(milestones_achieved - 1)?
+            .saturating_sub(self.paid_amount)
+            .min(self.amount_usdc.saturating_sub(self.paid_amount)))
+    }
+
+    /// Whether the pending report can still be challenged at `now`.
+    pub fn challenge_window_open(&self, now: i64) -> bool {
+        self.report_pending_since > 0 && now < self.report_pending_since.saturating_add(self.challenge_window)
+    }
+
+    /// Whether the influencer can open the oracle fallback at `now`.
+    ///
+    /// True when the oracle missed its heartbeat (no oracle report for
+    /// `oracle_heartbeat` seconds) or the brand gave cancellation notice, so
+    /// earned tranches can be settled before the refund.
+    pub fn fallback_available(&self, now: i64) -> bool {
+        self.cancel_requested_at > 0
+            || (self.oracle_heartbeat > 0 && now.saturating_sub(self.last_oracle_report_at) >= self.oracle_heartbeat)
+    }
+
+    /// Makes pending values current (report applied).
+    pub fn apply_pending_values(&mut self) {
+        for entry in self.metrics.iter_mut() {
+            if let Some(value) = entry.pending.take() {
+                entry.current = value;
+            }
+        }
+    }
+
+    /// Drops pending values (held report discarded); no oracle fee is owed for it.
+    pub fn discard_pending_values(&mut self) {
+        for entry in self.metrics.iter_mut() {
+            entry.pending = None;
+        }
+        self.report_fee_recipient = None;
+    }
+
+    /// Validates and records the sequence number and observation time of a report.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidObservationTime` - `observed_at` is in the future
+    /// * `ReportNonceReused` - `sequence` equals the last accepted sequence (duplicate)
+    /// * `ReportOutOfOrder` - `sequence` is lower than the last accepted sequence
+    /// * `StaleReport` - `observed_at` is older than the last observation
+    pub fn record_report_sequence(&mut self, sequence: u64, observed_at: i64, now: i64) -> Result<()> {
+        require!(observed_at <= now, ErrorCode::InvalidObservationTime);
+        require!(sequence != self.report_sequence, ErrorCode::ReportNonceReused);
+        require!(sequence > self.report_sequence, ErrorCode::ReportOutOfOrder);
+        require!(observed_at >= self.last_observed_at, ErrorCode::StaleReport);
+
+        self.report_sequence = sequence;
+        Ok(())
+    }
+
+    /// Checks the staged report against the circuit breaker of `metric_guards`.
+    ///
+    /// The monotonic rule is enforced when the report is staged. The per-hour
+    /// rule measures time between observations (`observed_at` versus
+    /// `last_observed_at`), not between on-chain writes. Before the first
+    /// observation it measures from the last write (activation).
+    ///
+    /// # Returns
+    ///
+    /// * `false` - Report can be applied
+    /// * `true` - Report trips the circuit breaker and must be held for review
+    pub fn check_metric_guards(&self, observed_at: i64) -> bool {
+        let since = if self.last_observed_at > 0 {
+            self.last_observed_at
+        } else {
+            self.last_updated
+        };
+        self.metric_guards.trips_circuit_breaker(
+            self.get_progress_percentage(),
+            self.pending_progress_percentage(),
+            observed_at.saturating_sub(since),
+        )
     }
 
     pub fn validate_payment_safety(&self, milestone: usize, amount_to_transfer: u64) -> Result<()> {
@@ -212,16 +630,34 @@ impl Campaign {
         Ok(())
     }
 
-    pub fn calculate_safe_payment(&self, milestone: usize) -> Result<u64> {
+    /// Cumulative amount (in USDC) owed once the given milestone is reached.
+    ///
+    /// Evaluates `payout_curve` at the milestone threshold `(milestone + 1) * 10%`
+    /// and applies it to `amount_usdc`. With the default `Linear` curve this is
+    /// exactly `(milestone + 1) * 10%` of the budget.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidMilestone` - `milestone >= 10`
+    /// * `MathOverflow` - Budget times basis points overflows `u64`
+    pub fn cumulative_payout_for_milestone(&self, milestone: usize) -> Result<u64> {
         require!(milestone < 10, ErrorCode::InvalidMilestone);
 
-        let percentage = ((milestone + 1) * 10) as u64;
-        let total_to_pay = self.amount_usdc
-            .checked_mul(percentage)
+        let progress_bps = ((milestone + 1) as u64) * (BPS_DENOMINATOR / 10);
+        let payout_bps = self.payout_curve.payout_bps(progress_bps);
+
+        let total_to_pay = (self.amount_usdc as u128)
+            .checked_mul(payout_bps as u128)
             .ok_or(ErrorCode::MathOverflow)?
-            .checked_div(100)
+            .checked_div(BPS_DENOMINATOR as u128)
             .ok_or(ErrorCode::MathOverflow)?;
 
+        u64::try_from(total_to_pay).map_err(|_| ErrorCode::MathOverflow.into())
+    }
+
+    pub fn calculate_safe_payment(&self, milestone: usize) -> Result<u64> {
+        let total_to_pay = self.cumulative_payout_for_milestone(milestone)?;
+
         let amount_to_transfer = total_to_pay
             .checked_sub(self.paid_amount)
             .ok_or(ErrorCode::MathOverflow)?;
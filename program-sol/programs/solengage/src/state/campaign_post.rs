@@ -0,0 +1,107 @@
+//! # Campaign Post
+//!
+//! A single piece of content (Reel, Story, video...) delivered for a campaign.
+//!
+//! ## PDA Layout
+//!
+//! ```text
+//! Campaign  ["campaign", influencer, brand, name]
+//!   └── CampaignPost  ["campaign_post", campaign, url_hash]  (one per post, up to MAX_CAMPAIGN_POSTS)
+//! ```
+//!
+//! ## Aggregation
+//!
+//! The oracle reports each post individually via `update_post_metrics`. The
+//! current values of the campaign's base entries always hold the sum across its posts
+//! (and per-platform entries of a base counter the sum across that platform's posts), so
+//! progress, milestones and metric guards work on the aggregate exactly as
+//! they do for single-post campaigns. Once a campaign has posts, whole-campaign
+//! reports are rejected so the aggregate cannot drift from its posts.
+//!
+//! ## Closing
+//!
+//! `settle_campaign` and `close_withdrawal` close the posts together with the
+//! campaign. Posts of Completed or Cancelled campaigns are closed one by one by
+//! the influencer with `close_campaign_post`, which refunds the rent they paid.
+
+use anchor_lang::prelude::*;
+use super::campaign::Campaign;
+use super::metric_entry::{MetricReading, BASE_METRIC_ENTRIES};
+use super::metric_values::MetricValues;
+use super::platform::Platform;
+use crate::errors::ErrorCode;
+
+/// Maximum number of posts a campaign can track.
+pub const MAX_CAMPAIGN_POSTS: u8 = 10;
+
+/// Engagement tracked for one post of a campaign.
+///
+/// Seeds: `["campaign_post", campaign, url_hash]`
+#[account]
+#[derive(InitSpace)]
+pub struct CampaignPost {
+    /// Campaign this post belongs to
+    pub campaign: Pubkey,
+
+    /// Influencer who registered the post and paid its rent
+    pub influencer: Pubkey,
+
+    /// SHA-256 hash of the post URL (or platform post identifier)
+    pub url_hash: [u8; 32],
+
+    /// Network where the post is published
+    pub platform: Platform,
+
+    /// Registration order within the campaign (0-based)
+    pub index: u8,
+
+    /// Metrics last applied for this post (included in the campaign aggregate)
+    pub metrics: MetricValues,
+
+    /// Report for this post held by the circuit breaker, awaiting review
+    pub held_metrics: Option<MetricValues>,
+
+    /// Registration timestamp (Unix timestamp)
+    pub created_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl CampaignPost {
+    /// Readings that replace this post's metrics with `values` in the campaign totals.
+    ///
+    /// Each base counter is reported on the campaign-wide entry and, when the
+    /// campaign also targets that counter on the post's platform, on the
+    /// `(kind, Some(platform))` entry, which sums the posts of that platform.
+    ///
+    /// # Errors
+    ///
+    /// * `MathOverflow` - A total overflows `u64`
+    pub fn readings_with(&self, campaign: &Campaign, values: &MetricValues) -> Result<Vec<MetricReading>> {
+        let replace = |total: u64, old: u64, new: u64| -> Result<u64> {
+            total
+                .checked_sub(old)
+                .ok_or(ErrorCode::MathOverflow)?
+                .checked_add(new)
+                .ok_or_else(|| ErrorCode::MathOverflow.into())
+        };
+
+        let mut readings = Vec::with_capacity(2 * BASE_METRIC_ENTRIES);
+        let old_readings = MetricReading::from_values(&self.metrics);
+        for (old, new) in old_readings.iter().zip(MetricReading::from_values(values)) {
+            for platform in [None, Some(self.platform)] {
+                let Some(entry) = campaign.metrics.iter().find(|entry| entry.matches(new.kind, platform)) else {
+                    continue;
+                };
+                readings.push(MetricReading {
+                    kind: new.kind,
+                    platform,
+                    value: replace(entry.current, old.value, new.value)?,
+                });
+            }
+        }
+
+        Ok(readings)
+    }
+}
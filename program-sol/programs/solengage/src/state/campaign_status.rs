@@ -5,33 +5,54 @@
 //! ## State Machine
 //!
 //! ```text
-//!                    brand_pay_campaign
-//!     Draft ───────────────────────────────> Active
-//!       │                                       │
-//!       │  cancel_campaign                      │  update_campaign_metrics (100%)
-//!       └────────────> Cancelled <──────────────┴──> Completed
-//!                                                           │
-//!                                              close_campaign (auto)
+//!              accept_offer
+//!   Offered ─────────────────> Draft   (offer not escrowed)
+//!     │  │                       │
+//!     │  │ accept_offer          │ brand_pay_campaign
+//!     │  │ (escrowed)            v
+//!     │  └─────────────────────> Active
+//!     │                          │   │
+//!     │  cancel_campaign         │   │  update_campaign_metrics (100%)
+//!     │  (Draft too)             v   v
+//!     └──────────────────> Cancelled  Completed
+//!                                        │
+//!     reject_offer: Offered → closed     close_campaign (after the challenge period)
+//!
+//!   Active ──pause_campaign──> Paused ──resume_campaign──> Active
+//!
+//!   Active / Paused ──open_dispute──> Disputed ──resolve_dispute──> Cancelled
+//!
+//!   Active / Paused ──influencer_withdraw──> Withdrawn
 //! ```
 //!
 //! ## Valid Transitions
 //!
+//! - **Offered → Draft**: Influencer accepts an offer without escrow (brand funds later)
+//! - **Offered → Active**: Influencer accepts an offer with the budget escrowed
+//! - **Offered → Cancelled**: Brand withdraws the offer (escrow refunded)
 //! - **Draft → Active**: Brand pays campaign budget
 //! - **Draft → Cancelled**: Brand cancels before funding
 //! - **Active → Completed**: Metrics reach 100% progress
 //! - **Active → Cancelled**: Brand cancels after funding
+//! - **Active → Paused**: Brand pauses the campaign (within the agreed pause budget)
+//! - **Paused → Active**: Brand resumes; the paused time is added to the deadline
+//! - **Paused → Cancelled**: Brand cancels while paused
+//! - **Active/Paused → Disputed**: Either party opens a dispute (payouts and cancellation frozen)
+//! - **Disputed → Cancelled**: Arbiter splits the remaining vault balance
+//! - **Active/Paused → Withdrawn**: Influencer walks away; unpaid budget refunded to brand
 //!
 //! ## Terminal States
 //!
 //! - **Completed**: Cannot transition to any other state
 //! - **Cancelled**: Cannot transition to any other state
+//! - **Withdrawn**: Cannot transition to any other state
 
 use anchor_lang::prelude::*;
 
 /// Campaign lifecycle status.
 ///
 /// Each campaign progresses through these states in a one-way manner
-/// (no reverse transitions allowed).
+/// (no reverse transitions allowed), except for pausing and resuming.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
 pub enum CampaignStatus {
     /// Campaign created but not yet funded by brand.
@@ -43,12 +64,28 @@ pub enum CampaignStatus {
     Active,
 
     /// Campaign reached 100% of targets.
-    /// All payments processed, account can be closed.
+    /// All payments processed; the account can be closed once the oracle challenge period ends.
     Completed,
 
     /// Campaign terminated by brand before completion.
     /// Remaining funds refunded to brand.
     Cancelled,
+
+    /// Campaign proposed by the brand, awaiting the influencer's answer.
+    /// The brand may have escrowed the budget up front.
+    Offered,
+
+    /// Campaign temporarily halted by the brand.
+    /// Metric updates are rejected; the deadline is extended on resume.
+    Paused,
+
+    /// Campaign frozen by a dispute opened by either party.
+    /// Payouts and cancellation are blocked until the arbiter resolves it.
+    Disputed,
+
+    /// Campaign abandoned by the influencer via `influencer_withdraw`.
+    /// Unpaid budget refunded to brand; a `WithdrawalRecord` keeps the outcome.
+    Withdrawn,
 }
 
 impl std::fmt::Display for CampaignStatus {
@@ -58,6 +95,10 @@ impl std::fmt::Display for CampaignStatus {
             CampaignStatus::Active => write!(f, "Active"),
             CampaignStatus::Completed => write!(f, "Completed"),
             CampaignStatus::Cancelled => write!(f, "Cancelled"),
+            CampaignStatus::Offered => write!(f, "Offered"),
+            CampaignStatus::Paused => write!(f, "Paused"),
+            CampaignStatus::Disputed => write!(f, "Disputed"),
+            CampaignStatus::Withdrawn => write!(f, "Withdrawn"),
         }
     }
 }
@@ -0,0 +1,76 @@
+//! # Content Submission
+//!
+//! Content delivered by the influencer and reviewed by the brand before metrics count.
+//!
+//! ## Review Flow
+//!
+//! ```text
+//!   submit_content (influencer)
+//!        │
+//!        v
+//!   Submitted ──review_content(approve = false)──> ChangesRequested ──submit_content──> Submitted
+//!        │
+//!        ├── review_content(approve = true) ──> Approved
+//!        └── review window elapsed ───────────> approved (no transaction needed)
+//! ```
+//!
+//! Campaigns opt in by agreeing a review window in Draft (`set_content_review`).
+//! While content is not approved, metric reports are rejected; a submission left
+//! unreviewed for the whole window counts as approved.
+
+use anchor_lang::prelude::*;
+use super::platform::Platform;
+
+/// Longest content review window a campaign can use (14 days, in seconds).
+pub const MAX_CONTENT_REVIEW_WINDOW: i64 = 14 * 24 * 3_600;
+
+/// Maximum length of a platform post identifier.
+pub const MAX_POST_ID_LEN: usize = 64;
+
+/// Review state of the submitted content.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum ContentStatus {
+    /// Waiting for the brand's review
+    Submitted,
+
+    /// Brand asked for changes; the influencer must submit again
+    ChangesRequested,
+
+    /// Brand approved the content
+    Approved,
+}
+
+/// Content reference submitted by the influencer.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub struct ContentSubmission {
+    /// SHA-256 hash of the content URL
+    pub url_hash: [u8; 32],
+
+    /// Network where the content is published
+    pub platform: Platform,
+
+    /// Post identifier on the platform (max 64 chars)
+    #[max_len(64)]
+    pub post_id: String,
+
+    /// Review state
+    pub status: ContentStatus,
+
+    /// When the content was (last) submitted (Unix timestamp)
+    pub submitted_at: i64,
+
+    /// When the brand last reviewed it (Unix timestamp, 0 if never reviewed)
+    pub reviewed_at: i64,
+}
+
+impl ContentSubmission {
+    /// Whether the content counts as approved at `now`: approved by the brand, or
+    /// still submitted after the whole review window elapsed.
+    pub fn is_approved(&self, review_window: i64, now: i64) -> bool {
+        match self.status {
+            ContentStatus::Approved => true,
+            ContentStatus::Submitted => now >= self.submitted_at.saturating_add(review_window),
+            ContentStatus::ChangesRequested => false,
+        }
+    }
+}
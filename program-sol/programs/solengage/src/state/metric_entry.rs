@@ -0,0 +1,251 @@
+//! # Metric Entries
+//!
+//! Generic metric schema: a campaign stores its targets and reported values as
+//! a bounded vector of `(kind, platform, target, current, weight)` entries, so
+//! new metric kinds only need a new `MetricKind` variant, not a layout change.
+//!
+//! ## Layout
+//!
+//! ```text
+//! metrics[0..4]   Likes, Comments, Views, Shares (campaign-wide, always present)
+//! metrics[4..]    Extra targets, e.g. (Saves, Instagram), (LinkClicks, None)
+//! ```
+//!
+//! The four base entries back the `MetricValues` counters carried by oracle
+//! reports, quorum submissions, signed reports and posts. A base entry with
+//! `target == 0` is tracked but does not count towards progress.
+//!
+//! ## Supported Platform Metrics
+//!
+//! | Metric       | Instagram | TikTok | X | YouTube | Twitch |
+//! |--------------|:---------:|:------:|:-:|:-------:|:------:|
+//! | Likes        | ✓         | ✓      | ✓ | ✓       |        |
+//! | Comments     | ✓         | ✓      | ✓ | ✓       |        |
+//! | Views        | ✓         | ✓      | ✓ | ✓       | ✓      |
+//! | Shares       | ✓         | ✓      |   | ✓       |        |
+//! | WatchMinutes |           | ✓      |   | ✓       | ✓      |
+//! | Saves        | ✓         | ✓      |   |         |        |
+//! | Retweets     |           |        | ✓ |         |        |
+//! | ClipViews    |           |        |   |         | ✓      |
+//! | LinkClicks   | ✓         | ✓      | ✓ | ✓       | ✓      |
+//! | Conversions  | ✓         | ✓      | ✓ | ✓       | ✓      |
+//!
+//! Entries without a platform (`platform == None`) accept any kind.
+
+use anchor_lang::prelude::*;
+use super::metric_values::MetricValues;
+use super::platform::Platform;
+use crate::errors::ErrorCode;
+
+/// Maximum number of metric entries per campaign (4 base + 6 extra).
+pub const MAX_METRIC_ENTRIES: usize = 10;
+
+/// Number of base entries backing `MetricValues` (likes, comments, views, shares).
+pub const BASE_METRIC_ENTRIES: usize = 4;
+
+/// Number of extra entries a campaign can add after the base ones.
+pub const EXTRA_METRIC_ENTRIES: usize = MAX_METRIC_ENTRIES - BASE_METRIC_ENTRIES;
+
+/// Kind of engagement metric.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum MetricKind {
+    /// Likes / hearts
+    Likes,
+
+    /// Comments / replies
+    Comments,
+
+    /// Views / impressions
+    Views,
+
+    /// Shares / sends
+    Shares,
+
+    /// Total watch time in minutes
+    WatchMinutes,
+
+    /// Saves / bookmarks
+    Saves,
+
+    /// Retweets / reposts on X
+    Retweets,
+
+    /// Views of Twitch clips
+    ClipViews,
+
+    /// Clicks on the campaign link
+    LinkClicks,
+
+    /// Attributed conversions (sales, sign-ups)
+    Conversions,
+}
+
+impl MetricKind {
+    /// Index of the base entry backing this kind, if it is one of the `MetricValues` counters.
+    pub fn base_index(&self) -> Option<usize> {
+        match self {
+            MetricKind::Likes => Some(0),
+            MetricKind::Comments => Some(1),
+            MetricKind::Views => Some(2),
+            MetricKind::Shares => Some(3),
+            _ => None,
+        }
+    }
+
+    /// Whether `platform` exposes this metric (see the module table).
+    pub fn is_supported_on(&self, platform: Platform) -> bool {
+        use Platform::*;
+        match self {
+            MetricKind::Likes | MetricKind::Comments => platform != Twitch,
+            MetricKind::Views | MetricKind::LinkClicks | MetricKind::Conversions => true,
+            MetricKind::Shares => matches!(platform, Instagram | TikTok | YouTube),
+            MetricKind::WatchMinutes => matches!(platform, TikTok | YouTube | Twitch),
+            MetricKind::Saves => matches!(platform, Instagram | TikTok),
+            MetricKind::Retweets => platform == X,
+            MetricKind::ClipViews => platform == Twitch,
+        }
+    }
+}
+
+/// Target, reported value and weight of one metric of a campaign.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct MetricEntry {
+    /// Metric being tracked
+    pub kind: MetricKind,
+
+    /// Platform the metric is measured on (None = campaign-wide)
+    pub platform: Option<Platform>,
+
+    /// Target value (0 = tracked but not counted towards progress)
+    pub target: u64,
+
+    /// Value of the last applied report
+    pub current: u64,
+
+    /// Relative weight of this entry in the progress calculation
+    pub weight: u64,
+
+    /// Value of a report not yet applied (being evaluated or held for review)
+    pub pending: Option<u64>,
+}
+
+impl MetricEntry {
+    fn new(kind: MetricKind, platform: Option<Platform>, target: u64, weight: u64) -> Self {
+        Self { kind, platform, target, current: 0, weight, pending: None }
+    }
+
+    /// Whether this entry tracks `kind` on `platform`.
+    pub fn matches(&self, kind: MetricKind, platform: Option<Platform>) -> bool {
+        self.kind == kind && self.platform == platform
+    }
+}
+
+/// Target configuration for one metric, as passed to `set_metric_targets`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct MetricTarget {
+    /// Metric being targeted
+    pub kind: MetricKind,
+
+    /// Platform the metric is measured on (None = campaign-wide)
+    pub platform: Option<Platform>,
+
+    /// Target value (> 0)
+    pub target: u64,
+
+    /// Relative weight in the progress calculation (> 0)
+    pub weight: u64,
+}
+
+/// Value observed by the oracle for one metric entry.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct MetricReading {
+    /// Metric being reported
+    pub kind: MetricKind,
+
+    /// Platform the metric was measured on (None = campaign-wide)
+    pub platform: Option<Platform>,
+
+    /// Observed value
+    pub value: u64,
+}
+
+impl MetricReading {
+    /// Readings for the four base counters of a `MetricValues` report.
+    pub fn from_values(values: &MetricValues) -> [MetricReading; BASE_METRIC_ENTRIES] {
+        let reading = |kind, value| MetricReading { kind, platform: None, value };
+        [
+            reading(MetricKind::Likes, values.likes),
+            reading(MetricKind::Comments, values.comments),
+            reading(MetricKind::Views, values.views),
+            reading(MetricKind::Shares, values.shares),
+        ]
+    }
+}
+
+/// Base entries for the four campaign-wide counters set at creation.
+///
+/// Each target is weighted by its own value, so progress is the ratio of
+/// summed (capped) counters to summed targets, as with the original fixed fields.
+pub fn base_metric_entries(likes: u64, comments: u64, views: u64, shares: u64) -> Vec<MetricEntry> {
+    vec![
+        MetricEntry::new(MetricKind::Likes, None, likes, likes),
+        MetricEntry::new(MetricKind::Comments, None, comments, comments),
+        MetricEntry::new(MetricKind::Views, None, views, views),
+        MetricEntry::new(MetricKind::Shares, None, shares, shares),
+    ]
+}
+
+/// Replaces the targets of the four base entries, keeping the extra entries.
+///
+/// A base entry weighted by its own target (as built by `base_metric_entries`)
+/// keeps following its target; a weight set explicitly via `set_metric_targets`
+/// is kept as is.
+pub fn set_base_targets(entries: &mut [MetricEntry], targets: [u64; BASE_METRIC_ENTRIES]) {
+    for (entry, target) in entries.iter_mut().zip(targets) {
+        if entry.weight == entry.target {
+            entry.weight = target;
+        }
+        entry.target = target;
+    }
+}
+
+/// Validates metric targets and builds the entries stored on a campaign.
+///
+/// Campaign-wide targets for likes, comments, views or shares fill the base
+/// entries; every other target becomes an extra entry.
+///
+/// # Errors
+///
+/// * `InvalidMetricTargets` - Zero target or weight, a kind the platform does not
+///   expose, a duplicated (kind, platform) pair, or more than `MAX_METRIC_ENTRIES` entries
+/// * `NoTargetsSet` - `targets` is empty
+pub fn build_metric_entries(targets: &[MetricTarget]) -> Result<Vec<MetricEntry>> {
+    require!(!targets.is_empty(), ErrorCode::NoTargetsSet);
+
+    let mut entries = base_metric_entries(0, 0, 0, 0);
+    for target in targets {
+        require!(target.target > 0 && target.weight > 0, ErrorCode::InvalidMetricTargets);
+        if let Some(platform) = target.platform {
+            require!(target.kind.is_supported_on(platform), ErrorCode::InvalidMetricTargets);
+        }
+
+        match (target.platform, target.kind.base_index()) {
+            (None, Some(index)) => {
+                let entry = &mut entries[index];
+                require!(entry.target == 0, ErrorCode::InvalidMetricTargets);
+                entry.target = target.target;
+                entry.weight = target.weight;
+            }
+            _ => {
+                require!(
+                    !entries.iter().any(|entry| entry.matches(target.kind, target.platform)),
+                    ErrorCode::InvalidMetricTargets
+                );
+                require!(entries.len() < MAX_METRIC_ENTRIES, ErrorCode::InvalidMetricTargets);
+                entries.push(MetricEntry::new(target.kind, target.platform, target.target, target.weight));
+            }
+        }
+    }
+
+    Ok(entries)
+}
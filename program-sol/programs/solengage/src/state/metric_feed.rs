@@ -0,0 +1,108 @@
+//! # Metric Feed
+//!
+//! Adapter for metric values published by a third-party oracle network in a
+//! standard feed account, instead of reported by a Solengage oracle signer.
+//!
+//! ## Feed Account Layout
+//!
+//! ```text
+//! offset  size  field
+//! 0       32    authority   (Pubkey allowed to write the feed)
+//! 32      8     value       (u64, little-endian)
+//! 40      8     timestamp   (i64, little-endian Unix timestamp of the observation)
+//! ```
+//!
+//! The campaign pins the feed address, the program that must own it and the
+//! authority expected in it. Only the owning program can write the account's
+//! data, so the authority field is trusted only after the owner check. The value is applied to a single metric entry
+//! `(kind, platform)` through the same guards and milestone payments as oracle
+//! reports, via the permissionless `apply_feed_metrics` crank.
+
+use anchor_lang::prelude::*;
+use super::metric_entry::{MetricKind, MetricReading};
+use super::platform::Platform;
+use crate::errors::ErrorCode;
+
+/// Feed configuration stored on a campaign.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct MetricFeed {
+    /// Address of the feed account
+    pub feed: Pubkey,
+
+    /// Program that must own the feed account (the oracle network's program)
+    pub owner_program: Pubkey,
+
+    /// Authority the feed account must name as its writer
+    pub authority: Pubkey,
+
+    /// Metric entry the feed value is applied to
+    pub kind: MetricKind,
+
+    /// Platform of the metric entry (None = campaign-wide)
+    pub platform: Option<Platform>,
+
+    /// Maximum age of the feed timestamp, in seconds
+    pub max_age: i64,
+}
+
+/// Decoded contents of a feed account.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct FeedData {
+    /// Key allowed to write the feed
+    pub authority: Pubkey,
+
+    /// Latest metric value
+    pub value: u64,
+
+    /// When the value was observed (Unix timestamp)
+    pub timestamp: i64,
+}
+
+impl FeedData {
+    /// Size of the feed layout in bytes.
+    pub const LEN: usize = 32 + 8 + 8;
+}
+
+impl MetricFeed {
+    /// Validates the configuration.
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.max_age > 0
+                && self.owner_program != Pubkey::default()
+                && self.owner_program != anchor_lang::system_program::ID
+                && self.platform.is_none_or(|platform| self.kind.is_supported_on(platform)),
+            ErrorCode::InvalidMetricFeed
+        );
+        Ok(())
+    }
+
+    /// Reads `feed_account` and returns its value as a reading of the configured entry,
+    /// along with the observation timestamp.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidFeedAccount` - Account is not the configured feed or is shorter than the layout
+    /// * `FeedOwnerMismatch` - Account is not owned by the configured feed program
+    /// * `UnauthorizedFeedAuthority` - Feed names a different authority
+    /// * `InvalidObservationTime` - Feed timestamp is in the future
+    /// * `StaleFeed` - Feed timestamp is older than `max_age`
+    pub fn read(&self, feed_account: &AccountInfo, now: i64) -> Result<(MetricReading, i64)> {
+        require_keys_eq!(feed_account.key(), self.feed, ErrorCode::InvalidFeedAccount);
+        require_keys_eq!(*feed_account.owner, self.owner_program, ErrorCode::FeedOwnerMismatch);
+
+        let data = feed_account.try_borrow_data()?;
+        require!(data.len() >= FeedData::LEN, ErrorCode::InvalidFeedAccount);
+        let feed = FeedData::try_from_slice(&data[..FeedData::LEN]).map_err(|_| ErrorCode::InvalidFeedAccount)?;
+
+        require_keys_eq!(feed.authority, self.authority, ErrorCode::UnauthorizedFeedAuthority);
+        require!(feed.timestamp <= now, ErrorCode::InvalidObservationTime);
+        require!(now.saturating_sub(feed.timestamp) <= self.max_age, ErrorCode::StaleFeed);
+
+        let reading = MetricReading {
+            kind: self.kind,
+            platform: self.platform,
+            value: feed.value,
+        };
+        Ok((reading, feed.timestamp))
+    }
+}
@@ -0,0 +1,79 @@
+//! # Metric Guards
+//!
+//! Sanity rules applied to every metric report before it can trigger payments.
+//!
+//! ## Rules
+//!
+//! - **Monotonic**: Reported counters may not decrease (report is rejected).
+//! - **Per-update jump**: Progress growth above `max_progress_per_update`
+//!   percentage points in a single report trips the circuit breaker.
+//! - **Per-hour rate**: Progress growth faster than `max_progress_per_hour`
+//!   percentage points per hour since the last update trips the circuit breaker.
+//!
+//! A tripped report is not applied: it is held on the campaign until the brand
+//! or the oracle registry admin approves or discards it via `resolve_held_report`.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+
+/// Number of seconds in an hour, used by the per-hour growth rule.
+pub const SECONDS_PER_HOUR: i64 = 3_600;
+
+/// Configurable sanity rules for metric reports.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct MetricGuards {
+    /// Reject reports where any counter is lower than its current value
+    pub enforce_monotonic: bool,
+
+    /// Maximum progress growth per report in percentage points (0 = disabled)
+    pub max_progress_per_update: u8,
+
+    /// Maximum progress growth per hour in percentage points (0 = disabled)
+    pub max_progress_per_hour: u8,
+}
+
+impl Default for MetricGuards {
+    /// Monotonic metrics enforced, growth circuit breaker disabled.
+    fn default() -> Self {
+        MetricGuards {
+            enforce_monotonic: true,
+            max_progress_per_update: 0,
+            max_progress_per_hour: 0,
+        }
+    }
+}
+
+impl MetricGuards {
+    /// Validates that thresholds are percentages (0-100).
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.max_progress_per_update <= 100 && self.max_progress_per_hour <= 100,
+            ErrorCode::InvalidMetricGuards
+        );
+        Ok(())
+    }
+
+    /// Whether moving from `old_progress` to `new_progress` after `elapsed`
+    /// seconds trips the circuit breaker.
+    pub fn trips_circuit_breaker(&self, old_progress: u64, new_progress: u64, elapsed: i64) -> bool {
+        let growth = new_progress.saturating_sub(old_progress);
+        if growth == 0 {
+            return false;
+        }
+
+        if self.max_progress_per_update > 0 && growth > u64::from(self.max_progress_per_update) {
+            return true;
+        }
+
+        // growth / (elapsed / 3600) > max  <=>  growth * 3600 > max * elapsed
+        if self.max_progress_per_hour > 0 {
+            let elapsed = elapsed.max(0) as u128;
+            let growth_scaled = growth as u128 * SECONDS_PER_HOUR as u128;
+            if growth_scaled > u128::from(self.max_progress_per_hour) * elapsed {
+                return true;
+            }
+        }
+
+        false
+    }
+}
@@ -0,0 +1,88 @@
+//! # Metric History
+//!
+//! Audit trail of the metric reports applied to a campaign.
+//!
+//! ## PDA Layout
+//!
+//! ```text
+//! Campaign  ["campaign", influencer, brand, name]
+//!   └── MetricHistory  ["metric_history", campaign]  (optional, one per campaign)
+//! ```
+//!
+//! ## Ring Buffer
+//!
+//! `entries` grows up to `MAX_HISTORY_ENTRIES`; after that each new report
+//! overwrites the oldest one. `head` is the slot written next, so once the
+//! buffer is full the entries in chronological order are
+//! `entries[head..]` followed by `entries[..head]`. `total_recorded` counts
+//! every report ever appended, including overwritten ones.
+
+use anchor_lang::prelude::*;
+use super::metric_entry::EXTRA_METRIC_ENTRIES;
+use super::metric_values::MetricValues;
+
+/// Number of reports kept in the ring buffer.
+pub const MAX_HISTORY_ENTRIES: usize = 32;
+
+/// One applied metric report.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct HistoryEntry {
+    /// When the oracle observed the metrics (Unix timestamp)
+    pub observed_at: i64,
+
+    /// Campaign-wide likes, comments, views and shares after the report
+    pub values: MetricValues,
+
+    /// Current values of the extra metric entries (`metrics[4..]`) after the report, 0 for unused slots
+    pub extra_values: [u64; EXTRA_METRIC_ENTRIES],
+
+    /// Campaign progress after the report (0-100)
+    pub progress: u8,
+
+    /// USDC paid to the influencer by this report (milestone payments)
+    pub amount_paid: u64,
+
+    /// Cumulative USDC paid to the influencer after this report
+    pub total_paid: u64,
+}
+
+/// Ring buffer of the reports applied to a campaign.
+///
+/// Seeds: `["metric_history", campaign]`
+#[account]
+#[derive(InitSpace)]
+pub struct MetricHistory {
+    /// Campaign this history belongs to
+    pub campaign: Pubkey,
+
+    /// Brand that funded the account (receives the rent when closed)
+    pub brand: Pubkey,
+
+    /// Recorded reports (see module docs for ordering)
+    #[max_len(32)]
+    pub entries: Vec<HistoryEntry>,
+
+    /// Slot written by the next report
+    pub head: u8,
+
+    /// Number of reports recorded since creation
+    pub total_recorded: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl MetricHistory {
+    /// Appends `entry`, overwriting the oldest one when the buffer is full.
+    pub fn record(&mut self, entry: HistoryEntry) {
+        let head = self.head as usize;
+        if self.entries.len() < MAX_HISTORY_ENTRIES {
+            self.entries.push(entry);
+        } else {
+            self.entries[head] = entry;
+        }
+
+        self.head = ((head + 1) % MAX_HISTORY_ENTRIES) as u8;
+        self.total_recorded = self.total_recorded.saturating_add(1);
+    }
+}
@@ -0,0 +1,21 @@
+//! # Metric Values
+//!
+//! A snapshot of the four engagement counters reported by oracles.
+
+use anchor_lang::prelude::*;
+
+/// Engagement counters observed for a campaign at a point in time.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, InitSpace)]
+pub struct MetricValues {
+    /// Number of likes
+    pub likes: u64,
+
+    /// Number of comments
+    pub comments: u64,
+
+    /// Number of views
+    pub views: u64,
+
+    /// Number of shares
+    pub shares: u64,
+}
@@ -4,6 +4,36 @@
 
 pub mod campaign;
 pub mod campaign_status;
+pub mod payout_curve;
+pub mod oracle_registry;
+pub mod metric_values;
+pub mod oracle_quorum;
+pub mod signed_metric_report;
+pub mod metric_guards;
+pub mod oracle_bond;
+pub mod platform;
+pub mod metric_entry;
+pub mod campaign_post;
+pub mod metric_history;
+pub mod metric_feed;
+pub mod terms_proposal;
+pub mod withdrawal_record;
+pub mod content_submission;
 
 pub use campaign::*;
 pub use campaign_status::*;
+pub use payout_curve::*;
+pub use oracle_registry::*;
+pub use metric_values::*;
+pub use oracle_quorum::*;
+pub use signed_metric_report::*;
+pub use metric_guards::*;
+pub use oracle_bond::*;
+pub use platform::*;
+pub use metric_entry::*;
+pub use campaign_post::*;
+pub use metric_history::*;
+pub use metric_feed::*;
+pub use terms_proposal::*;
+pub use withdrawal_record::*;
+pub use content_submission::*;
@@ -0,0 +1,187 @@
+//! # Oracle Bond
+//!
+//! Tokens locked by a registered oracle as collateral against misreports.
+//!
+//! ## PDA Layout
+//!
+//! ```text
+//! OracleEntry  ["oracle", id]
+//!   ├── OracleBond       ["oracle_bond", oracle_entry]         (bond state)
+//!   └── bond vault       ["oracle_bond_vault", oracle_entry]   (token account, authority = OracleBond)
+//!
+//! Campaign
+//!   └── OracleChallenge  ["oracle_challenge", campaign, nonce]  (one per upheld challenge)
+//! ```
+//!
+//! Campaigns reach the bond through `Campaign::oracle`: a brand or influencer
+//! who can show, with evidence signed by the registry arbiter, that the
+//! campaign's oracle misreported is compensated from that oracle's bond.
+//!
+//! ## Funding Requirements
+//!
+//! A campaign can only be funded while its oracle's bond holds at least
+//! `MIN_ORACLE_BOND` of the same mint as the campaign vault, so slashing always
+//! compensates in the campaign's USDC. Completed campaigns stay open for
+//! `ORACLE_CHALLENGE_PERIOD`, so the report that completed them can be challenged.
+//!
+//! ## Unbonding
+//!
+//! The bond stays locked while the oracle is active and for
+//! `ORACLE_UNBONDING_PERIOD` after the admin deactivates it. Challenges keep
+//! slashing the bond during that period.
+//!
+//! ## Signed Evidence
+//!
+//! The arbiter signs, with Ed25519, the bytes returned by
+//! [`ChallengeEvidence::message`]:
+//!
+//! ```text
+//! "solengage:oracle-challenge:v1"  domain separator (29 bytes)
+//! campaign, oracle, challenger     3 * 32 bytes (Pubkey)
+//! reported                         4 * 8 bytes (u64, little-endian)
+//! observed                         4 * 8 bytes (u64, little-endian)
+//! slash_amount                     8 bytes (u64, little-endian)
+//! nonce                            8 bytes (u64, little-endian)
+//! ```
+
+use anchor_lang::prelude::*;
+use super::campaign::MAX_CHALLENGE_WINDOW;
+use super::metric_values::MetricValues;
+use crate::errors::ErrorCode;
+
+/// Seconds a deactivated oracle waits before withdrawing its bond.
+///
+/// At least the longest challenge window, so a report sent right before
+/// deactivation can still be challenged and slashed.
+pub const ORACLE_UNBONDING_PERIOD: i64 = MAX_CHALLENGE_WINDOW;
+
+/// Seconds a completed campaign stays open before `close_campaign`.
+///
+/// Reports that complete a campaign can still be challenged against the
+/// campaign account during this period, as with any other report.
+pub const ORACLE_CHALLENGE_PERIOD: i64 = MAX_CHALLENGE_WINDOW;
+
+/// Minimum balance an oracle's bond must hold when a campaign using it is
+/// funded (100 USDC, 6 decimals).
+pub const MIN_ORACLE_BOND: u64 = 100_000_000;
+
+/// Domain separator prefixed to every challenge evidence message.
+pub const CHALLENGE_EVIDENCE_DOMAIN: &[u8] = b"solengage:oracle-challenge:v1";
+
+/// Collateral locked by an oracle.
+///
+/// Seeds: `["oracle_bond", oracle_entry]`
+#[account]
+#[derive(InitSpace)]
+pub struct OracleBond {
+    /// Oracle registry entry this bond backs
+    pub oracle: Pubkey,
+
+    /// Mint of the bonded token
+    pub mint: Pubkey,
+
+    /// Token account holding the bond (PDA, authority = this account)
+    pub vault: Pubkey,
+
+    /// Total amount slashed from this bond
+    pub slashed_amount: u64,
+
+    /// Number of upheld challenges against this oracle
+    pub slash_count: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Bump seed of the vault token account
+    pub vault_bump: u8,
+}
+
+impl OracleBond {
+    /// Checks that this bond can back a campaign funded in `campaign_mint`.
+    ///
+    /// # Errors
+    ///
+    /// * `OracleBondMintMismatch` - Bond is denominated in another mint
+    /// * `OracleBondTooLow` - `vault_balance` is below `MIN_ORACLE_BOND`
+    pub fn validate_for_campaign(&self, vault_balance: u64, campaign_mint: Pubkey) -> Result<()> {
+        require_keys_eq!(self.mint, campaign_mint, ErrorCode::OracleBondMintMismatch);
+        require!(vault_balance >= MIN_ORACLE_BOND, ErrorCode::OracleBondTooLow);
+        Ok(())
+    }
+}
+
+/// Arbiter ruling that an oracle misreported a campaign's metrics.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct ChallengeEvidence {
+    /// Campaign where the misreport happened
+    pub campaign: Pubkey,
+
+    /// Oracle registry entry being challenged (must be `campaign.oracle`)
+    pub oracle: Pubkey,
+
+    /// Harmed party compensated by the slash (brand or influencer)
+    pub challenger: Pubkey,
+
+    /// Values the oracle reported
+    pub reported: MetricValues,
+
+    /// Values the arbiter determined to be correct
+    pub observed: MetricValues,
+
+    /// Amount to slash from the bond (capped at the bond balance)
+    pub slash_amount: u64,
+
+    /// Unique per campaign (prevents replay)
+    pub nonce: u64,
+}
+
+impl ChallengeEvidence {
+    /// Bytes the arbiter must sign (see module docs for the layout).
+    pub fn message(&self) -> Vec<u8> {
+        let mut message = Vec::with_capacity(CHALLENGE_EVIDENCE_DOMAIN.len() + 3 * 32 + 8 * 8 + 8 + 8);
+        message.extend_from_slice(CHALLENGE_EVIDENCE_DOMAIN);
+        message.extend_from_slice(self.campaign.as_ref());
+        message.extend_from_slice(self.oracle.as_ref());
+        message.extend_from_slice(self.challenger.as_ref());
+        for values in [&self.reported, &self.observed] {
+            message.extend_from_slice(&values.likes.to_le_bytes());
+            message.extend_from_slice(&values.comments.to_le_bytes());
+            message.extend_from_slice(&values.views.to_le_bytes());
+            message.extend_from_slice(&values.shares.to_le_bytes());
+        }
+        message.extend_from_slice(&self.slash_amount.to_le_bytes());
+        message.extend_from_slice(&self.nonce.to_le_bytes());
+        message
+    }
+}
+
+/// Record of an upheld challenge.
+///
+/// Seeds: `["oracle_challenge", campaign, nonce.to_le_bytes()]`
+#[account]
+#[derive(InitSpace)]
+pub struct OracleChallenge {
+    /// Campaign where the misreport happened
+    pub campaign: Pubkey,
+
+    /// Oracle registry entry that was slashed
+    pub oracle: Pubkey,
+
+    /// Party compensated by the slash
+    pub challenger: Pubkey,
+
+    /// Values the oracle reported
+    pub reported: MetricValues,
+
+    /// Values the arbiter determined to be correct
+    pub observed: MetricValues,
+
+    /// Amount actually transferred from the bond
+    pub slashed_amount: u64,
+
+    /// When the challenge was upheld (Unix timestamp)
+    pub created_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
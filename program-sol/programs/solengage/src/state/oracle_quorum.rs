@@ -0,0 +1,161 @@
+//! # Oracle Quorum
+//!
+//! M-of-N aggregation of metric reports from independent oracles.
+//!
+//! ## Flow
+//!
+//! ```text
+//! oracle A ─┐
+//! oracle B ─┼─ submit_metric_report ──> OracleQuorum.submissions (pending round)
+//! oracle C ─┘                                   │
+//!                          >= threshold agree within tolerance of the median
+//!                                               │
+//!                                               v
+//!                    median values applied via update_campaign_metrics logic
+//! ```
+//!
+//! ## Aggregation
+//!
+//! Once at least `threshold` oracles have submitted in the current round, the
+//! per-metric median of all submissions is computed. A submission *agrees* when
+//! every metric is within `tolerance_bps` of that median. If at least
+//! `threshold` submissions agree, the median is applied and a new round starts.
+//! If every oracle has submitted and agreement is still not reached, the round
+//! is discarded. A `tolerance_bps` of `MEDIAN_ONLY_TOLERANCE` skips the agreement
+//! check, applying the median as soon as `threshold` submissions are in.
+//!
+//! The applied report is observed at the lower median of the submissions'
+//! `observed_at` and carries the round number as its report sequence, so it goes
+//! through the same ordering and staleness checks as single-oracle reports.
+
+use anchor_lang::prelude::*;
+use super::metric_values::MetricValues;
+use super::payout_curve::BPS_DENOMINATOR;
+
+/// Maximum number of oracles in a quorum.
+pub const MAX_QUORUM_ORACLES: usize = 5;
+
+/// Tolerance value that disables the agreement check (pure median aggregation).
+pub const MEDIAN_ONLY_TOLERANCE: u16 = u16::MAX;
+
+/// A single oracle's report in the pending round.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct QuorumSubmission {
+    /// Oracle registry entry that submitted the report
+    pub oracle: Pubkey,
+
+    /// Reported metric values
+    pub values: MetricValues,
+
+    /// When the oracle observed the metrics (Unix timestamp)
+    pub observed_at: i64,
+
+    /// Submission timestamp (Unix timestamp)
+    pub submitted_at: i64,
+}
+
+/// Quorum configuration and pending report of a campaign.
+///
+/// Seeds: `["oracle_quorum", campaign]`
+#[account]
+#[derive(InitSpace)]
+pub struct OracleQuorum {
+    /// Campaign this quorum reports for
+    pub campaign: Pubkey,
+
+    /// Oracle registry entries allowed to submit (N)
+    #[max_len(5)]
+    pub oracles: Vec<Pubkey>,
+
+    /// Number of agreeing submissions required (M)
+    pub threshold: u8,
+
+    /// Maximum deviation from the median, in basis points, to count as agreeing
+    /// (`MEDIAN_ONLY_TOLERANCE` = no agreement check)
+    pub tolerance_bps: u16,
+
+    /// Current report round (incremented when a round is applied or discarded)
+    pub round: u64,
+
+    /// Submissions received in the current round (one per oracle)
+    #[max_len(5)]
+    pub submissions: Vec<QuorumSubmission>,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl OracleQuorum {
+    /// Inserts or replaces the submission of `oracle` for the current round.
+    pub fn record_submission(&mut self, submission: QuorumSubmission) {
+        match self.submissions.iter_mut().find(|s| s.oracle == submission.oracle) {
+            Some(existing) => *existing = submission,
+            None => self.submissions.push(submission),
+        }
+    }
+
+    /// Returns the aggregated values if the quorum is reached.
+    ///
+    /// See the module documentation for the aggregation rule.
+    pub fn aggregate(&self) -> Option<MetricValues> {
+        if self.submissions.len() < self.threshold as usize {
+            return None;
+        }
+
+        let median = MetricValues {
+            likes: median_of(self.submissions.iter().map(|s| s.values.likes)),
+            comments: median_of(self.submissions.iter().map(|s| s.values.comments)),
+            views: median_of(self.submissions.iter().map(|s| s.values.views)),
+            shares: median_of(self.submissions.iter().map(|s| s.values.shares)),
+        };
+
+        if self.tolerance_bps == MEDIAN_ONLY_TOLERANCE {
+            return Some(median);
+        }
+
+        let agreeing = self
+            .submissions
+            .iter()
+            .filter(|s| {
+                within_tolerance(s.values.likes, median.likes, self.tolerance_bps)
+                    && within_tolerance(s.values.comments, median.comments, self.tolerance_bps)
+                    && within_tolerance(s.values.views, median.views, self.tolerance_bps)
+                    && within_tolerance(s.values.shares, median.shares, self.tolerance_bps)
+            })
+            .count();
+
+        (agreeing >= self.threshold as usize).then_some(median)
+    }
+
+    /// Observation time of the aggregated report: lower median of the submissions' `observed_at`.
+    pub fn observed_at(&self) -> i64 {
+        median_of(self.submissions.iter().map(|s| s.observed_at))
+    }
+
+    /// Whether every oracle in the quorum has submitted in the current round.
+    pub fn round_complete(&self) -> bool {
+        self.submissions.len() >= self.oracles.len()
+    }
+
+    /// Clears the pending submissions and starts a new round.
+    pub fn start_next_round(&mut self) {
+        self.submissions.clear();
+        self.round = self.round.saturating_add(1);
+    }
+}
+
+/// Lower median of the given values (0 if empty).
+fn median_of<T: Ord + Copy + Default>(values: impl Iterator<Item = T>) -> T {
+    let mut sorted: Vec<T> = values.collect();
+    sorted.sort_unstable();
+    match sorted.len() {
+        0 => T::default(),
+        len => sorted[(len - 1) / 2],
+    }
+}
+
+/// `|value - median| <= median * tolerance_bps / 10_000`, computed in u128.
+fn within_tolerance(value: u64, median: u64, tolerance_bps: u16) -> bool {
+    let deviation = value.abs_diff(median) as u128 * BPS_DENOMINATOR as u128;
+    deviation <= median as u128 * tolerance_bps as u128
+}
@@ -0,0 +1,66 @@
+//! # Oracle Registry
+//!
+//! Global, admin-managed registry of oracles allowed to report campaign metrics.
+//!
+//! ## PDA Layout
+//!
+//! ```text
+//! OracleRegistry  ["oracle_registry"]             (singleton)
+//!   └── OracleEntry  ["oracle", id (u64, LE)]     (one per registered oracle)
+//! ```
+//!
+//! Campaigns store the address of an `OracleEntry` in `Campaign::oracle`
+//! instead of a raw signing key. The key allowed to sign reports is
+//! `OracleEntry::authority`, so rotating it in the registry takes effect
+//! immediately for every live campaign referencing that entry.
+
+use anchor_lang::prelude::*;
+
+/// Singleton registry account.
+///
+/// Seeds: `["oracle_registry"]`
+#[account]
+#[derive(InitSpace)]
+pub struct OracleRegistry {
+    /// Registry administrator (the program upgrade authority at initialization)
+    pub admin: Pubkey,
+
+    /// Key whose signed evidence upholds challenges against oracles
+    /// (defaults to the admin, changeable via `set_arbiter`)
+    pub arbiter: Pubkey,
+
+    /// Number of oracles registered so far (next `OracleEntry::id`)
+    pub oracle_count: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// A registered oracle.
+///
+/// Seeds: `["oracle", id.to_le_bytes()]`
+#[account]
+#[derive(InitSpace)]
+pub struct OracleEntry {
+    /// Sequential identifier assigned by the registry
+    pub id: u64,
+
+    /// Current signing key of the oracle (rotatable by the admin)
+    pub authority: Pubkey,
+
+    /// Whether the oracle may be referenced by new campaigns and submit reports
+    pub active: bool,
+
+    /// Registration timestamp (Unix timestamp)
+    pub registered_at: i64,
+
+    /// Last key rotation timestamp (equals `registered_at` until first rotation)
+    pub rotated_at: i64,
+
+    /// When the admin last deactivated the oracle (Unix timestamp, 0 while active)
+    /// Starts the `ORACLE_UNBONDING_PERIOD` before the bond can be withdrawn
+    pub deactivated_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
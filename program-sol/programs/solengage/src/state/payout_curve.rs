@@ -0,0 +1,317 @@
+//! # Payout Curve
+//!
+//! Maps campaign progress to the cumulative share of the budget released to
+//! the influencer. Both axes are expressed in basis points (0-10_000).
+//!
+//! ## Built-in Curves
+//!
+//! ```text
+//! payout
+//!  100% ┤                 ╭─     Linear:  p(x) = x
+//!       │             ╭──╯ ·     Convex:  p(x) = x² / 10_000
+//!       │         ╭──╯  ·        Concave: p(x) = x · (20_000 - x) / 10_000
+//!       │     ╭──╯   ·
+//!       │  ╭─╯    ·
+//!    0% ┼─╯ · · ·
+//!       0%                100%  progress
+//! ```
+//!
+//! - **Linear**: Legacy behavior, every 10% milestone releases 10% of the budget.
+//! - **Convex**: Back-loaded, early (easy) tranches release little.
+//! - **Concave**: Front-loaded, early tranches release more.
+//! - **Piecewise**: Explicit table of `(progress_bps, payout_bps)` points,
+//!   linearly interpolated, starting from the implicit point `(0, 0)`.
+//!
+//! ## Guarantees
+//!
+//! Every valid curve is monotonically non-decreasing and satisfies
+//! `p(0) = 0` and `p(10_000) = 10_000`, so the cumulative payout never
+//! exceeds the budget and the full budget is released at 100% progress.
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+
+/// Basis points denominator (100% = 10_000 bps).
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Maximum number of points in a `Piecewise` curve.
+pub const MAX_CURVE_POINTS: usize = 8;
+
+/// A single point of a piecewise payout curve.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct CurvePoint {
+    /// Campaign progress in basis points (0-10_000)
+    pub progress_bps: u16,
+
+    /// Cumulative payout in basis points of `amount_usdc` (0-10_000)
+    pub payout_bps: u16,
+}
+
+/// Payout curve used to price each 10% milestone.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace, Default)]
+pub enum PayoutCurve {
+    /// Cumulative payout equals progress (default, legacy behavior).
+    #[default]
+    Linear,
+
+    /// Quadratic back-loaded curve: `x² / 10_000`.
+    Convex,
+
+    /// Quadratic front-loaded curve: `x · (20_000 - x) / 10_000`.
+    Concave,
+
+    /// Explicit table, interpolated linearly between points.
+    ///
+    /// Points must have strictly increasing `progress_bps`, non-decreasing
+    /// `payout_bps`, and the last point must be `(10_000, 10_000)`.
+    Piecewise {
+        #[max_len(8)]
+        points: Vec<CurvePoint>,
+    },
+}
+
+impl PayoutCurve {
+    /// Validates that the curve is monotonic and ends at 100% payout.
+    ///
+    /// Built-in curves are always valid. For `Piecewise` curves:
+    /// - 1 to `MAX_CURVE_POINTS` points
+    /// - `progress_bps` strictly increasing and > 0
+    /// - `payout_bps` non-decreasing and <= 10_000
+    /// - Last point is exactly `(10_000, 10_000)`
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidPayoutCurve` - Any of the rules above is violated
+    pub fn validate(&self) -> Result<()> {
+        let points = match self {
+            PayoutCurve::Piecewise { points } => points,
+            _ => return Ok(()),
+        };
+
+        require!(
+            !points.is_empty() && points.len() <= MAX_CURVE_POINTS,
+            ErrorCode::InvalidPayoutCurve
+        );
+
+        let mut previous = CurvePoint { progress_bps: 0, payout_bps: 0 };
+        for point in points.iter() {
+            require!(point.progress_bps > previous.progress_bps, ErrorCode::InvalidPayoutCurve);
+            require!(point.payout_bps >= previous.payout_bps, ErrorCode::InvalidPayoutCurve);
+            require!(u64::from(point.payout_bps) <= BPS_DENOMINATOR, ErrorCode::InvalidPayoutCurve);
+            previous = *point;
+        }
+
+        require!(
+            u64::from(previous.progress_bps) == BPS_DENOMINATOR
+                && u64::from(previous.payout_bps) == BPS_DENOMINATOR,
+            ErrorCode::InvalidPayoutCurve
+        );
+
+        Ok(())
+    }
+
+    /// Evaluates the curve, returning the cumulative payout in basis points.
+    ///
+    /// `progress_bps` is clamped to 10_000, so the result is always within
+    /// `0..=10_000` for a validated curve.
+    pub fn payout_bps(&self, progress_bps: u64) -> u64 {
+        let x = progress_bps.min(BPS_DENOMINATOR);
+
+        match self {
+            PayoutCurve::Linear => x,
+            PayoutCurve::Convex => x * x / BPS_DENOMINATOR,
+            PayoutCurve::Concave => x * (2 * BPS_DENOMINATOR - x) / BPS_DENOMINATOR,
+            PayoutCurve::Piecewise { points } => {
+                let mut start = CurvePoint { progress_bps: 0, payout_bps: 0 };
+                for end in points.iter() {
+                    let end_x = u64::from(end.progress_bps);
+                    if x <= end_x {
+                        let start_x = u64::from(start.progress_bps);
+                        let start_y = u64::from(start.payout_bps);
+                        let end_y = u64::from(end.payout_bps);
+                        // end_x > start_x is guaranteed by `validate`
+                        return start_y + (end_y - start_y) * (x - start_x) / (end_x - start_x);
+                    }
+                    start = *end;
+                }
+                u64::from(start.payout_bps).min(BPS_DENOMINATOR)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::Campaign;
+
+    /// Number of curves generated per property.
+    const CASES: usize = 2_000;
+
+    /// Deterministic xorshift generator, so failures are reproducible.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, bound: u64) -> u64 {
+            self.next() % bound
+        }
+    }
+
+    /// Random valid piecewise curve: sorted distinct progress values, sorted payouts,
+    /// ending at `(10_000, 10_000)`.
+    fn valid_piecewise(rng: &mut Rng) -> PayoutCurve {
+        let len = 1 + rng.below(MAX_CURVE_POINTS as u64) as usize;
+        let mut xs: Vec<u16> = Vec::new();
+        while xs.len() < len - 1 {
+            let x = 1 + rng.below(BPS_DENOMINATOR - 1) as u16;
+            if !xs.contains(&x) {
+                xs.push(x);
+            }
+        }
+        xs.sort_unstable();
+        let mut ys: Vec<u16> = (0..len - 1).map(|_| rng.below(BPS_DENOMINATOR + 1) as u16).collect();
+        ys.sort_unstable();
+
+        let mut points: Vec<CurvePoint> = xs
+            .into_iter()
+            .zip(ys)
+            .map(|(progress_bps, payout_bps)| CurvePoint { progress_bps, payout_bps })
+            .collect();
+        points.push(CurvePoint { progress_bps: 10_000, payout_bps: 10_000 });
+        PayoutCurve::Piecewise { points }
+    }
+
+    fn curves(rng: &mut Rng) -> Vec<PayoutCurve> {
+        let mut curves = vec![PayoutCurve::Linear, PayoutCurve::Convex, PayoutCurve::Concave];
+        curves.extend((0..CASES).map(|_| valid_piecewise(rng)));
+        curves
+    }
+
+    /// Campaign with every field zeroed except the budget and the curve.
+    fn campaign(amount_usdc: u64, payout_curve: PayoutCurve) -> Campaign {
+        let zeroed = vec![0u8; Campaign::INIT_SPACE];
+        let mut campaign = Campaign::deserialize(&mut zeroed.as_slice()).unwrap();
+        campaign.amount_usdc = amount_usdc;
+        campaign.payout_curve = payout_curve;
+        campaign
+    }
+
+    /// Random budget, odd about half the time, plus the edge cases.
+    fn budgets(rng: &mut Rng) -> Vec<u64> {
+        let mut budgets = vec![1, 3, 7, 999_999, 1_000_001, u64::MAX];
+        budgets.extend((0..8).map(|_| rng.below(1_000_000_000_000)));
+        budgets
+    }
+
+    #[test]
+    fn generated_curves_are_valid() {
+        let mut rng = Rng(0x5eed_0001);
+        for curve in curves(&mut rng) {
+            assert!(curve.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn payout_starts_at_zero_and_ends_at_full_budget() {
+        let mut rng = Rng(0x5eed_0002);
+        for curve in curves(&mut rng) {
+            assert_eq!(curve.payout_bps(0), 0);
+            assert_eq!(curve.payout_bps(BPS_DENOMINATOR), BPS_DENOMINATOR);
+            assert_eq!(curve.payout_bps(u64::MAX), BPS_DENOMINATOR);
+        }
+    }
+
+    #[test]
+    fn payout_is_monotonic_and_bounded() {
+        let mut rng = Rng(0x5eed_0003);
+        for curve in curves(&mut rng) {
+            let mut previous = 0;
+            for progress in (0..=BPS_DENOMINATOR).step_by(7).chain([BPS_DENOMINATOR]) {
+                let payout = curve.payout_bps(progress);
+                assert!(payout >= previous, "payout decreased at {progress} bps");
+                assert!(payout <= BPS_DENOMINATOR, "payout above 100% at {progress} bps");
+                previous = payout;
+            }
+        }
+    }
+
+    #[test]
+    fn milestone_payments_never_exceed_and_add_up_to_the_budget() {
+        let mut rng = Rng(0x5eed_0006);
+        for curve in curves(&mut rng) {
+            for amount_usdc in budgets(&mut rng) {
+                let mut campaign = campaign(amount_usdc, curve.clone());
+                for milestone in 0..10 {
+                    let payment = campaign.calculate_safe_payment(milestone).unwrap();
+                    campaign.paid_amount += payment;
+                    assert!(campaign.paid_amount <= amount_usdc, "overpaid at milestone {milestone}");
+                    assert_eq!(campaign.paid_amount, campaign.cumulative_payout_for_milestone(milestone).unwrap());
+                }
+                assert_eq!(campaign.paid_amount, amount_usdc, "budget not released at milestone 9");
+            }
+        }
+    }
+
+    #[test]
+    fn validate_rejects_non_monotonic_points() {
+        let mut rng = Rng(0x5eed_0004);
+        for _ in 0..CASES {
+            let PayoutCurve::Piecewise { mut points } = valid_piecewise(&mut rng) else {
+                unreachable!()
+            };
+            if points.len() < 2 {
+                continue;
+            }
+            let i = rng.below(points.len() as u64 - 1) as usize;
+            if rng.next() % 2 == 0 {
+                // Progress not strictly increasing
+                points[i].progress_bps = points[i + 1].progress_bps;
+            } else {
+                // Payout decreasing
+                points[i].payout_bps = points[i + 1].payout_bps + 1;
+            }
+            assert!(PayoutCurve::Piecewise { points }.validate().is_err());
+        }
+    }
+
+    #[test]
+    fn validate_rejects_out_of_range_points() {
+        let mut rng = Rng(0x5eed_0005);
+        for _ in 0..CASES {
+            let PayoutCurve::Piecewise { mut points } = valid_piecewise(&mut rng) else {
+                unreachable!()
+            };
+            let last = points.len() - 1;
+            let above = 10_001 + rng.below(u64::from(u16::MAX) - 10_001) as u16;
+            match rng.below(3) {
+                0 => points[last].payout_bps = above,
+                1 => points[last].progress_bps = above,
+                _ => points[last].payout_bps = rng.below(BPS_DENOMINATOR) as u16,
+            }
+            assert!(PayoutCurve::Piecewise { points }.validate().is_err());
+        }
+    }
+
+    #[test]
+    fn validate_rejects_empty_or_oversized_tables() {
+        assert!(PayoutCurve::Piecewise { points: vec![] }.validate().is_err());
+
+        let points = (1..=MAX_CURVE_POINTS as u16 + 1)
+            .map(|i| CurvePoint { progress_bps: i * 1_000, payout_bps: i * 1_000 })
+            .collect::<Vec<_>>();
+        assert!(PayoutCurve::Piecewise { points }.validate().is_err());
+
+        let zero_progress = vec![
+            CurvePoint { progress_bps: 0, payout_bps: 0 },
+            CurvePoint { progress_bps: 10_000, payout_bps: 10_000 },
+        ];
+        assert!(PayoutCurve::Piecewise { points: zero_progress }.validate().is_err());
+    }
+}
@@ -0,0 +1,24 @@
+//! # Platform
+//!
+//! Social networks where campaign content is published.
+
+use anchor_lang::prelude::*;
+
+/// Social network hosting campaign content.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum Platform {
+    /// Instagram (feed posts, Reels, Stories)
+    Instagram,
+
+    /// TikTok videos
+    TikTok,
+
+    /// X (formerly Twitter) posts
+    X,
+
+    /// YouTube videos and Shorts
+    YouTube,
+
+    /// Twitch streams and clips
+    Twitch,
+}
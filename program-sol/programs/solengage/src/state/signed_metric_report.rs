@@ -0,0 +1,57 @@
+//! # Signed Metric Report
+//!
+//! Metric report signed off-chain by an oracle and relayed by anyone.
+//!
+//! ## Signed Message
+//!
+//! The oracle signs, with Ed25519, the bytes returned by
+//! [`SignedMetricReport::message`]:
+//!
+//! ```text
+//! "solengage:metric-report:v1"   domain separator (26 bytes)
+//! campaign                       32 bytes (Pubkey)
+//! likes, comments, views, shares 4 * 8 bytes (u64, little-endian)
+//! observed_at                    8 bytes (i64, little-endian)
+//! nonce                          8 bytes (u64, little-endian)
+//! ```
+//!
+//! The relayer places an Ed25519 program instruction verifying that signature
+//! immediately before `submit_signed_metrics` in the same transaction.
+
+use anchor_lang::prelude::*;
+use super::metric_values::MetricValues;
+
+/// Domain separator prefixed to every signed report message.
+pub const SIGNED_REPORT_DOMAIN: &[u8] = b"solengage:metric-report:v1";
+
+/// Metric report as signed by the oracle.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct SignedMetricReport {
+    /// Campaign the report applies to (prevents cross-campaign replay)
+    pub campaign: Pubkey,
+
+    /// Observed metric values
+    pub values: MetricValues,
+
+    /// When the oracle observed the values (Unix timestamp)
+    pub observed_at: i64,
+
+    /// Strictly increasing per campaign (prevents replay)
+    pub nonce: u64,
+}
+
+impl SignedMetricReport {
+    /// Bytes the oracle must sign (see module docs for the layout).
+    pub fn message(&self) -> Vec<u8> {
+        let mut message = Vec::with_capacity(SIGNED_REPORT_DOMAIN.len() + 32 + 4 * 8 + 8 + 8);
+        message.extend_from_slice(SIGNED_REPORT_DOMAIN);
+        message.extend_from_slice(self.campaign.as_ref());
+        message.extend_from_slice(&self.values.likes.to_le_bytes());
+        message.extend_from_slice(&self.values.comments.to_le_bytes());
+        message.extend_from_slice(&self.values.views.to_le_bytes());
+        message.extend_from_slice(&self.values.shares.to_le_bytes());
+        message.extend_from_slice(&self.observed_at.to_le_bytes());
+        message.extend_from_slice(&self.nonce.to_le_bytes());
+        message
+    }
+}
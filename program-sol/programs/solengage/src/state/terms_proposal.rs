@@ -0,0 +1,42 @@
+//! # Terms Proposal
+//!
+//! Pending set of campaign terms put forward by one party of a Draft campaign.
+//!
+//! ## Negotiation
+//!
+//! ```text
+//!   propose_terms (influencer or brand)
+//!        │
+//!        v
+//!   pending_terms ──propose_terms (counterparty)──> pending_terms (counter-proposal)
+//!        │
+//!        │ accept_terms (counterparty, same version)
+//!        v
+//!   campaign terms updated ──> brand_pay_campaign (same version)
+//! ```
+//!
+//! Each proposal bumps `Campaign::terms_version` and carries the new value, so an
+//! acceptance always refers to one exact proposal. While a proposal is pending the
+//! campaign cannot be funded; any other change to the terms discards it.
+
+use anchor_lang::prelude::*;
+use super::metric_values::MetricValues;
+
+/// Terms proposed by the influencer or the brand, awaiting the other party.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct TermsProposal {
+    /// Party that made the proposal (campaign.influencer or campaign.brand)
+    pub proposer: Pubkey,
+
+    /// Terms version assigned to the proposal (the acceptance must name it)
+    pub version: u32,
+
+    /// Proposed campaign-wide targets (replace the base entry targets when accepted)
+    pub targets: MetricValues,
+
+    /// Proposed budget in USDC (6 decimals)
+    pub amount_usdc: u64,
+
+    /// Proposed deadline (Unix timestamp)
+    pub deadline: i64,
+}
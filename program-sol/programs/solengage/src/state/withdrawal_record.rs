@@ -0,0 +1,50 @@
+//! # Withdrawal Record
+//!
+//! Permanent record of an influencer walking away from a campaign.
+//!
+//! ## PDA Layout
+//!
+//! ```text
+//! Campaign  ["campaign", influencer, brand, name]
+//!   └── WithdrawalRecord  ["withdrawal", campaign]  (created by influencer_withdraw)
+//! ```
+//!
+//! The record is never closed (`close_withdrawal` closes only the campaign), so
+//! reputation tools can list every withdrawal of an influencer by filtering
+//! `WithdrawalRecord` accounts on `influencer`.
+
+use anchor_lang::prelude::*;
+
+/// Outcome of an `influencer_withdraw` call.
+///
+/// Seeds: `["withdrawal", campaign]`
+#[account]
+#[derive(InitSpace)]
+pub struct WithdrawalRecord {
+    /// Campaign the influencer withdrew from
+    pub campaign: Pubkey,
+
+    /// Influencer who withdrew (paid the rent)
+    pub influencer: Pubkey,
+
+    /// Brand refunded by the withdrawal
+    pub brand: Pubkey,
+
+    /// When the influencer withdrew (Unix timestamp)
+    pub withdrawn_at: i64,
+
+    /// Campaign progress at withdrawal (0-100)
+    pub progress: u8,
+
+    /// USDC paid to the influencer, including the kept share of pending earnings
+    pub paid_amount: u64,
+
+    /// Unpaid budget refunded to the brand, excluding the oracle fee reserve
+    pub refunded_amount: u64,
+
+    /// Share of an unapplied (held or pending) report's earnings given up by the influencer
+    pub forfeited_amount: u64,
+
+    /// PDA bump
+    pub bump: u8,
+}
@@ -27,6 +27,7 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { Solengage } from "../target/types/solengage";
+import { registerOracle } from "./utils/oracle_registry";
 import { 
   PublicKey, 
   Keypair, 
@@ -41,6 +42,7 @@ import {
   getAccount
 } from "@solana/spl-token";
 import { expect } from "chai";
+import { baseMetric } from "./utils/metrics";
 
 describe("Solengage - BDD Tests", () => {
   // Configure the client to use the local cluster
@@ -57,6 +59,7 @@ describe("Solengage - BDD Tests", () => {
   let influencer: Keypair;
   let brand: Keypair;
   let oracle: Keypair;
+  let oracleEntry: PublicKey;
   let usdcMint: PublicKey;
   let brandUsdcAccount: PublicKey;
   let campaignUsdcAccount: PublicKey;
@@ -116,6 +119,9 @@ describe("Solengage - BDD Tests", () => {
       await provider.connection.confirmTransaction(brandAirdrop, "confirmed");
       await provider.connection.confirmTransaction(oracleAirdrop, "confirmed");
 
+      // Register the oracle in the global registry
+      oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+
       // Wait for airdrops to be processed
       await new Promise(resolve => setTimeout(resolve, 2000));
 
@@ -239,7 +245,7 @@ describe("Solengage - BDD Tests", () => {
               campaign: campaignPda,
               influencer: influencer.publicKey,
               brand: brand.publicKey,
-              oracle: oracle.publicKey,
+              oracle: oracleEntry,
               systemProgram: SystemProgram.programId,
             })
             .signers([influencer])
@@ -256,14 +262,14 @@ describe("Solengage - BDD Tests", () => {
           console.log("   ✓ Verificando campos básicos...");
           expect(campaignAccount.influencer.toString()).to.equal(influencer.publicKey.toString());
           expect(campaignAccount.brand.toString()).to.equal(brand.publicKey.toString());
-          expect(campaignAccount.oracle.toString()).to.equal(oracle.publicKey.toString());
+          expect(campaignAccount.oracle.toString()).to.equal(oracleEntry.toString());
           expect(campaignAccount.name).to.equal(campaignName);
           expect(campaignAccount.brandName).to.equal(brandName);
           expect(campaignAccount.hashtag).to.equal(hashtag);
-          expect(campaignAccount.targetLikes.toNumber()).to.equal(targetLikes);
-          expect(campaignAccount.targetComments.toNumber()).to.equal(targetComments);
-          expect(campaignAccount.targetViews.toNumber()).to.equal(targetViews);
-          expect(campaignAccount.targetShares.toNumber()).to.equal(targetShares);
+          expect(baseMetric(campaignAccount, "likes").target.toNumber()).to.equal(targetLikes);
+          expect(baseMetric(campaignAccount, "comments").target.toNumber()).to.equal(targetComments);
+          expect(baseMetric(campaignAccount, "views").target.toNumber()).to.equal(targetViews);
+          expect(baseMetric(campaignAccount, "shares").target.toNumber()).to.equal(targetShares);
           expect(campaignAccount.amountUsdc.toNumber()).to.equal(amountUsdc);
           expect(campaignAccount.deadline.toNumber()).to.equal(deadline);
 
@@ -274,10 +280,10 @@ describe("Solengage - BDD Tests", () => {
 
           // Verificar métricas atuais zeradas
           console.log("   ✓ Verificando métricas iniciais...");
-          expect(campaignAccount.currentLikes.toNumber()).to.equal(0);
-          expect(campaignAccount.currentComments.toNumber()).to.equal(0);
-          expect(campaignAccount.currentViews.toNumber()).to.equal(0);
-          expect(campaignAccount.currentShares.toNumber()).to.equal(0);
+          expect(baseMetric(campaignAccount, "likes").current.toNumber()).to.equal(0);
+          expect(baseMetric(campaignAccount, "comments").current.toNumber()).to.equal(0);
+          expect(baseMetric(campaignAccount, "views").current.toNumber()).to.equal(0);
+          expect(baseMetric(campaignAccount, "shares").current.toNumber()).to.equal(0);
           console.log("     - Todas as métricas zeradas ✓");
 
           // Verificar valor pago zerado
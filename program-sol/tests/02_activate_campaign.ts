@@ -23,6 +23,7 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { Solengage } from "../target/types/solengage";
+import { bondOracle, registerOracle } from "./utils/oracle_registry";
 import { 
   PublicKey, 
   Keypair, 
@@ -38,6 +39,7 @@ import {
   getOrCreateAssociatedTokenAccount
 } from "@solana/spl-token";
 import { expect } from "chai";
+import { termsVersion } from "./utils/terms";
 
 describe("Solengage - BDD Tests", () => {
   // Configure the client to use the local cluster
@@ -49,6 +51,7 @@ describe("Solengage - BDD Tests", () => {
   let influencer: Keypair;
   let brand: Keypair;
   let oracle: Keypair;
+  let oracleEntry: PublicKey;
   let usdcMint: PublicKey;
   let brandUsdcAccount: PublicKey;
   let campaignPda: PublicKey;
@@ -81,9 +84,14 @@ describe("Solengage - BDD Tests", () => {
     ]);
     console.log("✅ Airdrops confirmados");
 
+    console.log("\n🔮 Registrando oracle...");
+    oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+    console.log("✅ Oracle registrado:", oracleEntry.toBase58());
+
     // Create USDC Mint
     console.log("\n🪙 Criando USDC mint (6 decimals)...");
     usdcMint = await createMint(provider.connection, brand, brand.publicKey, null, 6);
+    await bondOracle(program, provider, oracle, oracleEntry, usdcMint, brand);
     console.log("✅ USDC mint criado:", usdcMint.toBase58());
 
     // Create Brand's USDC account and mint tokens
@@ -139,7 +147,7 @@ describe("Solengage - BDD Tests", () => {
         campaign: campaignPda,
         influencer: influencer.publicKey,
         brand: brand.publicKey,
-        oracle: oracle.publicKey,
+        oracle: oracleEntry,
         systemProgram: SystemProgram.programId,
       })
       .signers([influencer])
@@ -167,7 +175,7 @@ describe("Solengage - BDD Tests", () => {
       console.log("\n💰 WHEN: Brand paga para ativar campanha...");
       console.log("   - Transferindo", amountUsdc.toNumber() / 1_000_000, "USDC para campaign vault");
       await program.methods
-        .brandPayCampaign()
+        .brandPayCampaign(await termsVersion(program, campaignPda))
         .accounts({
           campaign: campaignPda,
           brand: brand.publicKey,
@@ -17,6 +17,7 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { Solengage } from "../target/types/solengage";
+import { bondOracle, registerOracle } from "./utils/oracle_registry";
 import { 
   PublicKey, 
   Keypair, 
@@ -31,6 +32,9 @@ import {
   mintTo
 } from "@solana/spl-token";
 import { expect } from "chai";
+import { nextReport } from "./utils/reports";
+import { baseMetric } from "./utils/metrics";
+import { termsVersion } from "./utils/terms";
 
 describe("Solengage - 03 Update Campaign Metrics", () => {
   anchor.setProvider(anchor.AnchorProvider.env());
@@ -39,6 +43,7 @@ describe("Solengage - 03 Update Campaign Metrics", () => {
 
   // Accounts
   let influencer: Keypair, brand: Keypair, oracle: Keypair;
+  let oracleEntry: PublicKey;
   let usdcMint: PublicKey;
   let brandUsdcAccount: PublicKey, influencerUsdcAccount: PublicKey, campaignUsdcAccount: PublicKey;
   let campaignPda: PublicKey;
@@ -70,8 +75,13 @@ describe("Solengage - 03 Update Campaign Metrics", () => {
     await provider.connection.requestAirdrop(oracle.publicKey, 2 * LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig));
     console.log("✅ Airdrops confirmados");
 
+    console.log("\n🔮 Registrando oracle...");
+    oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+    console.log("✅ Oracle registrado:", oracleEntry.toBase58());
+
     console.log("\n🪙 Criando USDC mint...");
     usdcMint = await createMint(provider.connection, brand, brand.publicKey, null, 6);
+    await bondOracle(program, provider, oracle, oracleEntry, usdcMint, brand);
     console.log("✅ USDC mint criado:", usdcMint.toBase58());
 
     console.log("\n🔍 Calculando Campaign PDA...");
@@ -101,7 +111,7 @@ describe("Solengage - 03 Update Campaign Metrics", () => {
       )
       .accounts({
           campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey,
-          oracle: oracle.publicKey, systemProgram: SystemProgram.programId
+          oracle: oracleEntry, systemProgram: SystemProgram.programId
       })
       .signers([influencer])
       .rpc();
@@ -110,7 +120,7 @@ describe("Solengage - 03 Update Campaign Metrics", () => {
     // Activate Campaign
     console.log("\n💰 Ativando campanha...");
     await program.methods
-      .brandPayCampaign()
+      .brandPayCampaign(await termsVersion(program, campaignPda))
       .accounts({
           campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount,
           campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID
@@ -144,10 +154,11 @@ describe("Solengage - 03 Update Campaign Metrics", () => {
     console.log("   - New Likes:", newLikes.toString(), "(10% do alvo)");
 
     await program.methods
-      .updateCampaignMetrics(newLikes, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0))
+      .updateCampaignMetrics(newLikes, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), ...nextReport())
       .accounts({
         campaign: campaignPda,
-        oracle: oracle.publicKey,
+        oracle: oracleEntry,
+        oracleAuthority: oracle.publicKey,
         campaignUsdcAccount: campaignUsdcAccount,
         influencerUsdcAccount: influencerUsdcAccount,
         tokenProgram: TOKEN_PROGRAM_ID,
@@ -164,8 +175,8 @@ describe("Solengage - 03 Update Campaign Metrics", () => {
 
     // 1. Metrics are updated
     console.log("\n   ✓ Verificando atualização de métricas...");
-    expect(finalCampaignState.currentLikes.toString()).to.equal(newLikes.toString());
-    console.log("     - Current Likes:", finalCampaignState.currentLikes.toString(), "✓");
+    expect(baseMetric(finalCampaignState, "likes").current.toString()).to.equal(newLikes.toString());
+    console.log("     - Current Likes:", baseMetric(finalCampaignState, "likes").current.toString(), "✓");
 
     // 2. Influencer received the payment
     console.log("\n   ✓ Verificando pagamento ao influencer...");
@@ -18,9 +18,12 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { Solengage } from "../target/types/solengage";
+import { bondOracle, registerOracle } from "./utils/oracle_registry";
 import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
 import { getAccount, getOrCreateAssociatedTokenAccount, createMint, TOKEN_PROGRAM_ID, mintTo } from "@solana/spl-token";
 import { expect } from "chai";
+import { nextReport } from "./utils/reports";
+import { termsVersion } from "./utils/terms";
 
 describe("Solengage - 04 Micro Payments", () => {
   anchor.setProvider(anchor.AnchorProvider.env());
@@ -28,6 +31,7 @@ describe("Solengage - 04 Micro Payments", () => {
   const provider = anchor.getProvider() as anchor.AnchorProvider;
 
   let influencer: Keypair, brand: Keypair, oracle: Keypair;
+  let oracleEntry: PublicKey;
   let usdcMint: PublicKey;
   let influencerUsdcAccount: PublicKey, campaignUsdcAccount: PublicKey;
   let campaignPda: PublicKey;
@@ -55,8 +59,13 @@ describe("Solengage - 04 Micro Payments", () => {
     ]);
     console.log("✅ Airdrops confirmados");
 
+    console.log("\n🔮 Registrando oracle...");
+    oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+    console.log("✅ Oracle registrado");
+
     console.log("\n🪙 Criando USDC mint...");
     usdcMint = await createMint(provider.connection, brand, brand.publicKey, null, 6);
+    await bondOracle(program, provider, oracle, oracleEntry, usdcMint, brand);
     console.log("✅ USDC mint criado");
 
     console.log("\n💼 Criando token accounts...");
@@ -78,14 +87,14 @@ describe("Solengage - 04 Micro Payments", () => {
     console.log("\n📋 Criando campanha...");
     await program.methods
       .createCampaign(campaignName, "test-nickname", "Brand", "#micropayments", targetLikes, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), totalAmount, new anchor.BN(Date.now() / 1000 + 86400))
-      .accounts({ campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey, oracle: oracle.publicKey, systemProgram: SystemProgram.programId })
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey, oracle: oracleEntry, systemProgram: SystemProgram.programId })
       .signers([influencer])
       .rpc();
     console.log("✅ Campanha criada");
 
     console.log("\n💰 Ativando campanha...");
     await program.methods
-      .brandPayCampaign()
+      .brandPayCampaign(await termsVersion(program, campaignPda))
       .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
       .signers([brand])
       .rpc();
@@ -105,8 +114,8 @@ describe("Solengage - 04 Micro Payments", () => {
     // WHEN: First update to 15% progress (150 likes)
     console.log("\n📊 WHEN (1ª atualização): Oracle atualiza para 150 likes (15% do alvo)...");
     await program.methods
-      .updateCampaignMetrics(new anchor.BN(150), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0))
-      .accounts({ campaign: campaignPda, oracle: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .updateCampaignMetrics(new anchor.BN(150), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), ...nextReport())
+      .accounts({ campaign: campaignPda, oracle: oracleEntry, oracleAuthority: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
       .signers([oracle])
       .rpc();
     console.log("✅ Métricas atualizadas para 15%");
@@ -130,8 +139,8 @@ describe("Solengage - 04 Micro Payments", () => {
     // WHEN: Second update to 32% progress (320 likes)
     console.log("\n📊 WHEN (2ª atualização): Oracle atualiza para 320 likes (32% do alvo)...");
     await program.methods
-      .updateCampaignMetrics(new anchor.BN(320), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0))
-      .accounts({ campaign: campaignPda, oracle: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .updateCampaignMetrics(new anchor.BN(320), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), ...nextReport())
+      .accounts({ campaign: campaignPda, oracle: oracleEntry, oracleAuthority: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
       .signers([oracle])
       .rpc();
     console.log("✅ Métricas atualizadas para 32%");
@@ -16,9 +16,13 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { Solengage } from "../target/types/solengage";
+import { bondOracle, registerOracle } from "./utils/oracle_registry";
 import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
 import { getOrCreateAssociatedTokenAccount, createMint, TOKEN_PROGRAM_ID, mintTo } from "@solana/spl-token";
 import { expect } from "chai";
+import { nextReport } from "./utils/reports";
+import { baseMetric } from "./utils/metrics";
+import { termsVersion } from "./utils/terms";
 
 describe("Solengage - 05 Fetch Campaign Info", () => {
   anchor.setProvider(anchor.AnchorProvider.env());
@@ -26,6 +30,7 @@ describe("Solengage - 05 Fetch Campaign Info", () => {
   const provider = anchor.getProvider() as anchor.AnchorProvider;
 
   let influencer: Keypair, brand: Keypair, oracle: Keypair;
+  let oracleEntry: PublicKey;
   let usdcMint: PublicKey;
   let campaignPda: PublicKey;
 
@@ -58,8 +63,13 @@ describe("Solengage - 05 Fetch Campaign Info", () => {
     ]);
     console.log("✅ Airdrops confirmados");
 
+    console.log("\n🔮 Registrando oracle...");
+    oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+    console.log("✅ Oracle registrado");
+
     console.log("\n🪙 Criando USDC mint...");
     usdcMint = await createMint(provider.connection, brand, brand.publicKey, null, 6);
+    await bondOracle(program, provider, oracle, oracleEntry, usdcMint, brand);
     console.log("✅ USDC mint criado");
 
     console.log("\n💼 Criando token accounts...");
@@ -82,7 +92,7 @@ describe("Solengage - 05 Fetch Campaign Info", () => {
     console.log("\n📋 Criando campanha...");
     await program.methods
       .createCampaign(campaignName, "test-nickname", brandName, hashtag, targetLikes, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), totalAmount, deadline)
-      .accounts({ campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey, oracle: oracle.publicKey, systemProgram: SystemProgram.programId })
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey, oracle: oracleEntry, systemProgram: SystemProgram.programId })
       .signers([influencer])
       .rpc();
     console.log("✅ Campanha criada");
@@ -90,7 +100,7 @@ describe("Solengage - 05 Fetch Campaign Info", () => {
     // Activate
     console.log("\n💰 Ativando campanha...");
     await program.methods
-      .brandPayCampaign()
+      .brandPayCampaign(await termsVersion(program, campaignPda))
       .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
       .signers([brand])
       .rpc();
@@ -99,8 +109,8 @@ describe("Solengage - 05 Fetch Campaign Info", () => {
     // Update Metrics
     console.log("\n📊 Atualizando métricas para 15%...");
     await program.methods
-      .updateCampaignMetrics(updatedLikes, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0))
-      .accounts({ campaign: campaignPda, oracle: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .updateCampaignMetrics(updatedLikes, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), ...nextReport())
+      .accounts({ campaign: campaignPda, oracle: oracleEntry, oracleAuthority: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
       .signers([oracle])
       .rpc();
     console.log("✅ Métricas atualizadas");
@@ -127,7 +137,7 @@ describe("Solengage - 05 Fetch Campaign Info", () => {
     expect(campaignState.hashtag).to.equal(hashtag);
     expect(campaignState.influencer.equals(influencer.publicKey)).to.be.true;
     expect(campaignState.brand.equals(brand.publicKey)).to.be.true;
-    expect(campaignState.oracle.equals(oracle.publicKey)).to.be.true;
+    expect(campaignState.oracle.equals(oracleEntry)).to.be.true;
     expect(campaignState.amountUsdc.toString()).to.equal(totalAmount.toString());
     expect(campaignState.deadline.toString()).to.equal(deadline.toString());
     console.log("     - Status:", Object.keys(campaignState.status)[0], "✓");
@@ -138,11 +148,11 @@ describe("Solengage - 05 Fetch Campaign Info", () => {
 
     // Verify metrics
     console.log("\n   ✓ Verificando métricas...");
-    expect(campaignState.targetLikes.toString()).to.equal(targetLikes.toString());
-    expect(campaignState.currentLikes.toString()).to.equal(updatedLikes.toString());
-    console.log("     - Target Likes:", campaignState.targetLikes.toString(), "✓");
-    console.log("     - Current Likes:", campaignState.currentLikes.toString(), "✓");
-    console.log("     - Progress:", (Number(campaignState.currentLikes) / Number(campaignState.targetLikes) * 100).toFixed(1), "% ✓");
+    expect(baseMetric(campaignState, "likes").target.toString()).to.equal(targetLikes.toString());
+    expect(baseMetric(campaignState, "likes").current.toString()).to.equal(updatedLikes.toString());
+    console.log("     - Target Likes:", baseMetric(campaignState, "likes").target.toString(), "✓");
+    console.log("     - Current Likes:", baseMetric(campaignState, "likes").current.toString(), "✓");
+    console.log("     - Progress:", (Number(baseMetric(campaignState, "likes").current) / Number(baseMetric(campaignState, "likes").target) * 100).toFixed(1), "% ✓");
 
     // Verify payment state
     console.log("\n   ✓ Verificando estado de pagamentos...");
@@ -1,22 +1,23 @@
 /**
  * Testes Solengage - Especificação BDD
  *
- * Feature: Fechamento Automático de Campanha ao Atingir 100% de Conclusão
+ * Feature: Conclusão de Campanha ao Atingir 100% das Metas
  *
- * Cenário: Campanha é automaticamente fechada quando atinge 100% das metas
+ * Cenário: Campanha concluída fica aberta durante o período de contestação
  * Given uma campanha ativa com metas definidas (ex: 10 likes)
  * And o influenciador já recebeu pagamentos parciais
  * When o oracle atualiza as métricas para atingir 100% das metas
  * Then todos os marcos de pagamento pendentes devem ser processados (até completar 100% do valor)
  * And o status da campanha deve mudar para "Completed"
- * And a conta da campanha deve ser automaticamente fechada
- * And o aluguel (rent) da conta deve ser devolvido para o oracle
+ * And a conta da campanha deve continuar aberta com `completed_at` registrado
+ * And `close_campaign` deve falhar com "Completed campaign is still within its oracle challenge period"
  * And o saldo do influenciador deve refletir o pagamento total da campanha
  */
 
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { Solengage } from "../target/types/solengage";
+import { bondOracle, registerOracle } from "./utils/oracle_registry";
 import {
   TOKEN_PROGRAM_ID,
   getAssociatedTokenAddress,
@@ -27,6 +28,8 @@ import {
   getOrCreateAssociatedTokenAccount,
 } from "@solana/spl-token";
 import { assert } from "chai";
+import { nextReport } from "./utils/reports";
+import { termsVersion } from "./utils/terms";
 
 describe("solengage - Auto Close Campaign on 100% Completion", () => {
   const provider = anchor.AnchorProvider.env();
@@ -38,6 +41,9 @@ describe("solengage - Auto Close Campaign on 100% Completion", () => {
   const influencer = anchor.web3.Keypair.generate();
   const brand = anchor.web3.Keypair.generate();
   const oracle = anchor.web3.Keypair.generate(); // Oracle will receive rent refund
+  let oracleEntry: anchor.web3.PublicKey;
+  let oracleBond: anchor.web3.PublicKey;
+  let bondVault: anchor.web3.PublicKey;
 
   // Campaign details
   const campaignName = "Test Campaign Auto Close";
@@ -84,6 +90,11 @@ describe("solengage - Auto Close Campaign on 100% Completion", () => {
     );
     console.log("✅ Airdrops solicitados para todos os participantes");
 
+    // Register the oracle in the global registry
+    console.log("\n🔮 Registrando oracle...");
+    oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+    console.log("✅ Oracle registrado:", oracleEntry.toBase58());
+
     // Create USDC Mint
     console.log("\n🪙 Criando USDC mint (6 decimals)...");
     usdcMint = await createMint(
@@ -94,6 +105,7 @@ describe("solengage - Auto Close Campaign on 100% Completion", () => {
       6 // Decimals
     );
     console.log("✅ USDC mint criado:", usdcMint.toBase58());
+    ({ oracleBond, bondVault } = await bondOracle(program, provider, oracle, oracleEntry, usdcMint, provider.wallet.payer));
 
     // Get Associated Token Addresses
     console.log("\n🔍 Calculando Associated Token Addresses...");
@@ -166,17 +178,13 @@ describe("solengage - Auto Close Campaign on 100% Completion", () => {
     console.log("\n✅ Setup completo!\n");
   });
 
-  it("should create, activate, complete, and auto-close a campaign, refunding rent to oracle", async () => {
+  it("should create, activate and complete a campaign, keeping it open for oracle challenges", async () => {
     console.log("\n========================================");
-    console.log("🧪 TESTE: Fechamento Automático ao Atingir 100%");
+    console.log("🧪 TESTE: Conclusão ao Atingir 100%");
     console.log("========================================\n");
 
     // --- 1. Create Campaign ---
     console.log("📋 ETAPA 1: Criando campanha...");
-    const initialOracleSolBalance = await provider.connection.getBalance(
-      oracle.publicKey
-    );
-    console.log("   - Oracle SOL balance inicial:", initialOracleSolBalance / anchor.web3.LAMPORTS_PER_SOL, "SOL");
 
     await program.methods
       .createCampaign(
@@ -195,7 +203,7 @@ describe("solengage - Auto Close Campaign on 100% Completion", () => {
         campaign: campaignPda,
         influencer: influencer.publicKey,
         brand: brand.publicKey,
-        oracle: oracle.publicKey,
+        oracle: oracleEntry,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
       .signers([influencer])
@@ -212,12 +220,14 @@ describe("solengage - Auto Close Campaign on 100% Completion", () => {
     ).amount;
 
     await program.methods
-      .brandPayCampaign()
+      .brandPayCampaign(await termsVersion(program, campaignPda))
       .accountsStrict({
         campaign: campaignPda,
         brand: brand.publicKey,
         brandUsdcAccount: brandUsdcAccount,
         campaignUsdcAccount: campaignUsdcAccount,
+        oracleBond,
+        bondVault,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .signers([brand])
@@ -245,24 +255,12 @@ describe("solengage - Auto Close Campaign on 100% Completion", () => {
       "USDC"
     );
 
-    // --- 3. Oracle Updates Metrics to 100% and Triggers Closure ---
+    // --- 3. Oracle Updates Metrics to 100% and Completes the Campaign ---
     console.log("\n📊 ETAPA 3: Oracle atualizando métricas para 100%...");
     const initialInfluencerUsdcBalance = (
       await getAccount(provider.connection, influencerUsdcAccount)
     ).amount;
 
-    // Get rent amount of the campaign account before it's closed
-    const campaignAccountInfo = await provider.connection.getAccountInfo(
-      campaignPda
-    );
-    const rentExemptAmount = campaignAccountInfo
-      ? campaignAccountInfo.lamports
-      : 0;
-    console.log(
-      "   - Rent da campaign account:",
-      rentExemptAmount / anchor.web3.LAMPORTS_PER_SOL,
-      "SOL"
-    );
     console.log("   - Atualizando para target likes:", targetLikes.toString(), "(100%)");
 
     await program.methods
@@ -270,11 +268,13 @@ describe("solengage - Auto Close Campaign on 100% Completion", () => {
         targetLikes, // Set current likes to target likes for 100%
         targetComments,
         targetViews,
-        targetShares
+        targetShares,
+        ...nextReport()
       )
       .accountsStrict({
         campaign: campaignPda,
-        oracle: oracle.publicKey,
+        oracle: oracleEntry,
+        oracleAuthority: oracle.publicKey,
         campaignUsdcAccount: campaignUsdcAccount,
         influencerUsdcAccount: influencerUsdcAccount,
         tokenProgram: TOKEN_PROGRAM_ID,
@@ -285,40 +285,29 @@ describe("solengage - Auto Close Campaign on 100% Completion", () => {
 
     console.log("✅ Métricas atualizadas para 100%!");
 
-    // --- Assertions for Closure and Refunds ---
-    console.log("\n🔍 VERIFICAÇÕES: Validando fechamento automático...\n");
+    // --- Assertions for Completion ---
+    console.log("\n🔍 VERIFICAÇÕES: Validando conclusão...\n");
 
-    // 1. Campaign account should no longer exist
-    console.log("   ✓ Verificando fechamento da campaign account...");
+    // 1. Campaign account stays open as Completed for the challenge period
+    console.log("   ✓ Verificando campaign account...");
+    campaignAccount = await program.account.campaign.fetch(campaignPda);
+    assert.deepEqual(campaignAccount.status, { completed: {} });
+    assert.isAbove(campaignAccount.completedAt.toNumber(), 0);
+    console.log("     - Campaign account aberta como Completed ✓");
+
+    // 2. It cannot be closed before the challenge period ends
+    console.log("\n   ✓ Verificando período de contestação...");
     try {
-      await program.account.campaign.fetch(campaignPda);
-      assert.fail("Campaign account should have been closed.");
+      await program.methods
+        .closeCampaign()
+        .accountsStrict({ campaign: campaignPda, oracle: oracleEntry, oracleAuthority: oracle.publicKey })
+        .rpc();
+      assert.fail("Campaign account should stay open during the challenge period.");
     } catch (e) {
-      assert.include(
-        e.message,
-        "Account does not exist",
-        "Campaign account was not closed correctly."
-      );
-      console.log("     - Campaign account fechada com sucesso ✓");
+      assert.include(e.toString(), "Completed campaign is still within its oracle challenge period");
+      console.log("     - Fechamento bloqueado durante o período de contestação ✓");
     }
 
-    // 2. Oracle's SOL balance should have increased by the rent amount
-    console.log("\n   ✓ Verificando refund de rent para oracle...");
-    const finalOracleSolBalance = await provider.connection.getBalance(
-      oracle.publicKey
-    );
-    // Allow for transaction fees, so check if it's *approximately* increased by rent
-    const expectedMinOracleSolIncrease = rentExemptAmount - 2 * 5000; // Subtracting estimated tx fees for 2 transactions (update + close CPI)
-    assert.isAtLeast(
-      finalOracleSolBalance,
-      initialOracleSolBalance + expectedMinOracleSolIncrease,
-      "Oracle did not receive the rent refund."
-    );
-    console.log("     - Oracle SOL balance inicial:", initialOracleSolBalance / anchor.web3.LAMPORTS_PER_SOL, "SOL");
-    console.log("     - Oracle SOL balance final:", finalOracleSolBalance / anchor.web3.LAMPORTS_PER_SOL, "SOL");
-    console.log("     - Aumento mínimo esperado:", expectedMinOracleSolIncrease / anchor.web3.LAMPORTS_PER_SOL, "SOL");
-    console.log("     - Oracle recebeu rent refund ✓");
-
     // 3. Influencer's USDC balance should have increased by the full amount
     console.log("\n   ✓ Verificando pagamento completo ao influencer...");
     const finalInfluencerUsdcBalance = (
@@ -352,6 +341,6 @@ describe("solengage - Auto Close Campaign on 100% Completion", () => {
       console.log("     - Campaign USDC vault foi fechado (esperado para ATAs vazios) ✓");
     }
 
-    console.log("\n✅ Teste de Fechamento Automático concluído com sucesso!\n");
+    console.log("\n✅ Teste de Conclusão concluído com sucesso!\n");
   });
 });
@@ -0,0 +1,188 @@
+/**
+ * Testes Solengage - Especificação BDD
+ *
+ * Feature: Curvas de Pagamento Não Lineares
+ *
+ * Cenário: Propriedades da curva para curvas aleatórias e sequências de métricas aleatórias
+ * Given uma campanha em Draft com uma curva (Linear, Convex, Concave ou Piecewise aleatória)
+ * And a marca financia a campanha
+ * When o oracle envia uma sequência crescente e aleatória de métricas
+ * Then o `paid_amount` nunca diminui (monotonicidade)
+ * And o `paid_amount` nunca excede `amount_usdc` (orçamento)
+ * And o valor pago em cada marco é exatamente `amount_usdc * curve(marco) / 10_000`
+ * And ao atingir 100% o influenciador recebeu exatamente o orçamento total
+ *
+ * Cenário: Curva Piecewise inválida é rejeitada
+ * Given uma campanha em Draft
+ * When o influenciador define uma curva que decresce ou não termina em (10_000, 10_000)
+ * Then a transação deve falhar com "Invalid payout curve"
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Solengage } from "../target/types/solengage";
+import { bondOracle, registerOracle } from "./utils/oracle_registry";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { getAccount, getOrCreateAssociatedTokenAccount, createMint, TOKEN_PROGRAM_ID, mintTo } from "@solana/spl-token";
+import { expect } from "chai";
+import { nextReport } from "./utils/reports";
+import { termsVersion } from "./utils/terms";
+
+const BPS = 10_000;
+
+// Gerador determinístico (LCG) para que falhas sejam reproduzíveis
+function makeRng(seed: number) {
+  let state = seed >>> 0;
+  return (max: number) => {
+    state = (Math.imul(state, 1664525) + 1013904223) >>> 0;
+    return state % max;
+  };
+}
+
+// Espelho off-chain de `PayoutCurve::payout_bps`
+function payoutBps(curve: any, x: number): number {
+  x = Math.min(x, BPS);
+  if (curve.linear) return x;
+  if (curve.convex) return Math.floor((x * x) / BPS);
+  if (curve.concave) return Math.floor((x * (2 * BPS - x)) / BPS);
+  let start = { progressBps: 0, payoutBps: 0 };
+  for (const end of curve.piecewise.points) {
+    if (x <= end.progressBps) {
+      return start.payoutBps + Math.floor(((end.payoutBps - start.payoutBps) * (x - start.progressBps)) / (end.progressBps - start.progressBps));
+    }
+    start = end;
+  }
+  return start.payoutBps;
+}
+
+function randomPiecewise(rng: (max: number) => number) {
+  const count = 1 + rng(7);
+  const xs = new Set<number>();
+  while (xs.size < count) xs.add(1 + rng(BPS - 1));
+  const ys = Array.from({ length: count }, () => rng(BPS + 1)).sort((a, b) => a - b);
+  const points = Array.from(xs).sort((a, b) => a - b).map((x, i) => ({ progressBps: x, payoutBps: ys[i] }));
+  points.push({ progressBps: BPS, payoutBps: BPS });
+  return { piecewise: { points } };
+}
+
+describe("Solengage - 07 Payout Curves", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.solengage as Program<Solengage>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+
+  const totalAmount = new anchor.BN(1000 * 1_000_000); // 1000 USDC
+  const targetLikes = 1000;
+
+  async function setupDraftCampaign(campaignName: string) {
+    const influencer = Keypair.generate();
+    const brand = Keypair.generate();
+    const oracle = Keypair.generate();
+
+    await Promise.all([
+      provider.connection.requestAirdrop(influencer.publicKey, 2 * LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig, "confirmed")),
+      provider.connection.requestAirdrop(brand.publicKey, 2 * LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig, "confirmed")),
+      provider.connection.requestAirdrop(oracle.publicKey, 2 * LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig, "confirmed")),
+    ]);
+
+    const oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+    const usdcMint = await createMint(provider.connection, brand, brand.publicKey, null, 6);
+    await bondOracle(program, provider, oracle, oracleEntry, usdcMint, brand);
+    const brandUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, brand.publicKey).then(acc => acc.address);
+    const influencerUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, influencer, usdcMint, influencer.publicKey).then(acc => acc.address);
+
+    const [campaignPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign"), influencer.publicKey.toBuffer(), brand.publicKey.toBuffer(), Buffer.from(campaignName)],
+      program.programId
+    );
+    const campaignUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, campaignPda, true).then(acc => acc.address);
+    await mintTo(provider.connection, brand, usdcMint, brandUsdcAccount, brand, totalAmount.toNumber());
+
+    await program.methods
+      .createCampaign(campaignName, "test-nickname", "Brand", "#curves", new anchor.BN(targetLikes), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), totalAmount, new anchor.BN(Date.now() / 1000 + 86400))
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey, oracle: oracleEntry, systemProgram: SystemProgram.programId })
+      .signers([influencer])
+      .rpc();
+
+    return { influencer, brand, oracle, oracleEntry, brandUsdcAccount, influencerUsdcAccount, campaignUsdcAccount, campaignPda };
+  }
+
+  const rng = makeRng(26);
+  const curves: any[] = [{ linear: {} }, { convex: {} }, { concave: {} }, randomPiecewise(rng), randomPiecewise(rng), randomPiecewise(rng)];
+
+  curves.forEach((curve, caseIndex) => {
+    it(`Property: curve ${JSON.stringify(curve)} is monotonic and never exceeds the budget`, async () => {
+      const ctx = await setupDraftCampaign(`Payout Curve ${caseIndex}`);
+
+      await program.methods
+        .setPayoutCurve(curve)
+        .accounts({ campaign: ctx.campaignPda, influencer: ctx.influencer.publicKey })
+        .signers([ctx.influencer])
+        .rpc();
+
+      await program.methods
+        .brandPayCampaign(await termsVersion(program, ctx.campaignPda))
+        .accounts({ campaign: ctx.campaignPda, brand: ctx.brand.publicKey, brandUsdcAccount: ctx.brandUsdcAccount, campaignUsdcAccount: ctx.campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+        .signers([ctx.brand])
+        .rpc();
+      console.log("✅ Campanha ativada com curva:", JSON.stringify(curve));
+
+      // Sequência crescente aleatória de likes, terminando em 100%
+      const likesSequence: number[] = [];
+      let likes = 0;
+      while (likes < targetLikes) {
+        likes = Math.min(targetLikes, likes + 1 + rng(350));
+        likesSequence.push(likes);
+      }
+
+      let previousPaid = 0;
+      for (const currentLikes of likesSequence) {
+        await program.methods
+          .updateCampaignMetrics(new anchor.BN(currentLikes), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), ...nextReport())
+          .accounts({ campaign: ctx.campaignPda, oracle: ctx.oracleEntry, oracleAuthority: ctx.oracle.publicKey, campaignUsdcAccount: ctx.campaignUsdcAccount, influencerUsdcAccount: ctx.influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+          .signers([ctx.oracle])
+          .rpc();
+
+        const paid = Number((await getAccount(provider.connection, ctx.influencerUsdcAccount)).amount);
+        const milestonesReached = Math.floor((currentLikes * 100) / targetLikes / 10);
+        const expectedPaid = milestonesReached === 0
+          ? 0
+          : Math.floor((totalAmount.toNumber() * payoutBps(curve, milestonesReached * 1000)) / BPS);
+
+        expect(paid).to.be.at.least(previousPaid);
+        expect(paid).to.be.at.most(totalAmount.toNumber());
+        expect(paid).to.equal(expectedPaid);
+        console.log(`   ✓ ${currentLikes} likes -> ${paid / 1_000_000} USDC pagos`);
+        previousPaid = paid;
+      }
+
+      expect(previousPaid).to.equal(totalAmount.toNumber());
+    });
+  });
+
+  it("Scenario: Rejects non-monotonic or incomplete piecewise curves", async () => {
+    const ctx = await setupDraftCampaign("Invalid Payout Curve");
+
+    const invalidCurves = [
+      { piecewise: { points: [] } },
+      { piecewise: { points: [{ progressBps: 5000, payoutBps: 6000 }, { progressBps: 8000, payoutBps: 4000 }, { progressBps: BPS, payoutBps: BPS }] } },
+      { piecewise: { points: [{ progressBps: 5000, payoutBps: 2000 }, { progressBps: 5000, payoutBps: 3000 }, { progressBps: BPS, payoutBps: BPS }] } },
+      { piecewise: { points: [{ progressBps: 5000, payoutBps: 2000 }, { progressBps: BPS, payoutBps: 9000 }] } },
+    ];
+
+    for (const curve of invalidCurves) {
+      try {
+        await program.methods
+          .setPayoutCurve(curve as any)
+          .accounts({ campaign: ctx.campaignPda, influencer: ctx.influencer.publicKey })
+          .signers([ctx.influencer])
+          .rpc();
+        expect.fail("A curva inválida deveria ter sido rejeitada");
+      } catch (err) {
+        expect(err.toString()).to.include("Invalid payout curve");
+      }
+    }
+
+    const campaign = await program.account.campaign.fetch(ctx.campaignPda);
+    expect(campaign.payoutCurve).to.deep.equal({ linear: {} });
+  });
+});
@@ -0,0 +1,180 @@
+/**
+ * Testes Solengage - Especificação BDD
+ *
+ * Feature: Registro Global de Oráculos com Rotação de Chaves
+ *
+ * Cenário: Criação de campanha com oráculo não registrado ou desativado
+ * Given um oráculo que não está no registro (ou foi desativado pelo admin)
+ * When o influenciador tenta criar uma campanha referenciando esse oráculo
+ * Then a transação deve falhar
+ *
+ * Cenário: Influenciador não pode ser o próprio oráculo
+ * Given uma entrada do registro cuja chave é a do influenciador
+ * When o influenciador tenta criar uma campanha com essa entrada
+ * Then a transação deve falhar com "Oracle cannot be a party of the campaign"
+ *
+ * Cenário: Rotação de chave vale para campanhas ativas
+ * Given uma campanha ativa referenciando uma entrada do registro
+ * When o admin rotaciona a chave do oráculo
+ * Then a chave antiga não consegue mais atualizar métricas
+ * And a nova chave atualiza métricas e aciona pagamentos normalmente
+ *
+ * Cenário: Somente o admin gerencia o registro
+ * Given uma conta qualquer que não é o admin
+ * When ela tenta registrar um oráculo
+ * Then a transação deve falhar com "You are not the oracle registry admin"
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Solengage } from "../target/types/solengage";
+import { bondOracle, ensureOracleRegistry, findOracleEntryPda, registerOracle } from "./utils/oracle_registry";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { getAccount, getOrCreateAssociatedTokenAccount, createMint, TOKEN_PROGRAM_ID, mintTo } from "@solana/spl-token";
+import { expect } from "chai";
+import { nextReport } from "./utils/reports";
+import { termsVersion } from "./utils/terms";
+
+describe("Solengage - 08 Oracle Registry", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.solengage as Program<Solengage>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+
+  let influencer: Keypair, brand: Keypair, oracle: Keypair;
+  let brandUsdcAccount: PublicKey, influencerUsdcAccount: PublicKey, campaignUsdcAccount: PublicKey;
+  let usdcMint: PublicKey, campaignPda: PublicKey;
+
+  const campaignName = "Oracle Registry Test";
+  const totalAmount = new anchor.BN(1000 * 1_000_000); // 1000 USDC
+  const targetLikes = new anchor.BN(1000);
+
+  const createCampaign = (oracleEntry: PublicKey) =>
+    program.methods
+      .createCampaign(campaignName, "test-nickname", "Brand", "#registry", targetLikes, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), totalAmount, new anchor.BN(Date.now() / 1000 + 86400))
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey, oracle: oracleEntry, systemProgram: SystemProgram.programId })
+      .signers([influencer])
+      .rpc();
+
+  beforeEach(async () => {
+    influencer = Keypair.generate();
+    brand = Keypair.generate();
+    oracle = Keypair.generate();
+
+    await Promise.all([
+      provider.connection.requestAirdrop(influencer.publicKey, 2 * LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig, "confirmed")),
+      provider.connection.requestAirdrop(brand.publicKey, 2 * LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig, "confirmed")),
+      provider.connection.requestAirdrop(oracle.publicKey, 2 * LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig, "confirmed")),
+    ]);
+
+    usdcMint = await createMint(provider.connection, brand, brand.publicKey, null, 6);
+    brandUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, brand.publicKey).then(acc => acc.address);
+    influencerUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, influencer, usdcMint, influencer.publicKey).then(acc => acc.address);
+
+    [campaignPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign"), influencer.publicKey.toBuffer(), brand.publicKey.toBuffer(), Buffer.from(campaignName)],
+      program.programId
+    );
+    campaignUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, campaignPda, true).then(acc => acc.address);
+    await mintTo(provider.connection, brand, usdcMint, brandUsdcAccount, brand, totalAmount.toNumber());
+  });
+
+  it("Scenario: Rejects campaigns referencing unregistered or deactivated oracles", async () => {
+    // Conta que nunca foi registrada como OracleEntry
+    try {
+      await createCampaign(oracle.publicKey);
+      expect.fail("A campanha não deveria aceitar um oráculo não registrado");
+    } catch (err) {
+      expect(err.toString()).to.match(/AccountNotInitialized|AccountOwnedByWrongProgram/);
+    }
+
+    // Entrada registrada, porém desativada pelo admin
+    const oracleRegistry = await ensureOracleRegistry(program, provider);
+    const oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+    await program.methods
+      .setOracleActive(false)
+      .accounts({ oracleRegistry, oracleEntry, admin: provider.wallet.publicKey } as any)
+      .rpc();
+
+    try {
+      await createCampaign(oracleEntry);
+      expect.fail("A campanha não deveria aceitar um oráculo desativado");
+    } catch (err) {
+      expect(err.toString()).to.include("Oracle is not registered or has been deactivated");
+    }
+    console.log("✅ Oráculos não registrados e desativados rejeitados");
+  });
+
+  it("Scenario: Influencer cannot nominate itself as oracle", async () => {
+    const selfEntry = await registerOracle(program, provider, influencer.publicKey);
+
+    try {
+      await createCampaign(selfEntry);
+      expect.fail("O influenciador não deveria poder ser o oráculo");
+    } catch (err) {
+      expect(err.toString()).to.include("Oracle cannot be a party of the campaign");
+    }
+    console.log("✅ Conflito de interesse rejeitado");
+  });
+
+  it("Scenario: Rotating the oracle key takes effect for live campaigns", async () => {
+    const oracleRegistry = await ensureOracleRegistry(program, provider);
+    const oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+    await bondOracle(program, provider, oracle, oracleEntry, usdcMint, brand);
+    await createCampaign(oracleEntry);
+    await program.methods
+      .brandPayCampaign(await termsVersion(program, campaignPda))
+      .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([brand])
+      .rpc();
+    console.log("✅ Campanha ativa referenciando a entrada do oráculo");
+
+    // WHEN: o admin rotaciona a chave
+    const newOracle = Keypair.generate();
+    await program.methods
+      .rotateOracleKey(newOracle.publicKey)
+      .accounts({ oracleRegistry, oracleEntry, admin: provider.wallet.publicKey } as any)
+      .rpc();
+    const entry = await program.account.oracleEntry.fetch(oracleEntry);
+    expect(entry.authority.equals(newOracle.publicKey)).to.be.true;
+    console.log("✅ Chave rotacionada:", newOracle.publicKey.toBase58());
+
+    // THEN: a chave antiga é rejeitada
+    try {
+      await program.methods
+        .updateCampaignMetrics(new anchor.BN(100), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), ...nextReport())
+        .accounts({ campaign: campaignPda, oracle: oracleEntry, oracleAuthority: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+        .signers([oracle])
+        .rpc();
+      expect.fail("A chave antiga não deveria atualizar métricas");
+    } catch (err) {
+      expect(err.toString()).to.include("You are not the authorized oracle for this campaign");
+    }
+
+    // AND: a nova chave funciona na mesma campanha
+    await program.methods
+      .updateCampaignMetrics(new anchor.BN(100), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), ...nextReport())
+      .accounts({ campaign: campaignPda, oracle: oracleEntry, oracleAuthority: newOracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([newOracle])
+      .rpc();
+
+    const influencerBalance = (await getAccount(provider.connection, influencerUsdcAccount)).amount;
+    expect(influencerBalance.toString()).to.equal(totalAmount.divn(10).toString());
+    console.log("✅ Nova chave atualizou métricas e pagou o marco de 10%");
+  });
+
+  it("Scenario: Only the admin can register oracles", async () => {
+    const oracleRegistry = await ensureOracleRegistry(program, provider);
+    const { oracleCount } = await program.account.oracleRegistry.fetch(oracleRegistry);
+
+    try {
+      await program.methods
+        .registerOracle(oracle.publicKey)
+        .accounts({ oracleRegistry, oracleEntry: findOracleEntryPda(program, oracleCount), admin: brand.publicKey, systemProgram: SystemProgram.programId } as any)
+        .signers([brand])
+        .rpc();
+      expect.fail("Uma conta qualquer não deveria registrar oráculos");
+    } catch (err) {
+      expect(err.toString()).to.include("You are not the oracle registry admin");
+    }
+  });
+});
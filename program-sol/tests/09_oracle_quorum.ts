@@ -0,0 +1,170 @@
+/**
+ * Testes Solengage - Especificação BDD
+ *
+ * Feature: Quórum M-de-N de Oráculos
+ *
+ * Cenário: Métricas só são aplicadas quando 2 de 3 oráculos concordam
+ * Given uma campanha ativa com quórum de 3 oráculos, threshold 2 e tolerância de 5%
+ * When o primeiro oráculo envia 300 likes
+ * Then nada é pago (relatório pendente)
+ * When o segundo oráculo envia 310 likes (dentro da tolerância)
+ * Then a mediana (300 likes) é aplicada e os marcos de 10%, 20% e 30% são pagos
+ * And o relatório agregado usa a rodada como sequência e a mediana inferior das observações
+ * And uma nova rodada começa
+ *
+ * Cenário: Rodada descartada quando os oráculos discordam
+ * Given uma campanha ativa com quórum de 3 oráculos, threshold 2 e tolerância de 5%
+ * When os três oráculos enviam 100, 500 e 900 likes
+ * Then nenhum pagamento é feito e a rodada é descartada
+ *
+ * Cenário: Oráculo único é rejeitado em campanhas com quórum
+ * When o oráculo principal chama `update_campaign_metrics`
+ * Then a transação falha com "Campaign requires oracle quorum reports"
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Solengage } from "../target/types/solengage";
+import { bondOracle, registerOracle } from "./utils/oracle_registry";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { getAccount, getOrCreateAssociatedTokenAccount, createMint, TOKEN_PROGRAM_ID, mintTo } from "@solana/spl-token";
+import { expect } from "chai";
+import { nextReport } from "./utils/reports";
+import { baseMetric } from "./utils/metrics";
+import { termsVersion } from "./utils/terms";
+
+describe("Solengage - 09 Oracle Quorum", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.solengage as Program<Solengage>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+
+  let influencer: Keypair, brand: Keypair;
+  let oracles: Keypair[];
+  let oracleEntries: PublicKey[];
+  let influencerUsdcAccount: PublicKey, campaignUsdcAccount: PublicKey;
+  let campaignPda: PublicKey, oracleQuorumPda: PublicKey;
+
+  const campaignName = "Oracle Quorum Test";
+  const totalAmount = new anchor.BN(1000 * 1_000_000); // 1000 USDC
+  const targetLikes = new anchor.BN(1000);
+  const toleranceBps = 500; // 5%
+
+  const observedAt = (secondsAgo: number) => Math.floor(Date.now() / 1000) - secondsAgo;
+
+  const submit = (oracleIndex: number, likes: number, observed = observedAt(60)) =>
+    program.methods
+      .submitMetricReport(new anchor.BN(likes), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), new anchor.BN(observed))
+      .accounts({
+        campaign: campaignPda, oracleQuorum: oracleQuorumPda, oracle: oracleEntries[oracleIndex], oracleAuthority: oracles[oracleIndex].publicKey,
+        campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID,
+      } as any)
+      .signers([oracles[oracleIndex]])
+      .rpc();
+
+  beforeEach(async () => {
+    influencer = Keypair.generate();
+    brand = Keypair.generate();
+    oracles = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+
+    await Promise.all(
+      [influencer, brand, ...oracles].map(kp =>
+        provider.connection.requestAirdrop(kp.publicKey, 2 * LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig, "confirmed"))
+      )
+    );
+
+    console.log("🔮 Registrando 3 oráculos...");
+    oracleEntries = [];
+    for (const oracle of oracles) {
+      oracleEntries.push(await registerOracle(program, provider, oracle.publicKey));
+    }
+
+    const usdcMint = await createMint(provider.connection, brand, brand.publicKey, null, 6);
+    await bondOracle(program, provider, oracles[0], oracleEntries[0], usdcMint, brand);
+    const brandUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, brand.publicKey).then(acc => acc.address);
+    influencerUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, influencer, usdcMint, influencer.publicKey).then(acc => acc.address);
+
+    [campaignPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign"), influencer.publicKey.toBuffer(), brand.publicKey.toBuffer(), Buffer.from(campaignName)],
+      program.programId
+    );
+    [oracleQuorumPda] = PublicKey.findProgramAddressSync([Buffer.from("oracle_quorum"), campaignPda.toBuffer()], program.programId);
+    campaignUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, campaignPda, true).then(acc => acc.address);
+    await mintTo(provider.connection, brand, usdcMint, brandUsdcAccount, brand, totalAmount.toNumber());
+
+    await program.methods
+      .createCampaign(campaignName, "test-nickname", "Brand", "#quorum", targetLikes, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), totalAmount, new anchor.BN(Date.now() / 1000 + 86400))
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey, oracle: oracleEntries[0], systemProgram: SystemProgram.programId })
+      .signers([influencer])
+      .rpc();
+
+    await program.methods
+      .setOracleQuorum(2, toleranceBps)
+      .accounts({ campaign: campaignPda, oracleQuorum: oracleQuorumPda, influencer: influencer.publicKey, systemProgram: SystemProgram.programId } as any)
+      .remainingAccounts(oracleEntries.map(pubkey => ({ pubkey, isSigner: false, isWritable: false })))
+      .signers([influencer])
+      .rpc();
+    console.log("✅ Quórum 2-de-3 configurado");
+
+    await program.methods
+      .brandPayCampaign(await termsVersion(program, campaignPda))
+      .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([brand])
+      .rpc();
+    console.log("✅ Campanha ativada");
+  });
+
+  it("Scenario: Applies the median once 2 of 3 oracles agree", async () => {
+    const firstObservation = observedAt(120);
+    await submit(0, 300, firstObservation);
+
+    let campaign = await program.account.campaign.fetch(campaignPda);
+    let quorum = await program.account.oracleQuorum.fetch(oracleQuorumPda);
+    expect(baseMetric(campaign, "likes").current.toNumber()).to.equal(0);
+    expect(campaign.paidAmount.toNumber()).to.equal(0);
+    expect(quorum.submissions.length).to.equal(1);
+    console.log("   ✓ Primeiro relatório pendente, nada pago");
+
+    await submit(1, 310, observedAt(60));
+
+    campaign = await program.account.campaign.fetch(campaignPda);
+    quorum = await program.account.oracleQuorum.fetch(oracleQuorumPda);
+    expect(baseMetric(campaign, "likes").current.toNumber()).to.equal(300); // mediana inferior de [300, 310]
+    expect(campaign.paidAmount.toString()).to.equal(totalAmount.muln(3).divn(10).toString());
+    expect(campaign.paymentMilestones.slice(0, 3).every(m => m)).to.be.true;
+    expect(campaign.reportSequence.toNumber()).to.equal(1);
+    expect(campaign.lastObservedAt.toNumber()).to.equal(firstObservation);
+    expect(quorum.round.toNumber()).to.equal(1);
+    expect(quorum.submissions.length).to.equal(0);
+
+    const influencerBalance = (await getAccount(provider.connection, influencerUsdcAccount)).amount;
+    expect(influencerBalance.toString()).to.equal(totalAmount.muln(3).divn(10).toString());
+    console.log("   ✓ Quórum atingido: 30% pago, nova rodada iniciada");
+  });
+
+  it("Scenario: Discards the round when oracles disagree", async () => {
+    await submit(0, 100);
+    await submit(1, 500);
+    await submit(2, 900);
+
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    const quorum = await program.account.oracleQuorum.fetch(oracleQuorumPda);
+    expect(baseMetric(campaign, "likes").current.toNumber()).to.equal(0);
+    expect(campaign.paidAmount.toNumber()).to.equal(0);
+    expect(quorum.round.toNumber()).to.equal(1);
+    expect(quorum.submissions.length).to.equal(0);
+    console.log("   ✓ Rodada sem concordância descartada, nada pago");
+  });
+
+  it("Scenario: Rejects single-oracle updates on quorum campaigns", async () => {
+    try {
+      await program.methods
+        .updateCampaignMetrics(new anchor.BN(1000), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), ...nextReport())
+        .accounts({ campaign: campaignPda, oracle: oracleEntries[0], oracleAuthority: oracles[0].publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+        .signers([oracles[0]])
+        .rpc();
+      expect.fail("O oráculo único não deveria atualizar uma campanha com quórum");
+    } catch (err) {
+      expect(err.toString()).to.include("Campaign requires oracle quorum reports");
+    }
+  });
+});
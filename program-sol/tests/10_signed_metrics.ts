@@ -0,0 +1,158 @@
+/**
+ * Testes Solengage - Especificação BDD
+ *
+ * Feature: Relatórios de Métricas Assinados Off-chain e Enviados por Relayer
+ *
+ * Cenário: Relayer envia relatório assinado pelo oráculo
+ * Given uma campanha ativa cujo oráculo não possui SOL (chave "fria")
+ * And um relatório (campanha, métricas, horário de observação, nonce) assinado pelo oráculo
+ * When um relayer qualquer envia a instrução Ed25519 seguida de `submit_signed_metrics`
+ * Then as métricas são aplicadas e o marco de 10% é pago ao influenciador
+ *
+ * Cenário: Replay do mesmo relatório é rejeitado
+ * When o relayer reenvia o mesmo relatório (mesmo nonce)
+ * Then a transação falha com "Report nonce has already been used"
+ *
+ * Cenário: Relatório assinado por outra chave é rejeitado
+ * When o relatório é assinado por uma chave que não é a do oráculo
+ * Then a transação falha com "Report is not signed by the campaign oracle"
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Solengage } from "../target/types/solengage";
+import { bondOracle, registerOracle } from "./utils/oracle_registry";
+import {
+  Ed25519Program,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+} from "@solana/web3.js";
+import { getAccount, getOrCreateAssociatedTokenAccount, createMint, TOKEN_PROGRAM_ID, mintTo } from "@solana/spl-token";
+import { expect } from "chai";
+import { baseMetric } from "./utils/metrics";
+import { termsVersion } from "./utils/terms";
+
+const SIGNED_REPORT_DOMAIN = Buffer.from("solengage:metric-report:v1");
+
+// Espelho off-chain de `SignedMetricReport::message`
+function reportMessage(campaign: PublicKey, likes: number, observedAt: number, nonce: number): Buffer {
+  const u64 = (value: number) => new anchor.BN(value).toArrayLike(Buffer, "le", 8);
+  return Buffer.concat([SIGNED_REPORT_DOMAIN, campaign.toBuffer(), u64(likes), u64(0), u64(0), u64(0), u64(observedAt), u64(nonce)]);
+}
+
+describe("Solengage - 10 Signed Metric Reports", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.solengage as Program<Solengage>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+
+  let influencer: Keypair, brand: Keypair, oracle: Keypair, relayer: Keypair;
+  let oracleEntry: PublicKey;
+  let influencerUsdcAccount: PublicKey, campaignUsdcAccount: PublicKey;
+  let campaignPda: PublicKey;
+
+  const campaignName = "Signed Metrics Test";
+  const totalAmount = new anchor.BN(1000 * 1_000_000); // 1000 USDC
+  const targetLikes = new anchor.BN(1000);
+
+  const relay = (signer: Keypair, likes: number, nonce: number) => {
+    const observedAt = Math.floor(Date.now() / 1000) - 60;
+    const ed25519Ix = Ed25519Program.createInstructionWithPrivateKey({
+      privateKey: signer.secretKey,
+      message: reportMessage(campaignPda, likes, observedAt, nonce),
+    });
+
+    return program.methods
+      .submitSignedMetrics({
+        campaign: campaignPda,
+        values: { likes: new anchor.BN(likes), comments: new anchor.BN(0), views: new anchor.BN(0), shares: new anchor.BN(0) },
+        observedAt: new anchor.BN(observedAt),
+        nonce: new anchor.BN(nonce),
+      })
+      .accounts({
+        campaign: campaignPda, oracle: oracleEntry, oracleAuthority: oracle.publicKey, relayer: relayer.publicKey,
+        campaignUsdcAccount, influencerUsdcAccount, instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY, tokenProgram: TOKEN_PROGRAM_ID,
+      } as any)
+      .preInstructions([ed25519Ix])
+      .signers([relayer])
+      .rpc();
+  };
+
+  beforeEach(async () => {
+    influencer = Keypair.generate();
+    brand = Keypair.generate();
+    oracle = Keypair.generate(); // chave fria: nunca recebe airdrop nem assina transações
+    relayer = Keypair.generate();
+
+    await Promise.all(
+      [influencer, brand, relayer].map(kp =>
+        provider.connection.requestAirdrop(kp.publicKey, 2 * LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig, "confirmed"))
+      )
+    );
+    oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+
+    const usdcMint = await createMint(provider.connection, brand, brand.publicKey, null, 6);
+    await bondOracle(program, provider, oracle, oracleEntry, usdcMint, brand);
+    const brandUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, brand.publicKey).then(acc => acc.address);
+    influencerUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, influencer, usdcMint, influencer.publicKey).then(acc => acc.address);
+
+    [campaignPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign"), influencer.publicKey.toBuffer(), brand.publicKey.toBuffer(), Buffer.from(campaignName)],
+      program.programId
+    );
+    campaignUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, campaignPda, true).then(acc => acc.address);
+    await mintTo(provider.connection, brand, usdcMint, brandUsdcAccount, brand, totalAmount.toNumber());
+
+    await program.methods
+      .createCampaign(campaignName, "test-nickname", "Brand", "#signed", targetLikes, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), totalAmount, new anchor.BN(Date.now() / 1000 + 86400))
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey, oracle: oracleEntry, systemProgram: SystemProgram.programId })
+      .signers([influencer])
+      .rpc();
+
+    await program.methods
+      .brandPayCampaign(await termsVersion(program, campaignPda))
+      .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([brand])
+      .rpc();
+  });
+
+  it("Scenario: Any relayer can submit a report signed by the oracle", async () => {
+    await relay(oracle, 100, 1);
+
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    expect(baseMetric(campaign, "likes").current.toNumber()).to.equal(100);
+    expect(campaign.reportSequence.toNumber()).to.equal(1);
+    expect(campaign.paymentMilestones[0]).to.be.true;
+
+    const influencerBalance = (await getAccount(provider.connection, influencerUsdcAccount)).amount;
+    expect(influencerBalance.toString()).to.equal(totalAmount.divn(10).toString());
+    console.log("   ✓ Relatório assinado aplicado via relayer, 10% pago");
+  });
+
+  it("Scenario: Replaying a report nonce is rejected", async () => {
+    await relay(oracle, 100, 1);
+
+    try {
+      await relay(oracle, 200, 1);
+      expect.fail("O nonce repetido deveria ser rejeitado");
+    } catch (err) {
+      expect(err.toString()).to.include("Report nonce has already been used");
+    }
+  });
+
+  it("Scenario: Reports signed by another key are rejected", async () => {
+    const impostor = Keypair.generate();
+
+    try {
+      await relay(impostor, 1000, 1);
+      expect.fail("Um relatório assinado por outra chave deveria ser rejeitado");
+    } catch (err) {
+      expect(err.toString()).to.include("Report is not signed by the campaign oracle");
+    }
+
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    expect(baseMetric(campaign, "likes").current.toNumber()).to.equal(0);
+  });
+});
@@ -0,0 +1,183 @@
+/**
+ * Testes Solengage - Especificação BDD
+ *
+ * Feature: Métricas Monotônicas e Circuit Breaker de Anomalias
+ *
+ * Cenário: Métricas que diminuem são rejeitadas
+ * Given uma campanha ativa com 200 likes reportados
+ * When o oráculo envia 150 likes
+ * Then a transação falha com "Metrics cannot decrease"
+ *
+ * Cenário: Salto anormal é retido e aprovado pela marca
+ * Given uma campanha com limite de 30 pontos percentuais por atualização
+ * When o oráculo envia um salto de 0% para 60%
+ * Then nada é pago e o relatório fica retido
+ * And novos relatórios são rejeitados com "A report is held pending brand or admin review"
+ * When a marca aprova o relatório retido
+ * Then as métricas são aplicadas e 60% do orçamento é pago
+ *
+ * Cenário: Admin do registro descarta o salto anormal
+ * Given um relatório retido pelo circuit breaker
+ * When o admin do registro descarta o relatório
+ * Then as métricas anteriores são mantidas e o oráculo volta a reportar normalmente
+ *
+ * Cenário: Terceiros não podem revisar relatórios retidos
+ * When o influenciador tenta aprovar o relatório retido
+ * Then a transação falha com "Only the brand or the registry admin can resolve held reports"
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Solengage } from "../target/types/solengage";
+import { bondOracle, ensureOracleRegistry, registerOracle } from "./utils/oracle_registry";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { getAccount, getOrCreateAssociatedTokenAccount, createMint, TOKEN_PROGRAM_ID, mintTo } from "@solana/spl-token";
+import { expect } from "chai";
+import { nextReport } from "./utils/reports";
+import { baseMetric } from "./utils/metrics";
+import { termsVersion } from "./utils/terms";
+
+describe("Solengage - 11 Metric Guards", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.solengage as Program<Solengage>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+
+  let influencer: Keypair, brand: Keypair, oracle: Keypair;
+  let oracleRegistry: PublicKey, oracleEntry: PublicKey;
+  let influencerUsdcAccount: PublicKey, campaignUsdcAccount: PublicKey;
+  let campaignPda: PublicKey;
+
+  const campaignName = "Metric Guards Test";
+  const totalAmount = new anchor.BN(1000 * 1_000_000); // 1000 USDC
+  const targetLikes = new anchor.BN(1000);
+
+  const update = (likes: number) =>
+    program.methods
+      .updateCampaignMetrics(new anchor.BN(likes), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), ...nextReport())
+      .accounts({ campaign: campaignPda, oracle: oracleEntry, oracleAuthority: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([oracle])
+      .rpc();
+
+  const resolve = (reviewer: Keypair | null, approve: boolean) => {
+    const builder = program.methods
+      .resolveHeldReport(approve)
+      .accounts({
+        campaign: campaignPda, oracleRegistry, oracle: oracleEntry, oracleAuthority: oracle.publicKey,
+        reviewer: reviewer ? reviewer.publicKey : provider.wallet.publicKey,
+        campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID,
+      } as any);
+    return reviewer ? builder.signers([reviewer]).rpc() : builder.rpc();
+  };
+
+  beforeEach(async () => {
+    influencer = Keypair.generate();
+    brand = Keypair.generate();
+    oracle = Keypair.generate();
+
+    await Promise.all(
+      [influencer, brand, oracle].map(kp =>
+        provider.connection.requestAirdrop(kp.publicKey, 2 * LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig, "confirmed"))
+      )
+    );
+    oracleRegistry = await ensureOracleRegistry(program, provider);
+    oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+
+    const usdcMint = await createMint(provider.connection, brand, brand.publicKey, null, 6);
+    await bondOracle(program, provider, oracle, oracleEntry, usdcMint, brand);
+    const brandUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, brand.publicKey).then(acc => acc.address);
+    influencerUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, influencer, usdcMint, influencer.publicKey).then(acc => acc.address);
+
+    [campaignPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign"), influencer.publicKey.toBuffer(), brand.publicKey.toBuffer(), Buffer.from(campaignName)],
+      program.programId
+    );
+    campaignUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, campaignPda, true).then(acc => acc.address);
+    await mintTo(provider.connection, brand, usdcMint, brandUsdcAccount, brand, totalAmount.toNumber());
+
+    await program.methods
+      .createCampaign(campaignName, "test-nickname", "Brand", "#guards", targetLikes, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), totalAmount, new anchor.BN(Date.now() / 1000 + 86400))
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey, oracle: oracleEntry, systemProgram: SystemProgram.programId })
+      .signers([influencer])
+      .rpc();
+
+    await program.methods
+      .setMetricGuards({ enforceMonotonic: true, maxProgressPerUpdate: 30, maxProgressPerHour: 0 })
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey })
+      .signers([influencer])
+      .rpc();
+
+    await program.methods
+      .brandPayCampaign(await termsVersion(program, campaignPda))
+      .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([brand])
+      .rpc();
+    console.log("✅ Campanha ativada com limite de 30 p.p. por atualização");
+  });
+
+  it("Scenario: Rejects reports that decrease metrics", async () => {
+    await update(200);
+
+    try {
+      await update(150);
+      expect.fail("Métricas menores deveriam ser rejeitadas");
+    } catch (err) {
+      expect(err.toString()).to.include("Metrics cannot decrease");
+    }
+
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    expect(baseMetric(campaign, "likes").current.toNumber()).to.equal(200);
+  });
+
+  it("Scenario: Holds an abnormal jump until the brand approves it", async () => {
+    await update(600);
+
+    let campaign = await program.account.campaign.fetch(campaignPda);
+    expect(baseMetric(campaign, "likes").current.toNumber()).to.equal(0);
+    expect(campaign.paidAmount.toNumber()).to.equal(0);
+    expect(campaign.reportHeld).to.be.true;
+    expect(baseMetric(campaign, "likes").pending.toNumber()).to.equal(600);
+    console.log("   ✓ Salto de 60 p.p. retido, nada pago");
+
+    try {
+      await update(650);
+      expect.fail("Novos relatórios deveriam ser rejeitados enquanto há um relatório retido");
+    } catch (err) {
+      expect(err.toString()).to.include("A report is held pending brand or admin review");
+    }
+
+    await resolve(brand, true);
+
+    campaign = await program.account.campaign.fetch(campaignPda);
+    expect(baseMetric(campaign, "likes").current.toNumber()).to.equal(600);
+    expect(campaign.reportHeld).to.be.false;
+    const influencerBalance = (await getAccount(provider.connection, influencerUsdcAccount)).amount;
+    expect(influencerBalance.toString()).to.equal(totalAmount.muln(6).divn(10).toString());
+    console.log("   ✓ Marca aprovou: 60% pago");
+  });
+
+  it("Scenario: Registry admin discards an abnormal jump", async () => {
+    await update(200);
+    await update(900);
+    await resolve(null, false);
+
+    let campaign = await program.account.campaign.fetch(campaignPda);
+    expect(baseMetric(campaign, "likes").current.toNumber()).to.equal(200);
+    expect(campaign.reportHeld).to.be.false;
+
+    await update(300);
+    campaign = await program.account.campaign.fetch(campaignPda);
+    expect(baseMetric(campaign, "likes").current.toNumber()).to.equal(300);
+    console.log("   ✓ Admin descartou o salto, oráculo voltou a reportar");
+  });
+
+  it("Scenario: Only the brand or the registry admin can review", async () => {
+    await update(600);
+
+    try {
+      await resolve(influencer, true);
+      expect.fail("O influenciador não deveria aprovar relatórios retidos");
+    } catch (err) {
+      expect(err.toString()).to.include("Only the brand or the registry admin can resolve held reports");
+    }
+  });
+});
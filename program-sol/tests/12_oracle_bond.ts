@@ -0,0 +1,286 @@
+/**
+ * Testes Solengage - Especificação BDD
+ *
+ * Feature: Caução de Oráculos com Contestação e Slashing
+ *
+ * Cenário: Marca é compensada pela caução após contestação sustentada pelo árbitro
+ * Given um oráculo com 500 tokens travados em sua caução
+ * And uma campanha ativa em que o oráculo reportou 600 likes
+ * When a marca envia a evidência (600 reportados, 200 observados, slash de 300) assinada pelo árbitro
+ * Then 300 tokens saem da caução para a marca
+ * And a contestação fica registrada em `OracleChallenge`
+ *
+ * Cenário: Evidência não assinada pelo árbitro é rejeitada
+ * When a marca envia evidência assinada por outra chave
+ * Then a transação falha com "Evidence is not signed by the arbiter"
+ *
+ * Cenário: Oráculo ativo não pode sacar a caução
+ * When o oráculo tenta sacar sua caução enquanto ainda está ativo
+ * Then a transação falha com "Oracle must be deactivated before withdrawing its bond"
+ *
+ * Cenário: Campanha só é financiada com o oráculo caucionado no mint da campanha
+ * Given um oráculo caucionado em outro mint e outro com menos que `MIN_ORACLE_BOND`
+ * When a marca tenta financiar campanhas que os referenciam
+ * Then as transações falham com "Oracle bond is not denominated in the campaign's USDC mint"
+ * And "Oracle bond is below the minimum required to fund a campaign"
+ *
+ * Cenário: Oráculo desativado continua sujeito a slashing durante o unbonding
+ * Given o admin desativou o oráculo
+ * When o oráculo tenta sacar sua caução logo em seguida
+ * Then a transação falha com "Oracle bond is still unbonding after deactivation"
+ * And uma contestação sustentada ainda executa o slashing
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Solengage } from "../target/types/solengage";
+import { bondOracle, ensureOracleRegistry, MIN_ORACLE_BOND, registerOracle } from "./utils/oracle_registry";
+import { Ed25519Program, Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram, SYSVAR_INSTRUCTIONS_PUBKEY } from "@solana/web3.js";
+import { getAccount, getOrCreateAssociatedTokenAccount, createMint, TOKEN_PROGRAM_ID, mintTo } from "@solana/spl-token";
+import { expect } from "chai";
+import { nextReport } from "./utils/reports";
+import { termsVersion } from "./utils/terms";
+
+const CHALLENGE_EVIDENCE_DOMAIN = Buffer.from("solengage:oracle-challenge:v1");
+
+const likesOnly = (likes: number) => ({ likes: new anchor.BN(likes), comments: new anchor.BN(0), views: new anchor.BN(0), shares: new anchor.BN(0) });
+
+// Espelho off-chain de `ChallengeEvidence::message`
+function evidenceMessage(evidence: any): Buffer {
+  const u64 = (value: anchor.BN) => value.toArrayLike(Buffer, "le", 8);
+  const values = (v: any) => Buffer.concat([u64(v.likes), u64(v.comments), u64(v.views), u64(v.shares)]);
+  return Buffer.concat([
+    CHALLENGE_EVIDENCE_DOMAIN, evidence.campaign.toBuffer(), evidence.oracle.toBuffer(), evidence.challenger.toBuffer(),
+    values(evidence.reported), values(evidence.observed), u64(evidence.slashAmount), u64(evidence.nonce),
+  ]);
+}
+
+describe("Solengage - 12 Oracle Bond", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.solengage as Program<Solengage>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+
+  let influencer: Keypair, brand: Keypair, oracle: Keypair, arbiter: Keypair;
+  let oracleRegistry: PublicKey, oracleEntry: PublicKey, oracleBond: PublicKey, bondVault: PublicKey;
+  let oracleBondTokenAccount: PublicKey, brandBondTokenAccount: PublicKey;
+  let usdcMint: PublicKey, brandUsdcAccount: PublicKey, campaignPda: PublicKey;
+
+  const campaignName = "Oracle Bond Test";
+  const totalAmount = new anchor.BN(1000 * 1_000_000); // 1000 USDC
+  const bondAmount = 500 * 1_000_000;
+
+  const challenge = (signer: Keypair, slashAmount: number, nonce: number) => {
+    const evidence = {
+      campaign: campaignPda, oracle: oracleEntry, challenger: brand.publicKey,
+      reported: likesOnly(600), observed: likesOnly(200),
+      slashAmount: new anchor.BN(slashAmount), nonce: new anchor.BN(nonce),
+    };
+    const [oracleChallenge] = PublicKey.findProgramAddressSync(
+      [Buffer.from("oracle_challenge"), campaignPda.toBuffer(), evidence.nonce.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const ed25519Ix = Ed25519Program.createInstructionWithPrivateKey({ privateKey: signer.secretKey, message: evidenceMessage(evidence) });
+
+    return {
+      oracleChallenge,
+      rpc: () => program.methods
+        .challengeOracle(evidence)
+        .accounts({
+          campaign: campaignPda, oracleRegistry, oracleBond, bondVault, oracleChallenge,
+          challengerTokenAccount: brandBondTokenAccount, challenger: brand.publicKey,
+          instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY, tokenProgram: TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
+        } as any)
+        .preInstructions([ed25519Ix])
+        .signers([brand])
+        .rpc(),
+    };
+  };
+
+  beforeEach(async () => {
+    influencer = Keypair.generate();
+    brand = Keypair.generate();
+    oracle = Keypair.generate();
+    arbiter = Keypair.generate();
+
+    await Promise.all(
+      [influencer, brand, oracle].map(kp =>
+        provider.connection.requestAirdrop(kp.publicKey, 2 * LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig, "confirmed"))
+      )
+    );
+    oracleRegistry = await ensureOracleRegistry(program, provider);
+    oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+    await program.methods
+      .setArbiter(arbiter.publicKey)
+      .accounts({ oracleRegistry, admin: provider.wallet.publicKey } as any)
+      .rpc();
+
+    // Caução do oráculo, no mesmo mint da campanha
+    usdcMint = await createMint(provider.connection, brand, brand.publicKey, null, 6);
+    oracleBondTokenAccount = await getOrCreateAssociatedTokenAccount(provider.connection, oracle, usdcMint, oracle.publicKey).then(acc => acc.address);
+    brandUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, brand.publicKey).then(acc => acc.address);
+    brandBondTokenAccount = brandUsdcAccount;
+    await mintTo(provider.connection, brand, usdcMint, oracleBondTokenAccount, brand, bondAmount);
+
+    [oracleBond] = PublicKey.findProgramAddressSync([Buffer.from("oracle_bond"), oracleEntry.toBuffer()], program.programId);
+    [bondVault] = PublicKey.findProgramAddressSync([Buffer.from("oracle_bond_vault"), oracleEntry.toBuffer()], program.programId);
+
+    await program.methods
+      .initializeOracleBond()
+      .accounts({ oracle: oracleEntry, oracleBond, bondVault, mint: usdcMint, oracleAuthority: oracle.publicKey, tokenProgram: TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId } as any)
+      .signers([oracle])
+      .rpc();
+    await program.methods
+      .depositOracleBond(new anchor.BN(bondAmount))
+      .accounts({ oracle: oracleEntry, oracleBond, bondVault, authorityTokenAccount: oracleBondTokenAccount, oracleAuthority: oracle.publicKey, tokenProgram: TOKEN_PROGRAM_ID } as any)
+      .signers([oracle])
+      .rpc();
+    console.log("✅ Caução de 500 tokens travada");
+
+    // Campanha ativa com relatório do oráculo
+    const influencerUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, influencer, usdcMint, influencer.publicKey).then(acc => acc.address);
+
+    [campaignPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign"), influencer.publicKey.toBuffer(), brand.publicKey.toBuffer(), Buffer.from(campaignName)],
+      program.programId
+    );
+    const campaignUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, campaignPda, true).then(acc => acc.address);
+    await mintTo(provider.connection, brand, usdcMint, brandUsdcAccount, brand, totalAmount.toNumber());
+
+    await program.methods
+      .createCampaign(campaignName, "test-nickname", "Brand", "#bond", new anchor.BN(1000), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), totalAmount, new anchor.BN(Date.now() / 1000 + 86400))
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey, oracle: oracleEntry, systemProgram: SystemProgram.programId })
+      .signers([influencer])
+      .rpc();
+    await program.methods
+      .brandPayCampaign(await termsVersion(program, campaignPda))
+      .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([brand])
+      .rpc();
+    await program.methods
+      .updateCampaignMetrics(new anchor.BN(600), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), ...nextReport())
+      .accounts({ campaign: campaignPda, oracle: oracleEntry, oracleAuthority: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([oracle])
+      .rpc();
+    console.log("✅ Oráculo reportou 600 likes");
+  });
+
+  it("Scenario: Upheld challenge compensates the brand from the bond", async () => {
+    const slashAmount = 300 * 1_000_000;
+    const { oracleChallenge, rpc } = challenge(arbiter, slashAmount, 1);
+    await rpc();
+
+    const brandBalance = (await getAccount(provider.connection, brandBondTokenAccount)).amount;
+    const vaultBalance = (await getAccount(provider.connection, bondVault)).amount;
+    expect(brandBalance.toString()).to.equal(slashAmount.toString());
+    expect(vaultBalance.toString()).to.equal((bondAmount - slashAmount).toString());
+
+    const bond = await program.account.oracleBond.fetch(oracleBond);
+    expect(bond.slashedAmount.toNumber()).to.equal(slashAmount);
+    expect(bond.slashCount.toNumber()).to.equal(1);
+
+    const record = await program.account.oracleChallenge.fetch(oracleChallenge);
+    expect(record.challenger.equals(brand.publicKey)).to.be.true;
+    expect(record.observed.likes.toNumber()).to.equal(200);
+    console.log("   ✓ Marca compensada com 300 tokens da caução");
+
+    // O mesmo nonce não pode ser reutilizado
+    try {
+      await challenge(arbiter, slashAmount, 1).rpc();
+      expect.fail("A mesma evidência não deveria ser aceita duas vezes");
+    } catch (err) {
+      expect(err.toString()).to.match(/already in use|custom program error: 0x0/);
+    }
+  });
+
+  it("Scenario: Rejects evidence not signed by the arbiter", async () => {
+    try {
+      await challenge(Keypair.generate(), 300 * 1_000_000, 1).rpc();
+      expect.fail("Evidência de outra chave deveria ser rejeitada");
+    } catch (err) {
+      expect(err.toString()).to.include("Evidence is not signed by the arbiter");
+    }
+
+    const vaultBalance = (await getAccount(provider.connection, bondVault)).amount;
+    expect(vaultBalance.toString()).to.equal(bondAmount.toString());
+  });
+
+  it("Scenario: Active oracles cannot withdraw their bond", async () => {
+    try {
+      await program.methods
+        .withdrawOracleBond(new anchor.BN(bondAmount))
+        .accounts({ oracle: oracleEntry, oracleBond, bondVault, authorityTokenAccount: oracleBondTokenAccount, oracleAuthority: oracle.publicKey, tokenProgram: TOKEN_PROGRAM_ID } as any)
+        .signers([oracle])
+        .rpc();
+      expect.fail("Um oráculo ativo não deveria sacar a caução");
+    } catch (err) {
+      expect(err.toString()).to.include("Oracle must be deactivated before withdrawing its bond");
+    }
+  });
+
+  it("Scenario: Campaigns are only funded while the oracle is bonded in the campaign mint", async () => {
+    const fundCampaign = async (name: string, bondMint: PublicKey, mintAuthority: Keypair, amount: number) => {
+      const otherOracle = Keypair.generate();
+      const otherEntry = await registerOracle(program, provider, otherOracle.publicKey);
+      await bondOracle(program, provider, otherOracle, otherEntry, bondMint, mintAuthority, amount);
+
+      const [campaign] = PublicKey.findProgramAddressSync(
+        [Buffer.from("campaign"), influencer.publicKey.toBuffer(), brand.publicKey.toBuffer(), Buffer.from(name)],
+        program.programId
+      );
+      const campaignUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, campaign, true).then(acc => acc.address);
+      await mintTo(provider.connection, brand, usdcMint, brandUsdcAccount, brand, totalAmount.toNumber());
+      await program.methods
+        .createCampaign(name, "test-nickname", "Brand", "#bond", new anchor.BN(1000), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), totalAmount, new anchor.BN(Date.now() / 1000 + 86400))
+        .accounts({ campaign, influencer: influencer.publicKey, brand: brand.publicKey, oracle: otherEntry, systemProgram: SystemProgram.programId })
+        .signers([influencer])
+        .rpc();
+      await program.methods
+        .brandPayCampaign(await termsVersion(program, campaign))
+        .accounts({ campaign, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+        .signers([brand])
+        .rpc();
+    };
+
+    // Caução suficiente, mas em outro mint
+    const otherMint = await createMint(provider.connection, oracle, oracle.publicKey, null, 6);
+    try {
+      await fundCampaign("Bond Other Mint", otherMint, oracle, MIN_ORACLE_BOND);
+      expect.fail("A campanha não deveria ser financiada com caução em outro mint");
+    } catch (err) {
+      expect(err.toString()).to.include("Oracle bond is not denominated in the campaign's USDC mint");
+    }
+
+    // Caução no mint da campanha, mas abaixo do mínimo
+    try {
+      await fundCampaign("Bond Too Low", usdcMint, brand, MIN_ORACLE_BOND - 1);
+      expect.fail("A campanha não deveria ser financiada com caução abaixo do mínimo");
+    } catch (err) {
+      expect(err.toString()).to.include("Oracle bond is below the minimum required to fund a campaign");
+    }
+    console.log("   ✓ Caução em outro mint ou abaixo do mínimo rejeitada");
+  });
+
+  it("Scenario: Deactivated oracles can still be slashed while unbonding", async () => {
+    await program.methods
+      .setOracleActive(false)
+      .accounts({ oracleRegistry, oracleEntry, admin: provider.wallet.publicKey } as any)
+      .rpc();
+
+    try {
+      await program.methods
+        .withdrawOracleBond(new anchor.BN(bondAmount))
+        .accounts({ oracle: oracleEntry, oracleBond, bondVault, authorityTokenAccount: oracleBondTokenAccount, oracleAuthority: oracle.publicKey, tokenProgram: TOKEN_PROGRAM_ID } as any)
+        .signers([oracle])
+        .rpc();
+      expect.fail("O oráculo não deveria sacar a caução durante o unbonding");
+    } catch (err) {
+      expect(err.toString()).to.include("Oracle bond is still unbonding after deactivation");
+    }
+
+    const slashAmount = 300 * 1_000_000;
+    await challenge(arbiter, slashAmount, 1).rpc();
+    const vaultBalance = (await getAccount(provider.connection, bondVault)).amount;
+    expect(vaultBalance.toString()).to.equal((bondAmount - slashAmount).toString());
+    console.log("   ✓ Caução travada no unbonding e slashing aplicado");
+  });
+});
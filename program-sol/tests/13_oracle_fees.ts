@@ -0,0 +1,208 @@
+/**
+ * Testes Solengage - Especificação BDD
+ *
+ * Feature: Reserva de Taxas do Oráculo
+ *
+ * Cenário: Oráculo recebe taxa por atualização até o teto
+ * Given uma campanha com taxa de 1 USDC por atualização e teto de 2 USDC
+ * And a marca ativa a campanha depositando orçamento + reserva
+ * When o oráculo envia três atualizações
+ * Then o oráculo recebe 1 USDC nas duas primeiras e nada na terceira
+ *
+ * Cenário: Reserva não usada é reembolsada no cancelamento
+ * Given uma campanha ativa com reserva de 2 USDC e uma atualização paga
+ * When a marca cancela a campanha
+ * Then a marca recebe o orçamento não pago mais 1 USDC de reserva restante
+ *
+ * Cenário: Campanha concluída com reserva restante aguarda reembolso
+ * Given uma campanha com reserva de 5 USDC
+ * When o oráculo leva a campanha a 100% em uma atualização
+ * Then a campanha fica `Completed` e aberta com 4 USDC de reserva
+ * And `close_campaign` falha com "Oracle fee reserve must be refunded before closing"
+ * When a marca chama `refund_oracle_fees`
+ * Then a marca recebe os 4 USDC
+ * And `close_campaign` passa a falhar só com "Completed campaign is still within its oracle challenge period"
+ *
+ * Cenário: Relatório pendente só paga a taxa quando é aplicado
+ * Given uma campanha com janela de contestação de 2 segundos e taxa de 1 USDC
+ * When o oráculo envia uma atualização
+ * Then o oráculo ainda não recebeu a taxa
+ * When a janela termina e o relatório é finalizado
+ * Then o oráculo recebe 1 USDC
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Solengage } from "../target/types/solengage";
+import { bondOracle, registerOracle } from "./utils/oracle_registry";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { getAccount, getOrCreateAssociatedTokenAccount, createMint, TOKEN_PROGRAM_ID, mintTo } from "@solana/spl-token";
+import { expect } from "chai";
+import { nextReport } from "./utils/reports";
+import { termsVersion } from "./utils/terms";
+
+const sleep = (ms: number) => new Promise(resolve => setTimeout(resolve, ms));
+
+describe("Solengage - 13 Oracle Fees", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.solengage as Program<Solengage>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+
+  let influencer: Keypair, brand: Keypair, oracle: Keypair;
+  let oracleEntry: PublicKey;
+  let brandUsdcAccount: PublicKey, influencerUsdcAccount: PublicKey, oracleUsdcAccount: PublicKey, campaignUsdcAccount: PublicKey;
+  let campaignPda: PublicKey;
+
+  const campaignName = "Oracle Fees Test";
+  const totalAmount = new anchor.BN(1000 * 1_000_000); // 1000 USDC
+  const targetLikes = new anchor.BN(1000);
+  const feePerUpdate = 1_000_000; // 1 USDC
+
+  const balance = async (account: PublicKey) => Number((await getAccount(provider.connection, account)).amount);
+
+  const update = (likes: number) =>
+    program.methods
+      .updateCampaignMetrics(new anchor.BN(likes), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), ...nextReport())
+      .accounts({ campaign: campaignPda, oracle: oracleEntry, oracleAuthority: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, oracleUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID } as any)
+      .signers([oracle])
+      .rpc();
+
+  async function setupActiveCampaign(feeCap: number, challengeWindow = 0) {
+    influencer = Keypair.generate();
+    brand = Keypair.generate();
+    oracle = Keypair.generate();
+
+    await Promise.all(
+      [influencer, brand, oracle].map(kp =>
+        provider.connection.requestAirdrop(kp.publicKey, 2 * LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig, "confirmed"))
+      )
+    );
+    oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+
+    const usdcMint = await createMint(provider.connection, brand, brand.publicKey, null, 6);
+    await bondOracle(program, provider, oracle, oracleEntry, usdcMint, brand);
+    brandUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, brand.publicKey).then(acc => acc.address);
+    influencerUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, influencer, usdcMint, influencer.publicKey).then(acc => acc.address);
+    oracleUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, oracle, usdcMint, oracle.publicKey).then(acc => acc.address);
+
+    [campaignPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign"), influencer.publicKey.toBuffer(), brand.publicKey.toBuffer(), Buffer.from(campaignName)],
+      program.programId
+    );
+    campaignUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, campaignPda, true).then(acc => acc.address);
+    await mintTo(provider.connection, brand, usdcMint, brandUsdcAccount, brand, totalAmount.toNumber() + feeCap);
+
+    await program.methods
+      .createCampaign(campaignName, "test-nickname", "Brand", "#fees", targetLikes, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), totalAmount, new anchor.BN(Date.now() / 1000 + 86400))
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey, oracle: oracleEntry, systemProgram: SystemProgram.programId })
+      .signers([influencer])
+      .rpc();
+
+    await program.methods
+      .setOracleFee(new anchor.BN(feePerUpdate), new anchor.BN(feeCap))
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey })
+      .signers([influencer])
+      .rpc();
+
+    if (challengeWindow > 0) {
+      await program.methods
+        .setChallengeWindow(new anchor.BN(challengeWindow))
+        .accounts({ campaign: campaignPda, influencer: influencer.publicKey })
+        .signers([influencer])
+        .rpc();
+    }
+
+    await program.methods
+      .brandPayCampaign(await termsVersion(program, campaignPda))
+      .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([brand])
+      .rpc();
+
+    expect(await balance(campaignUsdcAccount)).to.equal(totalAmount.toNumber() + feeCap);
+    console.log(`✅ Campanha ativada com reserva de ${feeCap / 1_000_000} USDC`);
+  }
+
+  it("Scenario: Pays the oracle fee per update up to the cap", async () => {
+    await setupActiveCampaign(2 * feePerUpdate);
+
+    await update(100);
+    expect(await balance(oracleUsdcAccount)).to.equal(feePerUpdate);
+    await update(200);
+    expect(await balance(oracleUsdcAccount)).to.equal(2 * feePerUpdate);
+    await update(300);
+    expect(await balance(oracleUsdcAccount)).to.equal(2 * feePerUpdate);
+
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.oracleFeesPaid.toNumber()).to.equal(2 * feePerUpdate);
+    expect(campaign.oracleFeeReserve.toNumber()).to.equal(0);
+    console.log("   ✓ Oráculo recebeu 2 USDC, teto respeitado");
+  });
+
+  it("Scenario: Refunds the unused reserve on cancellation", async () => {
+    await setupActiveCampaign(2 * feePerUpdate);
+    await update(100);
+
+    await program.methods
+      .cancelCampaign()
+      .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([brand])
+      .rpc();
+
+    const expectedRefund = totalAmount.toNumber() * 9 / 10 + feePerUpdate;
+    expect(await balance(brandUsdcAccount)).to.equal(expectedRefund);
+    expect(await balance(campaignUsdcAccount)).to.equal(0);
+    console.log("   ✓ Marca reembolsada com orçamento restante + reserva não usada");
+  });
+
+  it("Scenario: Completed campaigns stay open until the reserve is refunded", async () => {
+    await setupActiveCampaign(5 * feePerUpdate);
+    await update(1000);
+
+    let campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.status).to.deep.equal({ completed: {} });
+    expect(campaign.oracleFeeReserve.toNumber()).to.equal(4 * feePerUpdate);
+
+    try {
+      await program.methods
+        .closeCampaign()
+        .accounts({ campaign: campaignPda, oracle: oracleEntry, oracleAuthority: oracle.publicKey } as any)
+        .rpc();
+      expect.fail("A campanha não deveria ser fechada com reserva pendente");
+    } catch (err) {
+      expect(err.toString()).to.include("Oracle fee reserve must be refunded before closing");
+    }
+
+    await program.methods
+      .refundOracleFees()
+      .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([brand])
+      .rpc();
+    expect(await balance(brandUsdcAccount)).to.equal(4 * feePerUpdate);
+
+    // A reserva não bloqueia mais; o fechamento aguarda o período de contestação
+    try {
+      await program.methods
+        .closeCampaign()
+        .accounts({ campaign: campaignPda, oracle: oracleEntry, oracleAuthority: oracle.publicKey } as any)
+        .rpc();
+      expect.fail("A campanha não deveria ser fechada durante o período de contestação");
+    } catch (err) {
+      expect(err.toString()).to.include("Completed campaign is still within its oracle challenge period");
+    }
+    console.log("   ✓ Reserva reembolsada; fechamento aguarda o período de contestação");
+  });
+
+  it("Scenario: A pending report only pays the fee once it is applied", async () => {
+    await setupActiveCampaign(2 * feePerUpdate, 2);
+    await update(100);
+    expect(await balance(oracleUsdcAccount)).to.equal(0);
+
+    await sleep(3000);
+    await program.methods
+      .finalizePendingReport()
+      .accounts({ campaign: campaignPda, oracle: oracleEntry, oracleAuthority: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, oracleUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID } as any)
+      .rpc();
+    expect(await balance(oracleUsdcAccount)).to.equal(feePerUpdate);
+    console.log("   ✓ Taxa paga somente na finalização do relatório");
+  });
+});
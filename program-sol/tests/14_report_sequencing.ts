@@ -0,0 +1,125 @@
+/**
+ * Testes Solengage - Especificação BDD
+ *
+ * Feature: Sequência e Horário de Observação dos Relatórios
+ *
+ * Cenário: Relatório aceito registra sequência e observação
+ * Given uma campanha ativa
+ * When o oráculo envia o relatório de sequência 5 observado há 2 minutos
+ * Then `report_sequence` é 5 e `last_observed_at` é o horário de observação
+ * And `last_updated` registra a escrita on-chain (posterior à observação)
+ *
+ * Cenário: Relatórios duplicados, fora de ordem ou antigos são rejeitados
+ * Given o relatório de sequência 5 já aceito
+ * When o oráculo reenvia a sequência 5
+ * Then a transação falha com "Report nonce has already been used"
+ * When o oráculo envia a sequência 4
+ * Then a transação falha com "Report sequence is out of order"
+ * When o oráculo envia a sequência 6 observada antes da última observação
+ * Then a transação falha com "Report observation is older than the last applied observation"
+ * When o oráculo envia a sequência 6 observada no futuro
+ * Then a transação falha com "Invalid report observation time"
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Solengage } from "../target/types/solengage";
+import { bondOracle, registerOracle } from "./utils/oracle_registry";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { getOrCreateAssociatedTokenAccount, createMint, TOKEN_PROGRAM_ID, mintTo } from "@solana/spl-token";
+import { expect } from "chai";
+import { baseMetric } from "./utils/metrics";
+import { termsVersion } from "./utils/terms";
+
+describe("Solengage - 14 Report Sequencing", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.solengage as Program<Solengage>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+
+  let influencer: Keypair, brand: Keypair, oracle: Keypair;
+  let oracleEntry: PublicKey;
+  let influencerUsdcAccount: PublicKey, campaignUsdcAccount: PublicKey;
+  let campaignPda: PublicKey;
+  let firstObservedAt: number;
+
+  const campaignName = "Report Sequencing Test";
+  const totalAmount = new anchor.BN(1000 * 1_000_000); // 1000 USDC
+  const targetLikes = new anchor.BN(1000);
+
+  const update = (likes: number, sequence: number, observedAt: number) =>
+    program.methods
+      .updateCampaignMetrics(new anchor.BN(likes), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), new anchor.BN(sequence), new anchor.BN(observedAt))
+      .accounts({ campaign: campaignPda, oracle: oracleEntry, oracleAuthority: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([oracle])
+      .rpc();
+
+  const expectRejected = async (promise: Promise<string>, message: string) => {
+    try {
+      await promise;
+      expect.fail(`O relatório deveria ser rejeitado com "${message}"`);
+    } catch (err) {
+      expect(err.toString()).to.include(message);
+    }
+  };
+
+  beforeEach(async () => {
+    influencer = Keypair.generate();
+    brand = Keypair.generate();
+    oracle = Keypair.generate();
+
+    await Promise.all(
+      [influencer, brand, oracle].map(kp =>
+        provider.connection.requestAirdrop(kp.publicKey, 2 * LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig, "confirmed"))
+      )
+    );
+    oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+
+    const usdcMint = await createMint(provider.connection, brand, brand.publicKey, null, 6);
+    await bondOracle(program, provider, oracle, oracleEntry, usdcMint, brand);
+    const brandUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, brand.publicKey).then(acc => acc.address);
+    influencerUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, influencer, usdcMint, influencer.publicKey).then(acc => acc.address);
+
+    [campaignPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign"), influencer.publicKey.toBuffer(), brand.publicKey.toBuffer(), Buffer.from(campaignName)],
+      program.programId
+    );
+    campaignUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, campaignPda, true).then(acc => acc.address);
+    await mintTo(provider.connection, brand, usdcMint, brandUsdcAccount, brand, totalAmount.toNumber());
+
+    await program.methods
+      .createCampaign(campaignName, "test-nickname", "Brand", "#sequence", targetLikes, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), totalAmount, new anchor.BN(Date.now() / 1000 + 86400))
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey, oracle: oracleEntry, systemProgram: SystemProgram.programId })
+      .signers([influencer])
+      .rpc();
+
+    await program.methods
+      .brandPayCampaign(await termsVersion(program, campaignPda))
+      .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([brand])
+      .rpc();
+
+    firstObservedAt = Math.floor(Date.now() / 1000) - 120;
+    await update(100, 5, firstObservedAt);
+    console.log("✅ Relatório de sequência 5 aceito");
+  });
+
+  it("Scenario: Stores the sequence and observation time of accepted reports", async () => {
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.reportSequence.toNumber()).to.equal(5);
+    expect(campaign.lastObservedAt.toNumber()).to.equal(firstObservedAt);
+    expect(campaign.lastUpdated.toNumber()).to.be.greaterThan(firstObservedAt);
+    expect(baseMetric(campaign, "likes").current.toNumber()).to.equal(100);
+  });
+
+  it("Scenario: Rejects duplicated, out-of-order, stale and future reports", async () => {
+    await expectRejected(update(200, 5, firstObservedAt), "Report nonce has already been used");
+    await expectRejected(update(200, 4, firstObservedAt), "Report sequence is out of order");
+    await expectRejected(update(200, 6, firstObservedAt - 1), "Report observation is older than the last applied observation");
+    await expectRejected(update(200, 6, Math.floor(Date.now() / 1000) + 3600), "Invalid report observation time");
+
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.reportSequence.toNumber()).to.equal(5);
+    expect(baseMetric(campaign, "likes").current.toNumber()).to.equal(100);
+    console.log("   ✓ Duplicado, fora de ordem, antigo e futuro rejeitados");
+  });
+});
@@ -0,0 +1,210 @@
+/**
+ * Testes Solengage - Especificação BDD
+ *
+ * Feature: Múltiplos Posts por Campanha
+ *
+ * Cenário: Progresso é calculado sobre a soma dos posts
+ * Given uma campanha ativa com meta de 1000 likes e dois posts (Reel e Story)
+ * When o oráculo reporta 300 likes no Reel e 200 likes na Story
+ * Then a campanha soma 500 likes e paga 50% do orçamento
+ * When o oráculo atualiza o Reel para 400 likes
+ * Then a campanha soma 600 likes (o valor anterior do Reel é substituído)
+ *
+ * Cenário: Campanhas com posts não aceitam relatórios da campanha inteira
+ * Given uma campanha ativa com posts
+ * When o oráculo chama `update_campaign_metrics`
+ * Then a transação falha com "Campaign has posts: report metrics per post"
+ *
+ * Cenário: Posts não podem ser adicionados depois de relatórios
+ * Given posts com métricas já reportadas
+ * When o influenciador registra um novo post
+ * Then a transação falha com "Posts can only be added before any metrics are reported"
+ *
+ * Cenário: O mesmo post não pode ser registrado duas vezes
+ * When o influenciador registra novamente a URL do Reel
+ * Then a transação falha (PDA já existe)
+ *
+ * Cenário: Posts de campanha encerrada são fechados pelo influenciador
+ * Given uma campanha ativa com posts
+ * When o influenciador tenta fechar um post
+ * Then a transação falha com "Campaign must be in Completed or Cancelled status"
+ * When a marca cancela a campanha
+ * Then o influenciador fecha os posts e recupera o rent
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Solengage } from "../target/types/solengage";
+import { bondOracle, registerOracle } from "./utils/oracle_registry";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { getAccount, getOrCreateAssociatedTokenAccount, createMint, TOKEN_PROGRAM_ID, mintTo } from "@solana/spl-token";
+import { createHash } from "crypto";
+import { expect } from "chai";
+import { nextReport } from "./utils/reports";
+import { baseMetric } from "./utils/metrics";
+import { termsVersion } from "./utils/terms";
+
+const urlHash = (url: string) => Array.from(createHash("sha256").update(url).digest());
+
+describe("Solengage - 15 Campaign Posts", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.solengage as Program<Solengage>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+
+  let influencer: Keypair, brand: Keypair, oracle: Keypair;
+  let oracleEntry: PublicKey;
+  let brandUsdcAccount: PublicKey, influencerUsdcAccount: PublicKey, campaignUsdcAccount: PublicKey;
+  let campaignPda: PublicKey, reelPost: PublicKey, storyPost: PublicKey;
+
+  const campaignName = "Campaign Posts Test";
+  const totalAmount = new anchor.BN(1000 * 1_000_000); // 1000 USDC
+  const targetLikes = new anchor.BN(1000);
+  const reelUrl = "https://instagram.com/reel/solengage-reel";
+  const storyUrl = "https://instagram.com/stories/solengage-story";
+
+  const postPda = (url: string) =>
+    PublicKey.findProgramAddressSync([Buffer.from("campaign_post"), campaignPda.toBuffer(), Buffer.from(urlHash(url))], program.programId)[0];
+
+  const addPost = (url: string) =>
+    program.methods
+      .addCampaignPost(urlHash(url), { instagram: {} })
+      .accounts({ campaign: campaignPda, campaignPost: postPda(url), influencer: influencer.publicKey, systemProgram: SystemProgram.programId } as any)
+      .signers([influencer])
+      .rpc();
+
+  const updatePost = (post: PublicKey, likes: number) =>
+    program.methods
+      .updatePostMetrics(new anchor.BN(likes), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), ...nextReport())
+      .accounts({ campaign: campaignPda, campaignPost: post, oracle: oracleEntry, oracleAuthority: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID } as any)
+      .signers([oracle])
+      .rpc();
+
+  const closePost = (post: PublicKey) =>
+    program.methods
+      .closeCampaignPost()
+      .accounts({ campaignPost: post, campaign: campaignPda, influencer: influencer.publicKey } as any)
+      .signers([influencer])
+      .rpc();
+
+  beforeEach(async () => {
+    influencer = Keypair.generate();
+    brand = Keypair.generate();
+    oracle = Keypair.generate();
+
+    await Promise.all(
+      [influencer, brand, oracle].map(kp =>
+        provider.connection.requestAirdrop(kp.publicKey, 2 * LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig, "confirmed"))
+      )
+    );
+    oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+
+    const usdcMint = await createMint(provider.connection, brand, brand.publicKey, null, 6);
+    await bondOracle(program, provider, oracle, oracleEntry, usdcMint, brand);
+    brandUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, brand.publicKey).then(acc => acc.address);
+    influencerUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, influencer, usdcMint, influencer.publicKey).then(acc => acc.address);
+
+    [campaignPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign"), influencer.publicKey.toBuffer(), brand.publicKey.toBuffer(), Buffer.from(campaignName)],
+      program.programId
+    );
+    campaignUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, campaignPda, true).then(acc => acc.address);
+    await mintTo(provider.connection, brand, usdcMint, brandUsdcAccount, brand, totalAmount.toNumber());
+
+    await program.methods
+      .createCampaign(campaignName, "test-nickname", "Brand", "#posts", targetLikes, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), totalAmount, new anchor.BN(Date.now() / 1000 + 86400))
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey, oracle: oracleEntry, systemProgram: SystemProgram.programId })
+      .signers([influencer])
+      .rpc();
+
+    await program.methods
+      .brandPayCampaign(await termsVersion(program, campaignPda))
+      .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([brand])
+      .rpc();
+
+    await addPost(reelUrl);
+    await addPost(storyUrl);
+    reelPost = postPda(reelUrl);
+    storyPost = postPda(storyUrl);
+    console.log("✅ Campanha ativa com dois posts registrados");
+  });
+
+  it("Scenario: Computes progress from the sum across posts", async () => {
+    await updatePost(reelPost, 300);
+    await updatePost(storyPost, 200);
+
+    let campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.postCount).to.equal(2);
+    expect(baseMetric(campaign, "likes").current.toNumber()).to.equal(500);
+    const influencerBalance = (await getAccount(provider.connection, influencerUsdcAccount)).amount;
+    expect(influencerBalance.toString()).to.equal(totalAmount.divn(2).toString());
+    console.log("   ✓ 300 + 200 likes = 50% pago");
+
+    await updatePost(reelPost, 400);
+    campaign = await program.account.campaign.fetch(campaignPda);
+    expect(baseMetric(campaign, "likes").current.toNumber()).to.equal(600);
+
+    const reel = await program.account.campaignPost.fetch(reelPost);
+    expect(reel.metrics.likes.toNumber()).to.equal(400);
+    expect(reel.index).to.equal(0);
+    console.log("   ✓ Reel atualizado para 400: agregado 600 likes");
+  });
+
+  it("Scenario: Rejects whole-campaign reports once posts exist", async () => {
+    try {
+      await program.methods
+        .updateCampaignMetrics(new anchor.BN(500), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), ...nextReport())
+        .accounts({ campaign: campaignPda, oracle: oracleEntry, oracleAuthority: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+        .signers([oracle])
+        .rpc();
+      expect.fail("Relatórios da campanha inteira deveriam ser rejeitados");
+    } catch (err) {
+      expect(err.toString()).to.include("Campaign has posts: report metrics per post");
+    }
+  });
+
+  it("Scenario: Rejects new posts once metrics were reported", async () => {
+    await updatePost(reelPost, 300);
+    try {
+      await addPost("https://instagram.com/p/late-post");
+      expect.fail("Post adicionado após relatórios deveria ser rejeitado");
+    } catch (err) {
+      expect(err.toString()).to.include("Posts can only be added before any metrics are reported");
+    }
+  });
+
+  it("Scenario: The same post cannot be registered twice", async () => {
+    try {
+      await addPost(reelUrl);
+      expect.fail("O mesmo post não deveria ser registrado duas vezes");
+    } catch (err) {
+      expect(err.toString()).to.match(/already in use|custom program error: 0x0/);
+    }
+
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.postCount).to.equal(2);
+  });
+
+  it("Scenario: The influencer closes the posts of a finished campaign", async () => {
+    try {
+      await closePost(reelPost);
+      expect.fail("Post de campanha ativa não deveria ser fechado");
+    } catch (err) {
+      expect(err.toString()).to.include("Campaign must be in Completed or Cancelled status");
+    }
+
+    await program.methods
+      .cancelCampaign()
+      .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([brand])
+      .rpc();
+
+    const influencerBalanceBefore = await provider.connection.getBalance(influencer.publicKey);
+    await closePost(reelPost);
+    await closePost(storyPost);
+    expect(await provider.connection.getAccountInfo(reelPost)).to.be.null;
+    expect(await provider.connection.getAccountInfo(storyPost)).to.be.null;
+    expect(await provider.connection.getBalance(influencer.publicKey)).to.be.greaterThan(influencerBalanceBefore);
+    console.log("   ✓ Campanha cancelada: posts fechados e rent devolvido ao influenciador");
+  });
+});
@@ -0,0 +1,202 @@
+/**
+ * Testes Solengage - Especificação BDD
+ *
+ * Feature: Metas Genéricas de Métricas
+ *
+ * Cenário: Metas por plataforma contam para o progresso
+ * Given uma campanha com meta de 1000 likes e meta de 1000 salvamentos no Instagram, pesos iguais
+ * When o oráculo reporta 500 likes
+ * Then o progresso é 25% e 20% do orçamento é pago
+ * When o oráculo reporta 500 salvamentos no Instagram
+ * Then o progresso é 50% e 50% do orçamento foi pago
+ *
+ * Cenário: Pesos definem a contribuição de cada meta
+ * Given metas de 1000 likes (peso 1) e 100 cliques no link (peso 3)
+ * When o oráculo reporta 1000 likes e 50 cliques
+ * Then o progresso é (1 * 100% + 3 * 50%) / 4 = 62% e 60% do orçamento é pago
+ *
+ * Cenário: Posts alimentam a meta da sua plataforma
+ * Given uma campanha com meta de 1000 likes no Instagram, um Reel no Instagram e um vídeo no TikTok
+ * When o oráculo reporta 300 likes no Reel e 200 likes no vídeo
+ * Then a meta do Instagram soma 300 likes, o total geral 500 likes e 30% do orçamento é pago
+ *
+ * Cenário: Métrica não suportada pela plataforma é rejeitada
+ * When o influenciador define uma meta de retweets no Instagram
+ * Then a transação falha com "Invalid metric targets"
+ *
+ * Cenário: Relatório sem meta correspondente é rejeitado
+ * When o oráculo reporta minutos assistidos no YouTube sem meta definida
+ * Then a transação falha com "No metric target for the reported value"
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Solengage } from "../target/types/solengage";
+import { bondOracle, registerOracle } from "./utils/oracle_registry";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { getAccount, getOrCreateAssociatedTokenAccount, createMint, TOKEN_PROGRAM_ID, mintTo } from "@solana/spl-token";
+import { createHash } from "crypto";
+import { expect } from "chai";
+import { nextReport } from "./utils/reports";
+import { baseMetric } from "./utils/metrics";
+import { termsVersion } from "./utils/terms";
+
+const urlHash = (url: string) => Array.from(createHash("sha256").update(url).digest());
+
+describe("Solengage - 16 Metric Targets", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.solengage as Program<Solengage>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+
+  let influencer: Keypair, brand: Keypair, oracle: Keypair;
+  let oracleEntry: PublicKey;
+  let brandUsdcAccount: PublicKey, influencerUsdcAccount: PublicKey, campaignUsdcAccount: PublicKey;
+  let campaignPda: PublicKey;
+
+  const campaignName = "Metric Targets Test";
+  const totalAmount = new anchor.BN(1000 * 1_000_000); // 1000 USDC
+  const targetLikes = new anchor.BN(1000);
+
+  const target = (kind: string, platform: string | null, value: number, weight: number) =>
+    ({ kind: { [kind]: {} }, platform: platform ? { [platform]: {} } : null, target: new anchor.BN(value), weight: new anchor.BN(weight) });
+  const reading = (kind: string, platform: string | null, value: number) =>
+    ({ kind: { [kind]: {} }, platform: platform ? { [platform]: {} } : null, value: new anchor.BN(value) });
+
+  const setTargets = (targets: any[]) =>
+    program.methods
+      .setMetricTargets(targets)
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey })
+      .signers([influencer])
+      .rpc();
+
+  const updateValues = (readings: any[]) =>
+    program.methods
+      .updateMetricValues(readings, ...nextReport())
+      .accounts({ campaign: campaignPda, oracle: oracleEntry, oracleAuthority: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID } as any)
+      .signers([oracle])
+      .rpc();
+
+  const activate = async () =>
+    program.methods
+      .brandPayCampaign(await termsVersion(program, campaignPda))
+      .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([brand])
+      .rpc();
+
+  beforeEach(async () => {
+    influencer = Keypair.generate();
+    brand = Keypair.generate();
+    oracle = Keypair.generate();
+
+    await Promise.all(
+      [influencer, brand, oracle].map(kp =>
+        provider.connection.requestAirdrop(kp.publicKey, 2 * LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig, "confirmed"))
+      )
+    );
+    oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+
+    const usdcMint = await createMint(provider.connection, brand, brand.publicKey, null, 6);
+    await bondOracle(program, provider, oracle, oracleEntry, usdcMint, brand);
+    brandUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, brand.publicKey).then(acc => acc.address);
+    influencerUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, influencer, usdcMint, influencer.publicKey).then(acc => acc.address);
+
+    [campaignPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign"), influencer.publicKey.toBuffer(), brand.publicKey.toBuffer(), Buffer.from(campaignName)],
+      program.programId
+    );
+    campaignUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, campaignPda, true).then(acc => acc.address);
+    await mintTo(provider.connection, brand, usdcMint, brandUsdcAccount, brand, totalAmount.toNumber());
+
+    await program.methods
+      .createCampaign(campaignName, "test-nickname", "Brand", "#metrics", targetLikes, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), totalAmount, new anchor.BN(Date.now() / 1000 + 86400))
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey, oracle: oracleEntry, systemProgram: SystemProgram.programId })
+      .signers([influencer])
+      .rpc();
+  });
+
+  it("Scenario: Platform targets count towards progress", async () => {
+    await setTargets([target("likes", null, 1000, 1), target("saves", "instagram", 1000, 1)]);
+    await activate();
+
+    await program.methods
+      .updateCampaignMetrics(new anchor.BN(500), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), ...nextReport())
+      .accounts({ campaign: campaignPda, oracle: oracleEntry, oracleAuthority: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([oracle])
+      .rpc();
+    let influencerBalance = (await getAccount(provider.connection, influencerUsdcAccount)).amount;
+    expect(influencerBalance.toString()).to.equal(totalAmount.muln(2).divn(10).toString());
+    console.log("   ✓ 500 likes: 25% de progresso, 20% pago");
+
+    await updateValues([reading("saves", "instagram", 500)]);
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.metrics[4].current.toNumber()).to.equal(500);
+    expect(campaign.metrics[4].pending).to.be.null;
+    expect(baseMetric(campaign, "likes").current.toNumber()).to.equal(500);
+    influencerBalance = (await getAccount(provider.connection, influencerUsdcAccount)).amount;
+    expect(influencerBalance.toString()).to.equal(totalAmount.divn(2).toString());
+    console.log("   ✓ 500 salvamentos no Instagram: 50% pago");
+  });
+
+  it("Scenario: Posts feed the target of their platform", async () => {
+    await setTargets([target("likes", "instagram", 1000, 1)]);
+    const addPost = async (url: string, platform: string) => {
+      const [post] = PublicKey.findProgramAddressSync([Buffer.from("campaign_post"), campaignPda.toBuffer(), Buffer.from(urlHash(url))], program.programId);
+      await program.methods
+        .addCampaignPost(urlHash(url), { [platform]: {} } as any)
+        .accounts({ campaign: campaignPda, campaignPost: post, influencer: influencer.publicKey, systemProgram: SystemProgram.programId } as any)
+        .signers([influencer])
+        .rpc();
+      return post;
+    };
+    const reel = await addPost("https://instagram.com/reel/targets-reel", "instagram");
+    const video = await addPost("https://tiktok.com/@solengage/video/targets", "tikTok");
+    await activate();
+
+    const updatePost = (post: PublicKey, likes: number) =>
+      program.methods
+        .updatePostMetrics(new anchor.BN(likes), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), ...nextReport())
+        .accounts({ campaign: campaignPda, campaignPost: post, oracle: oracleEntry, oracleAuthority: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID } as any)
+        .signers([oracle])
+        .rpc();
+    await updatePost(reel, 300);
+    await updatePost(video, 200);
+
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.metrics[4].current.toNumber()).to.equal(300);
+    expect(baseMetric(campaign, "likes").current.toNumber()).to.equal(500);
+    const influencerBalance = (await getAccount(provider.connection, influencerUsdcAccount)).amount;
+    expect(influencerBalance.toString()).to.equal(totalAmount.muln(3).divn(10).toString());
+    console.log("   ✓ Meta do Instagram soma apenas o Reel: 30% pago");
+  });
+
+  it("Scenario: Weights set each target's contribution", async () => {
+    await setTargets([target("likes", null, 1000, 1), target("linkClicks", null, 100, 3)]);
+    await activate();
+
+    await updateValues([reading("likes", null, 1000), reading("linkClicks", null, 50)]);
+    const influencerBalance = (await getAccount(provider.connection, influencerUsdcAccount)).amount;
+    expect(influencerBalance.toString()).to.equal(totalAmount.muln(6).divn(10).toString());
+    console.log("   ✓ Progresso ponderado de 62%: 60% pago");
+  });
+
+  it("Scenario: Rejects metrics the platform does not expose", async () => {
+    try {
+      await setTargets([target("retweets", "instagram", 100, 1)]);
+      expect.fail("Retweets no Instagram deveriam ser rejeitados");
+    } catch (err) {
+      expect(err.toString()).to.include("Invalid metric targets");
+    }
+  });
+
+  it("Scenario: Rejects reports without a matching target", async () => {
+    await setTargets([target("likes", null, 1000, 1), target("saves", "instagram", 1000, 1)]);
+    await activate();
+
+    try {
+      await updateValues([reading("watchMinutes", "youTube", 100)]);
+      expect.fail("Relatório sem meta correspondente deveria ser rejeitado");
+    } catch (err) {
+      expect(err.toString()).to.include("No metric target for the reported value");
+    }
+  });
+});
@@ -0,0 +1,153 @@
+/**
+ * Testes Solengage - Especificação BDD
+ *
+ * Feature: Histórico de Métricas
+ *
+ * Cenário: Cada relatório aplicado é registrado no histórico
+ * Given uma campanha ativa com meta de 1000 likes e histórico habilitado pela marca
+ * When o oráculo reporta 250 likes e depois 500 likes
+ * Then o histórico tem duas entradas com valores (base e extras), progresso e valor pago em cada relatório
+ *
+ * Cenário: Relatórios sem a conta de histórico são rejeitados
+ * When o oráculo chama `update_campaign_metrics` sem informar o histórico
+ * Then a transação falha com "Campaign records metric history: history account required"
+ *
+ * Cenário: O histórico só pode ser fechado com a campanha encerrada
+ * When a marca tenta fechar o histórico de uma campanha ativa
+ * Then a transação falha com "Campaign must be in Completed or Cancelled status"
+ * When a campanha atinge 100% e fica `Completed`
+ * Then a marca fecha o histórico e recupera o rent
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Solengage } from "../target/types/solengage";
+import { bondOracle, registerOracle } from "./utils/oracle_registry";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { getOrCreateAssociatedTokenAccount, createMint, TOKEN_PROGRAM_ID, mintTo } from "@solana/spl-token";
+import { expect } from "chai";
+import { nextReport } from "./utils/reports";
+import { termsVersion } from "./utils/terms";
+
+describe("Solengage - 17 Metric History", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.solengage as Program<Solengage>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+
+  let influencer: Keypair, brand: Keypair, oracle: Keypair;
+  let oracleEntry: PublicKey;
+  let influencerUsdcAccount: PublicKey, campaignUsdcAccount: PublicKey;
+  let campaignPda: PublicKey, historyPda: PublicKey;
+
+  const campaignName = "Metric History Test";
+  const totalAmount = new anchor.BN(1000 * 1_000_000); // 1000 USDC
+  const targetLikes = new anchor.BN(1000);
+
+  const updateMetrics = (likes: number, metricHistory: PublicKey | null = historyPda) =>
+    program.methods
+      .updateCampaignMetrics(new anchor.BN(likes), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), ...nextReport())
+      .accounts({ campaign: campaignPda, oracle: oracleEntry, oracleAuthority: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, metricHistory, tokenProgram: TOKEN_PROGRAM_ID } as any)
+      .signers([oracle])
+      .rpc();
+
+  const closeHistory = () =>
+    program.methods
+      .closeMetricHistory()
+      .accounts({ metricHistory: historyPda, campaign: campaignPda, brand: brand.publicKey } as any)
+      .signers([brand])
+      .rpc();
+
+  beforeEach(async () => {
+    influencer = Keypair.generate();
+    brand = Keypair.generate();
+    oracle = Keypair.generate();
+
+    await Promise.all(
+      [influencer, brand, oracle].map(kp =>
+        provider.connection.requestAirdrop(kp.publicKey, 2 * LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig, "confirmed"))
+      )
+    );
+    oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+
+    const usdcMint = await createMint(provider.connection, brand, brand.publicKey, null, 6);
+    await bondOracle(program, provider, oracle, oracleEntry, usdcMint, brand);
+    const brandUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, brand.publicKey).then(acc => acc.address);
+    influencerUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, influencer, usdcMint, influencer.publicKey).then(acc => acc.address);
+
+    [campaignPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign"), influencer.publicKey.toBuffer(), brand.publicKey.toBuffer(), Buffer.from(campaignName)],
+      program.programId
+    );
+    [historyPda] = PublicKey.findProgramAddressSync([Buffer.from("metric_history"), campaignPda.toBuffer()], program.programId);
+    campaignUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, campaignPda, true).then(acc => acc.address);
+    await mintTo(provider.connection, brand, usdcMint, brandUsdcAccount, brand, totalAmount.toNumber());
+
+    await program.methods
+      .createCampaign(campaignName, "test-nickname", "Brand", "#history", targetLikes, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), totalAmount, new anchor.BN(Date.now() / 1000 + 86400))
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey, oracle: oracleEntry, systemProgram: SystemProgram.programId })
+      .signers([influencer])
+      .rpc();
+
+    await program.methods
+      .brandPayCampaign(await termsVersion(program, campaignPda))
+      .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([brand])
+      .rpc();
+
+    await program.methods
+      .initializeMetricHistory()
+      .accounts({ campaign: campaignPda, metricHistory: historyPda, brand: brand.publicKey, systemProgram: SystemProgram.programId } as any)
+      .signers([brand])
+      .rpc();
+    console.log("✅ Campanha ativa com histórico habilitado");
+  });
+
+  it("Scenario: Records every applied report", async () => {
+    await updateMetrics(250);
+    await updateMetrics(500);
+
+    const history = await program.account.metricHistory.fetch(historyPda);
+    expect(history.totalRecorded.toNumber()).to.equal(2);
+    expect(history.head).to.equal(2);
+    expect(history.entries).to.have.length(2);
+
+    const [first, second] = history.entries;
+    expect(first.values.likes.toNumber()).to.equal(250);
+    expect(first.progress).to.equal(25);
+    expect(first.amountPaid.toString()).to.equal(totalAmount.muln(2).divn(10).toString());
+    expect(first.extraValues.map(value => value.toNumber())).to.deep.equal([0, 0, 0, 0, 0, 0]);
+    expect(second.values.likes.toNumber()).to.equal(500);
+    expect(second.progress).to.equal(50);
+    expect(second.amountPaid.toString()).to.equal(totalAmount.muln(3).divn(10).toString());
+    expect(second.totalPaid.toString()).to.equal(totalAmount.divn(2).toString());
+    console.log("   ✓ Dois relatórios registrados: 20% e depois 30% pagos");
+  });
+
+  it("Scenario: Rejects reports without the history account", async () => {
+    try {
+      await updateMetrics(250, null);
+      expect.fail("Relatório sem histórico deveria ser rejeitado");
+    } catch (err) {
+      expect(err.toString()).to.include("Campaign records metric history: history account required");
+    }
+  });
+
+  it("Scenario: History closes only with the campaign", async () => {
+    try {
+      await closeHistory();
+      expect.fail("Histórico de campanha ativa não deveria ser fechado");
+    } catch (err) {
+      expect(err.toString()).to.include("Campaign must be in Completed or Cancelled status");
+    }
+
+    await updateMetrics(1000);
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.status).to.deep.equal({ completed: {} });
+
+    const brandBalanceBefore = await provider.connection.getBalance(brand.publicKey);
+    await closeHistory();
+    expect(await provider.connection.getAccountInfo(historyPda)).to.be.null;
+    expect(await provider.connection.getBalance(brand.publicKey)).to.be.greaterThan(brandBalanceBefore);
+    console.log("   ✓ Campanha concluída: histórico fechado e rent devolvido à marca");
+  });
+});
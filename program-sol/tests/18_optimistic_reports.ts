@@ -0,0 +1,211 @@
+/**
+ * Testes Solengage - Especificação BDD
+ *
+ * Feature: Relatórios Otimistas com Janela de Contestação
+ *
+ * Cenário: Relatório não contestado é finalizado após a janela
+ * Given uma campanha ativa com meta de 1000 likes e janela de contestação de 3 segundos
+ * When o oráculo reporta 500 likes
+ * Then nenhum pagamento é feito e o relatório fica pendente
+ * When alguém tenta finalizar antes do fim da janela
+ * Then a transação falha com "The challenge window for this report is still open"
+ * When a janela termina e qualquer conta chama `finalize_pending_report`
+ * Then 50% do orçamento é pago
+ *
+ * Cenário: Relatório contestado fica congelado até o árbitro decidir
+ * When o oráculo reporta 500 likes e a marca contesta dentro da janela
+ * Then o relatório não pode ser finalizado e novos relatórios são rejeitados
+ * And a marca não pode resolver a própria contestação
+ * When o árbitro descarta o relatório
+ * Then nenhum pagamento é feito e a campanha mantém as métricas anteriores
+ *
+ * Cenário: Relatório pendente impede o cancelamento
+ * When o oráculo reporta 500 likes e a marca tenta cancelar dentro da janela
+ * Then a transação falha com "A report is pending its challenge window"
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Solengage } from "../target/types/solengage";
+import { bondOracle, ensureOracleRegistry, registerOracle } from "./utils/oracle_registry";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { getAccount, getOrCreateAssociatedTokenAccount, createMint, TOKEN_PROGRAM_ID, mintTo } from "@solana/spl-token";
+import { expect } from "chai";
+import { nextReport } from "./utils/reports";
+import { baseMetric } from "./utils/metrics";
+import { termsVersion } from "./utils/terms";
+
+const sleep = (ms: number) => new Promise(resolve => setTimeout(resolve, ms));
+
+describe("Solengage - 18 Optimistic Reports", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.solengage as Program<Solengage>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+
+  let influencer: Keypair, brand: Keypair, oracle: Keypair, arbiter: Keypair;
+  let oracleRegistry: PublicKey, oracleEntry: PublicKey;
+  let brandUsdcAccount: PublicKey, influencerUsdcAccount: PublicKey, campaignUsdcAccount: PublicKey;
+  let campaignPda: PublicKey;
+
+  const campaignName = "Optimistic Reports Test";
+  const totalAmount = new anchor.BN(1000 * 1_000_000); // 1000 USDC
+  const targetLikes = new anchor.BN(1000);
+  const challengeWindow = 3; // segundos
+
+  const updateMetrics = (likes: number) =>
+    program.methods
+      .updateCampaignMetrics(new anchor.BN(likes), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), ...nextReport())
+      .accounts({ campaign: campaignPda, oracle: oracleEntry, oracleAuthority: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID } as any)
+      .signers([oracle])
+      .rpc();
+
+  const finalize = () =>
+    program.methods
+      .finalizePendingReport()
+      .accounts({ campaign: campaignPda, oracle: oracleEntry, oracleAuthority: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID } as any)
+      .rpc();
+
+  const resolve = (reviewer: Keypair, approve: boolean) =>
+    program.methods
+      .resolveHeldReport(approve)
+      .accounts({
+        campaign: campaignPda, oracleRegistry, oracle: oracleEntry, oracleAuthority: oracle.publicKey,
+        reviewer: reviewer.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID,
+      } as any)
+      .signers([reviewer])
+      .rpc();
+
+  const influencerBalance = async () => (await getAccount(provider.connection, influencerUsdcAccount)).amount.toString();
+
+  beforeEach(async () => {
+    influencer = Keypair.generate();
+    brand = Keypair.generate();
+    oracle = Keypair.generate();
+    arbiter = Keypair.generate();
+
+    await Promise.all(
+      [influencer, brand, oracle].map(kp =>
+        provider.connection.requestAirdrop(kp.publicKey, 2 * LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig, "confirmed"))
+      )
+    );
+    oracleRegistry = await ensureOracleRegistry(program, provider);
+    oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+    await program.methods
+      .setArbiter(arbiter.publicKey)
+      .accounts({ oracleRegistry, admin: provider.wallet.publicKey } as any)
+      .rpc();
+
+    const usdcMint = await createMint(provider.connection, brand, brand.publicKey, null, 6);
+    await bondOracle(program, provider, oracle, oracleEntry, usdcMint, brand);
+    brandUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, brand.publicKey).then(acc => acc.address);
+    influencerUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, influencer, usdcMint, influencer.publicKey).then(acc => acc.address);
+
+    [campaignPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign"), influencer.publicKey.toBuffer(), brand.publicKey.toBuffer(), Buffer.from(campaignName)],
+      program.programId
+    );
+    campaignUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, campaignPda, true).then(acc => acc.address);
+    await mintTo(provider.connection, brand, usdcMint, brandUsdcAccount, brand, totalAmount.toNumber());
+
+    await program.methods
+      .createCampaign(campaignName, "test-nickname", "Brand", "#optimistic", targetLikes, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), totalAmount, new anchor.BN(Date.now() / 1000 + 86400))
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey, oracle: oracleEntry, systemProgram: SystemProgram.programId })
+      .signers([influencer])
+      .rpc();
+
+    await program.methods
+      .setChallengeWindow(new anchor.BN(challengeWindow))
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey })
+      .signers([influencer])
+      .rpc();
+
+    await program.methods
+      .brandPayCampaign(await termsVersion(program, campaignPda))
+      .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([brand])
+      .rpc();
+    console.log("✅ Campanha ativa com janela de contestação de 3s");
+  });
+
+  it("Scenario: Unchallenged report is finalized after the window", async () => {
+    await updateMetrics(500);
+
+    let campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.reportPendingSince.toNumber()).to.be.greaterThan(0);
+    expect(baseMetric(campaign, "likes").current.toNumber()).to.equal(0);
+    expect(baseMetric(campaign, "likes").pending.toNumber()).to.equal(500);
+    expect(await influencerBalance()).to.equal("0");
+    console.log("   ✓ Relatório pendente, nenhum pagamento");
+
+    try {
+      await finalize();
+      expect.fail("Finalização antes do fim da janela deveria falhar");
+    } catch (err) {
+      expect(err.toString()).to.include("The challenge window for this report is still open");
+    }
+
+    await sleep((challengeWindow + 1) * 1000);
+    await finalize();
+
+    campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.reportPendingSince.toNumber()).to.equal(0);
+    expect(baseMetric(campaign, "likes").current.toNumber()).to.equal(500);
+    expect(await influencerBalance()).to.equal(totalAmount.divn(2).toString());
+    console.log("   ✓ Janela encerrada: relatório finalizado e 50% pago");
+  });
+
+  it("Scenario: Challenged report stays frozen until the arbiter rules", async () => {
+    await updateMetrics(500);
+    await program.methods
+      .challengePendingReport()
+      .accounts({ campaign: campaignPda, brand: brand.publicKey })
+      .signers([brand])
+      .rpc();
+
+    let campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.reportChallenged).to.be.true;
+    expect(campaign.reportHeld).to.be.true;
+
+    await sleep((challengeWindow + 1) * 1000);
+    try {
+      await finalize();
+      expect.fail("Relatório contestado não deveria ser finalizado");
+    } catch (err) {
+      expect(err.toString()).to.include("No report is pending its challenge window");
+    }
+    try {
+      await updateMetrics(600);
+      expect.fail("Novos relatórios deveriam ser rejeitados");
+    } catch (err) {
+      expect(err.toString()).to.include("A report is held pending brand or admin review");
+    }
+    try {
+      await resolve(brand, true);
+      expect.fail("A marca não deveria resolver a própria contestação");
+    } catch (err) {
+      expect(err.toString()).to.include("Only the registry arbiter can resolve challenged reports");
+    }
+
+    await resolve(arbiter, false);
+    campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.reportHeld).to.be.false;
+    expect(campaign.reportChallenged).to.be.false;
+    expect(baseMetric(campaign, "likes").current.toNumber()).to.equal(0);
+    expect(await influencerBalance()).to.equal("0");
+    console.log("   ✓ Árbitro descartou o relatório contestado: nada pago");
+  });
+
+  it("Scenario: A pending report blocks cancellation", async () => {
+    await updateMetrics(500);
+    try {
+      await program.methods
+        .cancelCampaign()
+        .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID } as any)
+        .signers([brand])
+        .rpc();
+      expect.fail("Campanha com relatório pendente não deveria ser cancelada");
+    } catch (err) {
+      expect(err.toString()).to.include("A report is pending its challenge window");
+    }
+  });
+});
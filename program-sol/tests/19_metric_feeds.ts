@@ -0,0 +1,159 @@
+/**
+ * Testes Solengage - Especificação BDD
+ *
+ * Feature: Feed Externo de Métricas
+ *
+ * O feed de teste é uma conta construída localmente (tests/fixtures/metric_feed.json,
+ * carregada pelo validador via Anchor.toml) com 500 likes observados em 2023-11-14.
+ *
+ * Cenário: Valor do feed é aplicado pela mesma lógica de pagamento
+ * Given uma campanha ativa com meta de 1000 likes e feed de likes sem limite prático de idade
+ * When qualquer conta chama `apply_feed_metrics`
+ * Then a campanha registra 500 likes e paga 50% do orçamento
+ * When o mesmo valor do feed é aplicado novamente
+ * Then a transação falha com "Report observation is older than the last applied observation"
+ *
+ * Cenário: Feed antigo é rejeitado
+ * Given um feed configurado com idade máxima de 60 segundos
+ * When alguém chama `apply_feed_metrics`
+ * Then a transação falha com "Feed value is too old"
+ *
+ * Cenário: Feed escrito por outra autoridade é rejeitado
+ * Given um feed configurado com outra autoridade esperada
+ * When alguém chama `apply_feed_metrics`
+ * Then a transação falha com "Feed is not written by the configured feed authority"
+ *
+ * Cenário: Feed de outro programa é rejeitado
+ * Given um feed configurado com outro programa dono esperado
+ * When alguém chama `apply_feed_metrics`
+ * Then a transação falha com "Feed account is not owned by the configured feed program"
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Solengage } from "../target/types/solengage";
+import { bondOracle, registerOracle } from "./utils/oracle_registry";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { getAccount, getOrCreateAssociatedTokenAccount, createMint, TOKEN_PROGRAM_ID, mintTo } from "@solana/spl-token";
+import { expect } from "chai";
+import { baseMetric } from "./utils/metrics";
+import { termsVersion } from "./utils/terms";
+
+const FEED = new PublicKey("Gkf6Lt1i23GhcGvaYX1tzTu2VTTNi4one1m93fdij6KE");
+const FEED_AUTHORITY = new PublicKey("CtM7wo1PjTXrZxiNasM3mCuLhrFkQcdM6DDXQHgRCs59");
+const FEED_PROGRAM = new PublicKey("7vRF22TQnZqKfwCFvWMQ7ER1qGKBbho9vDS3FBxkZYc8");
+const ONE_HUNDRED_YEARS = 100 * 365 * 24 * 3600;
+
+describe("Solengage - 19 Metric Feeds", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.solengage as Program<Solengage>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+
+  let influencer: Keypair, brand: Keypair, oracle: Keypair;
+  let oracleEntry: PublicKey;
+  let brandUsdcAccount: PublicKey, influencerUsdcAccount: PublicKey, campaignUsdcAccount: PublicKey;
+  let campaignPda: PublicKey;
+
+  const campaignName = "Metric Feeds Test";
+  const totalAmount = new anchor.BN(1000 * 1_000_000); // 1000 USDC
+  const targetLikes = new anchor.BN(1000);
+
+  const setFeedAndActivate = async (authority: PublicKey, maxAge: number, ownerProgram = FEED_PROGRAM) => {
+    await program.methods
+      .setMetricFeed({ feed: FEED, ownerProgram, authority, kind: { likes: {} }, platform: null, maxAge: new anchor.BN(maxAge) })
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey })
+      .signers([influencer])
+      .rpc();
+
+    await program.methods
+      .brandPayCampaign(await termsVersion(program, campaignPda))
+      .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([brand])
+      .rpc();
+  };
+
+  const applyFeed = () =>
+    program.methods
+      .applyFeedMetrics()
+      .accounts({ campaign: campaignPda, feed: FEED, oracle: oracleEntry, oracleAuthority: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID } as any)
+      .rpc();
+
+  beforeEach(async () => {
+    influencer = Keypair.generate();
+    brand = Keypair.generate();
+    oracle = Keypair.generate();
+
+    await Promise.all(
+      [influencer, brand, oracle].map(kp =>
+        provider.connection.requestAirdrop(kp.publicKey, 2 * LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig, "confirmed"))
+      )
+    );
+    oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+
+    const usdcMint = await createMint(provider.connection, brand, brand.publicKey, null, 6);
+    await bondOracle(program, provider, oracle, oracleEntry, usdcMint, brand);
+    brandUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, brand.publicKey).then(acc => acc.address);
+    influencerUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, influencer, usdcMint, influencer.publicKey).then(acc => acc.address);
+
+    [campaignPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign"), influencer.publicKey.toBuffer(), brand.publicKey.toBuffer(), Buffer.from(campaignName)],
+      program.programId
+    );
+    campaignUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, campaignPda, true).then(acc => acc.address);
+    await mintTo(provider.connection, brand, usdcMint, brandUsdcAccount, brand, totalAmount.toNumber());
+
+    await program.methods
+      .createCampaign(campaignName, "test-nickname", "Brand", "#feeds", targetLikes, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), totalAmount, new anchor.BN(Date.now() / 1000 + 86400))
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey, oracle: oracleEntry, systemProgram: SystemProgram.programId })
+      .signers([influencer])
+      .rpc();
+  });
+
+  it("Scenario: Applies the feed value through the payout logic", async () => {
+    await setFeedAndActivate(FEED_AUTHORITY, ONE_HUNDRED_YEARS);
+    await applyFeed();
+
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    expect(baseMetric(campaign, "likes").current.toNumber()).to.equal(500);
+    const influencerBalance = (await getAccount(provider.connection, influencerUsdcAccount)).amount;
+    expect(influencerBalance.toString()).to.equal(totalAmount.divn(2).toString());
+    console.log("   ✓ Feed aplicado: 500 likes, 50% pago");
+
+    try {
+      await applyFeed();
+      expect.fail("O mesmo valor do feed não deveria ser aplicado duas vezes");
+    } catch (err) {
+      expect(err.toString()).to.include("Report observation is older than the last applied observation");
+    }
+  });
+
+  it("Scenario: Rejects a stale feed", async () => {
+    await setFeedAndActivate(FEED_AUTHORITY, 60);
+    try {
+      await applyFeed();
+      expect.fail("Feed antigo deveria ser rejeitado");
+    } catch (err) {
+      expect(err.toString()).to.include("Feed value is too old");
+    }
+  });
+
+  it("Scenario: Rejects a feed written by another authority", async () => {
+    await setFeedAndActivate(Keypair.generate().publicKey, ONE_HUNDRED_YEARS);
+    try {
+      await applyFeed();
+      expect.fail("Feed de outra autoridade deveria ser rejeitado");
+    } catch (err) {
+      expect(err.toString()).to.include("Feed is not written by the configured feed authority");
+    }
+  });
+
+  it("Scenario: Rejects a feed owned by another program", async () => {
+    await setFeedAndActivate(FEED_AUTHORITY, ONE_HUNDRED_YEARS, Keypair.generate().publicKey);
+    try {
+      await applyFeed();
+      expect.fail("Feed de outro programa deveria ser rejeitado");
+    } catch (err) {
+      expect(err.toString()).to.include("Feed account is not owned by the configured feed program");
+    }
+  });
+});
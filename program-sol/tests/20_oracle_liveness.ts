@@ -0,0 +1,208 @@
+/**
+ * Testes Solengage - Especificação BDD
+ *
+ * Feature: Fallback de Liveness do Oráculo
+ *
+ * Cenário: Influenciador liquida marcos quando o oráculo perde o heartbeat
+ * Given uma campanha ativa com meta de 1000 likes e heartbeat de 2 segundos
+ * When o influenciador tenta abrir o fallback antes do heartbeat expirar
+ * Then a transação falha com "Oracle is live: fallback not available"
+ * When o oráculo fica inativo além do heartbeat e o influenciador abre o fallback
+ * And envia 500 likes co-assinados pela marca
+ * Then 50% do orçamento é pago
+ *
+ * Cenário: Marca só cancela depois do aviso prévio
+ * Given uma campanha ativa com aviso prévio de cancelamento de 3 segundos
+ * When a marca tenta cancelar sem aviso
+ * Then a transação falha com "Cancellation notice period has not elapsed"
+ * When a marca solicita o cancelamento
+ * Then o influenciador abre o fallback e liquida 500 likes co-assinados pelo árbitro
+ * When o prazo de aviso termina
+ * Then a marca cancela e recebe de volta os 50% restantes
+ *
+ * Cenário: Relatório do oráculo fecha o fallback
+ * Given o influenciador abriu o fallback depois de um heartbeat perdido
+ * When o oráculo volta a reportar 300 likes
+ * Then o heartbeat é renovado e métricas co-assinadas falham com "Oracle fallback is not open"
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Solengage } from "../target/types/solengage";
+import { bondOracle, ensureOracleRegistry, registerOracle } from "./utils/oracle_registry";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { getAccount, getOrCreateAssociatedTokenAccount, createMint, TOKEN_PROGRAM_ID, mintTo } from "@solana/spl-token";
+import { expect } from "chai";
+import { nextReport } from "./utils/reports";
+import { termsVersion } from "./utils/terms";
+
+const sleep = (ms: number) => new Promise(resolve => setTimeout(resolve, ms));
+
+describe("Solengage - 20 Oracle Liveness", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.solengage as Program<Solengage>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+
+  let influencer: Keypair, brand: Keypair, oracle: Keypair;
+  let oracleRegistry: PublicKey, oracleEntry: PublicKey;
+  let brandUsdcAccount: PublicKey, influencerUsdcAccount: PublicKey, campaignUsdcAccount: PublicKey;
+  let campaignPda: PublicKey;
+
+  const campaignName = "Oracle Liveness Test";
+  const totalAmount = new anchor.BN(1000 * 1_000_000); // 1000 USDC
+  const targetLikes = new anchor.BN(1000);
+  const heartbeat = 2; // segundos
+  const cancelNotice = 3; // segundos
+
+  const openFallback = () =>
+    program.methods
+      .openOracleFallback()
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey })
+      .signers([influencer])
+      .rpc();
+
+  const submitFallback = (likes: number, cosigner: Keypair) =>
+    program.methods
+      .submitFallbackMetrics([{ kind: { likes: {} }, platform: null, value: new anchor.BN(likes) }])
+      .accounts({ campaign: campaignPda, oracleRegistry, influencer: influencer.publicKey, cosigner: cosigner.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID } as any)
+      .signers([influencer, cosigner])
+      .rpc();
+
+  const cancel = () =>
+    program.methods
+      .cancelCampaign()
+      .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([brand])
+      .rpc();
+
+  const balance = async (account: PublicKey) => (await getAccount(provider.connection, account)).amount.toString();
+
+  beforeEach(async () => {
+    influencer = Keypair.generate();
+    brand = Keypair.generate();
+    oracle = Keypair.generate();
+
+    await Promise.all(
+      [influencer, brand, oracle].map(kp =>
+        provider.connection.requestAirdrop(kp.publicKey, 2 * LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig, "confirmed"))
+      )
+    );
+    oracleRegistry = await ensureOracleRegistry(program, provider);
+    oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+
+    const usdcMint = await createMint(provider.connection, brand, brand.publicKey, null, 6);
+    await bondOracle(program, provider, oracle, oracleEntry, usdcMint, brand);
+    brandUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, brand.publicKey).then(acc => acc.address);
+    influencerUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, influencer, usdcMint, influencer.publicKey).then(acc => acc.address);
+
+    [campaignPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign"), influencer.publicKey.toBuffer(), brand.publicKey.toBuffer(), Buffer.from(campaignName)],
+      program.programId
+    );
+    campaignUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, campaignPda, true).then(acc => acc.address);
+    await mintTo(provider.connection, brand, usdcMint, brandUsdcAccount, brand, totalAmount.toNumber());
+
+    await program.methods
+      .createCampaign(campaignName, "test-nickname", "Brand", "#liveness", targetLikes, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), totalAmount, new anchor.BN(Date.now() / 1000 + 86400))
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey, oracle: oracleEntry, systemProgram: SystemProgram.programId })
+      .signers([influencer])
+      .rpc();
+  });
+
+  const activate = async (oracleHeartbeat: number, cancelNoticePeriod: number) => {
+    await program.methods
+      .setOracleLiveness(new anchor.BN(oracleHeartbeat), new anchor.BN(cancelNoticePeriod))
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey })
+      .signers([influencer])
+      .rpc();
+
+    await program.methods
+      .brandPayCampaign(await termsVersion(program, campaignPda))
+      .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([brand])
+      .rpc();
+  };
+
+  it("Scenario: Influencer settles earned tranches after a missed heartbeat", async () => {
+    await activate(heartbeat, 0);
+
+    try {
+      await openFallback();
+      expect.fail("Fallback não deveria abrir com o oráculo ativo");
+    } catch (err) {
+      expect(err.toString()).to.include("Oracle is live: fallback not available");
+    }
+
+    await sleep((heartbeat + 1) * 1000);
+    await openFallback();
+    await submitFallback(500, brand);
+
+    expect(await balance(influencerUsdcAccount)).to.equal(totalAmount.divn(2).toString());
+    console.log("   ✓ Heartbeat perdido: 500 likes co-assinados pela marca, 50% pago");
+  });
+
+  it("Scenario: Brand cancels only after the notice period", async () => {
+    await activate(0, cancelNotice);
+
+    try {
+      await cancel();
+      expect.fail("Cancelamento sem aviso prévio deveria falhar");
+    } catch (err) {
+      expect(err.toString()).to.include("Cancellation notice period has not elapsed");
+    }
+
+    await program.methods
+      .requestCancellation()
+      .accounts({ campaign: campaignPda, brand: brand.publicKey })
+      .signers([brand])
+      .rpc();
+
+    // Aviso de cancelamento abre o fallback: o influenciador liquida o que já conquistou
+    const arbiter = Keypair.generate();
+    await program.methods
+      .setArbiter(arbiter.publicKey)
+      .accounts({ oracleRegistry, admin: provider.wallet.publicKey } as any)
+      .rpc();
+    await openFallback();
+    await submitFallback(500, arbiter);
+    expect(await balance(influencerUsdcAccount)).to.equal(totalAmount.divn(2).toString());
+
+    try {
+      await cancel();
+      expect.fail("Cancelamento durante o aviso prévio deveria falhar");
+    } catch (err) {
+      expect(err.toString()).to.include("Cancellation notice period has not elapsed");
+    }
+
+    await sleep((cancelNotice + 1) * 1000);
+    await cancel();
+
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.status).to.deep.equal({ cancelled: {} });
+    expect(await balance(brandUsdcAccount)).to.equal(totalAmount.divn(2).toString());
+    console.log("   ✓ Aviso prévio cumprido: 50% liquidado ao influenciador, 50% reembolsado à marca");
+  });
+
+  it("Scenario: An oracle report closes the fallback", async () => {
+    await activate(heartbeat, 0);
+    await sleep((heartbeat + 1) * 1000);
+    await openFallback();
+
+    await program.methods
+      .updateCampaignMetrics(new anchor.BN(300), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), ...nextReport())
+      .accounts({ campaign: campaignPda, oracle: oracleEntry, oracleAuthority: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([oracle])
+      .rpc();
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.fallbackOpenedAt.toNumber()).to.equal(0);
+    expect(campaign.lastOracleReportAt.toNumber()).to.be.greaterThan(campaign.activatedAt.toNumber());
+
+    try {
+      await submitFallback(500, brand);
+      expect.fail("Fallback deveria estar fechado após o relatório do oráculo");
+    } catch (err) {
+      expect(err.toString()).to.include("Oracle fallback is not open");
+    }
+    console.log("   ✓ Oráculo voltou a reportar: fallback fechado e heartbeat renovado");
+  });
+});
@@ -0,0 +1,154 @@
+/**
+ * Testes Solengage - Especificação BDD
+ *
+ * Feature: Ofertas da Marca
+ *
+ * Cenário: Oferta com depósito é ativada pelo aceite do influenciador
+ * Given a marca oferece uma campanha de 1000 USDC com depósito antecipado
+ * Then a campanha está em Offered e o cofre tem 1000 USDC
+ * When o influenciador aceita a oferta
+ * Then a campanha está Active e o oráculo já pode reportar métricas
+ *
+ * Cenário: Oferta sem depósito é aceita para Draft e financiada depois
+ * Given a marca oferece uma campanha sem depósito
+ * When o influenciador aceita a oferta
+ * Then a campanha está em Draft
+ * When a marca chama `brand_pay_campaign`
+ * Then a campanha está Active
+ *
+ * Cenário: Recusa reembolsa a marca e fecha a conta
+ * Given uma oferta com depósito
+ * When o influenciador recusa a oferta
+ * Then a marca recebe os 1000 USDC de volta e a conta da campanha é fechada
+ *
+ * Cenário: Somente o influenciador responde à oferta
+ * When a marca tenta aceitar a própria oferta
+ * Then a transação falha com "You are not the influencer of this campaign"
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Solengage } from "../target/types/solengage";
+import { bondOracle, registerOracle } from "./utils/oracle_registry";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { getAccount, getOrCreateAssociatedTokenAccount, createMint, TOKEN_PROGRAM_ID, mintTo } from "@solana/spl-token";
+import { expect } from "chai";
+import { nextReport } from "./utils/reports";
+import { termsVersion } from "./utils/terms";
+
+describe("Solengage - 21 Brand Offers", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.solengage as Program<Solengage>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+
+  let influencer: Keypair, brand: Keypair, oracle: Keypair;
+  let oracleEntry: PublicKey, oracleBond: PublicKey, bondVault: PublicKey;
+  let brandUsdcAccount: PublicKey, influencerUsdcAccount: PublicKey, campaignUsdcAccount: PublicKey;
+  let campaignPda: PublicKey;
+
+  const campaignName = "Brand Offer Test";
+  const totalAmount = new anchor.BN(1000 * 1_000_000); // 1000 USDC
+  const targetLikes = new anchor.BN(1000);
+
+  const createOffer = (escrow: boolean) =>
+    program.methods
+      .createOffer(campaignName, "test-nickname", "Brand", "#offer", targetLikes, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), totalAmount, new anchor.BN(Date.now() / 1000 + 86400), escrow)
+      .accounts({ campaign: campaignPda, brand: brand.publicKey, influencer: influencer.publicKey, oracle: oracleEntry, brandUsdcAccount, campaignUsdcAccount, oracleBond, bondVault, tokenProgram: TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId } as any)
+      .signers([brand])
+      .rpc();
+
+  const acceptOffer = (signer: Keypair) =>
+    program.methods
+      .acceptOffer()
+      .accounts({ campaign: campaignPda, influencer: signer.publicKey } as any)
+      .signers([signer])
+      .rpc();
+
+  beforeEach(async () => {
+    influencer = Keypair.generate();
+    brand = Keypair.generate();
+    oracle = Keypair.generate();
+
+    await Promise.all(
+      [influencer, brand, oracle].map(kp =>
+        provider.connection.requestAirdrop(kp.publicKey, 2 * LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig, "confirmed"))
+      )
+    );
+    oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+
+    const usdcMint = await createMint(provider.connection, brand, brand.publicKey, null, 6);
+    ({ oracleBond, bondVault } = await bondOracle(program, provider, oracle, oracleEntry, usdcMint, brand));
+    brandUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, brand.publicKey).then(acc => acc.address);
+    influencerUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, influencer, usdcMint, influencer.publicKey).then(acc => acc.address);
+
+    [campaignPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign"), influencer.publicKey.toBuffer(), brand.publicKey.toBuffer(), Buffer.from(campaignName)],
+      program.programId
+    );
+    campaignUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, campaignPda, true).then(acc => acc.address);
+    await mintTo(provider.connection, brand, usdcMint, brandUsdcAccount, brand, totalAmount.toNumber());
+  });
+
+  it("Scenario: Escrowed offer becomes Active on acceptance", async () => {
+    await createOffer(true);
+    let campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.status).to.deep.equal({ offered: {} });
+    expect(campaign.offerEscrowed).to.be.true;
+    let vaultBalance = (await getAccount(provider.connection, campaignUsdcAccount)).amount;
+    expect(vaultBalance.toString()).to.equal(totalAmount.toString());
+    console.log("   ✓ Oferta criada com 1000 USDC no cofre");
+
+    await acceptOffer(influencer);
+    campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.status).to.deep.equal({ active: {} });
+
+    await program.methods
+      .updateCampaignMetrics(new anchor.BN(500), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), ...nextReport())
+      .accounts({ campaign: campaignPda, oracle: oracleEntry, oracleAuthority: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([oracle])
+      .rpc();
+    const influencerBalance = (await getAccount(provider.connection, influencerUsdcAccount)).amount;
+    expect(influencerBalance.toString()).to.equal(totalAmount.divn(2).toString());
+    console.log("   ✓ Oferta aceita: campanha ativa e 50% pago");
+  });
+
+  it("Scenario: Unescrowed offer is accepted into Draft and funded later", async () => {
+    await createOffer(false);
+    await acceptOffer(influencer);
+    let campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.status).to.deep.equal({ draft: {} });
+
+    await program.methods
+      .brandPayCampaign(await termsVersion(program, campaignPda))
+      .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([brand])
+      .rpc();
+    campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.status).to.deep.equal({ active: {} });
+    console.log("   ✓ Oferta aceita em Draft e financiada pela marca");
+  });
+
+  it("Scenario: Rejection refunds the brand and closes the account", async () => {
+    await createOffer(true);
+    await program.methods
+      .rejectOffer()
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID } as any)
+      .signers([influencer])
+      .rpc();
+
+    const brandBalance = (await getAccount(provider.connection, brandUsdcAccount)).amount;
+    expect(brandBalance.toString()).to.equal(totalAmount.toString());
+    expect(await program.account.campaign.fetchNullable(campaignPda)).to.be.null;
+    console.log("   ✓ Oferta recusada: depósito devolvido e conta fechada");
+  });
+
+  it("Scenario: Only the influencer answers the offer", async () => {
+    await createOffer(false);
+    try {
+      await acceptOffer(brand);
+      expect.fail("A marca não deveria aceitar a própria oferta");
+    } catch (err) {
+      expect(err.toString()).to.include("You are not the influencer of this campaign");
+    }
+  });
+});
@@ -0,0 +1,203 @@
+/**
+ * Testes Solengage - Especificação BDD
+ *
+ * Feature: Edição de Termos em Rascunho
+ *
+ * Cenário: Influenciador corrige os termos e a marca financia a versão revisada
+ * Given uma campanha em Draft com hashtag "#typo", 1000 likes e 1000 USDC
+ * When o influenciador atualiza para "#fixed", 5000 views e 800 USDC
+ * Then os campos são atualizados e a versão dos termos passa a 1
+ * When a marca paga informando a versão 1
+ * Then a campanha está Active e o cofre recebe 800 USDC
+ *
+ * Cenário: Marca não financia termos alterados depois da revisão
+ * Given a marca revisou a versão 0 dos termos
+ * When o influenciador altera as metas (set_metric_targets)
+ * And a marca paga informando a versão 0
+ * Then a transação falha com "Campaign terms changed since the reviewed version"
+ *
+ * Cenário: Atualizar o rascunho mantém metas extras e pesos próprios
+ * Given metas de 1000 likes com peso 3 e 200 salvamentos no Instagram com peso 1
+ * When o influenciador atualiza o rascunho para 5000 views
+ * Then likes mantém o peso 3, views passa a meta 5000 e a meta de salvamentos continua
+ *
+ * Cenário: Metas não podem remover a métrica do feed configurado
+ * Given um feed configurado para salvamentos no Instagram
+ * When o influenciador redefine as metas só com likes
+ * Then a transação falha com "No metric target for the reported value"
+ *
+ * Cenário: Termos inválidos são rejeitados como na criação
+ * When o influenciador atualiza com prazo no passado
+ * Then a transação falha com "Deadline must be in the future"
+ *
+ * Cenário: Campanha ativa não pode ser editada
+ * Given uma campanha já financiada
+ * When o influenciador chama `update_draft`
+ * Then a transação falha com "Campaign is not in draft status"
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Solengage } from "../target/types/solengage";
+import { bondOracle, registerOracle } from "./utils/oracle_registry";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { getAccount, getOrCreateAssociatedTokenAccount, createMint, TOKEN_PROGRAM_ID, mintTo } from "@solana/spl-token";
+import { expect } from "chai";
+import { baseMetric } from "./utils/metrics";
+import { termsVersion } from "./utils/terms";
+
+describe("Solengage - 22 Draft Updates", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.solengage as Program<Solengage>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+
+  let influencer: Keypair, brand: Keypair, oracle: Keypair;
+  let oracleEntry: PublicKey;
+  let brandUsdcAccount: PublicKey, campaignUsdcAccount: PublicKey;
+  let campaignPda: PublicKey;
+
+  const campaignName = "Draft Updates Test";
+  const totalAmount = new anchor.BN(1000 * 1_000_000); // 1000 USDC
+  const updatedAmount = new anchor.BN(800 * 1_000_000); // 800 USDC
+  const zero = new anchor.BN(0);
+
+  const updateDraft = (hashtag: string, targetViews: number, amount: anchor.BN, deadline: number) =>
+    program.methods
+      .updateDraft("test-nickname", "Brand", hashtag, zero, zero, new anchor.BN(targetViews), zero, amount, new anchor.BN(deadline))
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey } as any)
+      .signers([influencer])
+      .rpc();
+
+  const setTargets = (targets: any[]) =>
+    program.methods
+      .setMetricTargets(targets)
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey })
+      .signers([influencer])
+      .rpc();
+
+  const likesTarget = { kind: { likes: {} }, platform: null, target: new anchor.BN(1000), weight: new anchor.BN(3) };
+  const savesTarget = { kind: { saves: {} }, platform: { instagram: {} }, target: new anchor.BN(200), weight: new anchor.BN(1) };
+
+  const pay = (version: number) =>
+    program.methods
+      .brandPayCampaign(version)
+      .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([brand])
+      .rpc();
+
+  beforeEach(async () => {
+    influencer = Keypair.generate();
+    brand = Keypair.generate();
+    oracle = Keypair.generate();
+
+    await Promise.all(
+      [influencer, brand, oracle].map(kp =>
+        provider.connection.requestAirdrop(kp.publicKey, 2 * LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig, "confirmed"))
+      )
+    );
+    oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+
+    const usdcMint = await createMint(provider.connection, brand, brand.publicKey, null, 6);
+    await bondOracle(program, provider, oracle, oracleEntry, usdcMint, brand);
+    brandUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, brand.publicKey).then(acc => acc.address);
+
+    [campaignPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign"), influencer.publicKey.toBuffer(), brand.publicKey.toBuffer(), Buffer.from(campaignName)],
+      program.programId
+    );
+    campaignUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, campaignPda, true).then(acc => acc.address);
+    await mintTo(provider.connection, brand, usdcMint, brandUsdcAccount, brand, totalAmount.toNumber());
+
+    await program.methods
+      .createCampaign(campaignName, "test-nickname", "Brand", "#typo", new anchor.BN(1000), zero, zero, zero, totalAmount, new anchor.BN(Date.now() / 1000 + 86400))
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey, oracle: oracleEntry, systemProgram: SystemProgram.programId })
+      .signers([influencer])
+      .rpc();
+  });
+
+  it("Scenario: Influencer fixes the terms and the brand funds the reviewed version", async () => {
+    await updateDraft("#fixed", 5000, updatedAmount, Date.now() / 1000 + 2 * 86400);
+
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.hashtag).to.equal("#fixed");
+    expect(baseMetric(campaign, "likes").target.toNumber()).to.equal(0);
+    expect(baseMetric(campaign, "views").target.toNumber()).to.equal(5000);
+    expect(campaign.amountUsdc.toString()).to.equal(updatedAmount.toString());
+    expect(campaign.termsVersion).to.equal(1);
+    console.log("   ✓ Termos atualizados: versão 1");
+
+    await pay(1);
+    const vaultBalance = (await getAccount(provider.connection, campaignUsdcAccount)).amount;
+    expect(vaultBalance.toString()).to.equal(updatedAmount.toString());
+    console.log("   ✓ Marca financiou 800 USDC da versão revisada");
+  });
+
+  it("Scenario: Brand cannot fund terms changed after its review", async () => {
+    const reviewed = await termsVersion(program, campaignPda);
+    await program.methods
+      .setMetricTargets([{ kind: { likes: {} }, platform: null, target: new anchor.BN(2000), weight: new anchor.BN(1) }])
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey })
+      .signers([influencer])
+      .rpc();
+
+    try {
+      await pay(reviewed);
+      expect.fail("Pagamento de versão desatualizada deveria falhar");
+    } catch (err) {
+      expect(err.toString()).to.include("Campaign terms changed since the reviewed version");
+    }
+  });
+
+  it("Scenario: Updating the draft keeps extra targets and explicit weights", async () => {
+    await setTargets([likesTarget, savesTarget]);
+    await updateDraft("#fixed", 5000, updatedAmount, Date.now() / 1000 + 2 * 86400);
+
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    expect(baseMetric(campaign, "likes").target.toNumber()).to.equal(0);
+    expect(baseMetric(campaign, "likes").weight.toNumber()).to.equal(3);
+    expect(baseMetric(campaign, "views").target.toNumber()).to.equal(5000);
+    expect(baseMetric(campaign, "views").weight.toNumber()).to.equal(5000);
+    expect(campaign.metrics.length).to.equal(5);
+    expect(campaign.metrics[4].kind).to.deep.equal({ saves: {} });
+    expect(campaign.metrics[4].target.toNumber()).to.equal(200);
+    console.log("   ✓ Meta de salvamentos e peso de likes mantidos");
+  });
+
+  it("Scenario: Targets cannot drop the configured feed's metric", async () => {
+    await setTargets([likesTarget, savesTarget]);
+    await program.methods
+      .setMetricFeed({
+        feed: Keypair.generate().publicKey, ownerProgram: Keypair.generate().publicKey, authority: oracle.publicKey,
+        kind: { saves: {} }, platform: { instagram: {} }, maxAge: new anchor.BN(3600),
+      })
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey })
+      .signers([influencer])
+      .rpc();
+
+    try {
+      await setTargets([likesTarget]);
+      expect.fail("Metas sem a métrica do feed deveriam ser rejeitadas");
+    } catch (err) {
+      expect(err.toString()).to.include("No metric target for the reported value");
+    }
+  });
+
+  it("Scenario: Rejects invalid terms like create_campaign", async () => {
+    try {
+      await updateDraft("#fixed", 5000, updatedAmount, Date.now() / 1000 - 60);
+      expect.fail("Prazo no passado deveria ser rejeitado");
+    } catch (err) {
+      expect(err.toString()).to.include("Deadline must be in the future");
+    }
+  });
+
+  it("Scenario: Active campaigns cannot be edited", async () => {
+    await pay(0);
+    try {
+      await updateDraft("#fixed", 5000, updatedAmount, Date.now() / 1000 + 2 * 86400);
+      expect.fail("Campanha ativa não deveria ser editada");
+    } catch (err) {
+      expect(err.toString()).to.include("Campaign is not in draft status");
+    }
+  });
+});
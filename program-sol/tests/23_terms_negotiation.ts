@@ -0,0 +1,179 @@
+/**
+ * Testes Solengage - Especificação BDD
+ *
+ * Feature: Negociação de Termos
+ *
+ * Cenário: Contraproposta aceita é financiada
+ * Given uma campanha em Draft de 1000 USDC e 1000 likes
+ * When a marca propõe 800 USDC e 2000 likes
+ * Then a campanha não pode ser financiada ("A terms proposal is pending acceptance")
+ * When o influenciador contrapropõe 900 USDC e 1500 likes
+ * And a marca aceita a versão da contraproposta
+ * Then a campanha passa a ter 900 USDC e 1500 likes
+ * And a marca financia 900 USDC com essa versão
+ *
+ * Cenário: Proposta só é aceita pela outra parte
+ * When a marca propõe novos termos e tenta aceitá-los
+ * Then a transação falha com "Terms must be accepted by the other party"
+ *
+ * Cenário: Aceite de versão substituída é rejeitado
+ * Given a marca propõe termos (versão 1) e o influenciador contrapropõe (versão 2)
+ * When a marca aceita a versão 1
+ * Then a transação falha com "Campaign terms changed since the reviewed version"
+ *
+ * Cenário: Aceite mantém metas extras
+ * Given metas de 1000 likes e 200 salvamentos no Instagram
+ * When a marca propõe 2000 likes e o influenciador aceita
+ * Then a meta de likes passa a 2000 e a meta de salvamentos continua
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Solengage } from "../target/types/solengage";
+import { bondOracle, registerOracle } from "./utils/oracle_registry";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { getAccount, getOrCreateAssociatedTokenAccount, createMint, TOKEN_PROGRAM_ID, mintTo } from "@solana/spl-token";
+import { expect } from "chai";
+import { baseMetric } from "./utils/metrics";
+import { termsVersion } from "./utils/terms";
+
+describe("Solengage - 23 Terms Negotiation", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.solengage as Program<Solengage>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+
+  let influencer: Keypair, brand: Keypair, oracle: Keypair;
+  let oracleEntry: PublicKey;
+  let brandUsdcAccount: PublicKey, campaignUsdcAccount: PublicKey;
+  let campaignPda: PublicKey;
+
+  const campaignName = "Terms Negotiation Test";
+  const totalAmount = new anchor.BN(1000 * 1_000_000); // 1000 USDC
+  const usdc = (amount: number) => new anchor.BN(amount * 1_000_000);
+  const deadline = () => new anchor.BN(Date.now() / 1000 + 86400);
+  const likes = (value: number) => ({ likes: new anchor.BN(value), comments: new anchor.BN(0), views: new anchor.BN(0), shares: new anchor.BN(0) });
+
+  const propose = (proposer: Keypair, targetLikes: number, amount: anchor.BN) =>
+    program.methods
+      .proposeTerms(likes(targetLikes), amount, deadline())
+      .accounts({ campaign: campaignPda, proposer: proposer.publicKey } as any)
+      .signers([proposer])
+      .rpc();
+
+  const accept = (acceptor: Keypair, version: number) =>
+    program.methods
+      .acceptTerms(version)
+      .accounts({ campaign: campaignPda, acceptor: acceptor.publicKey } as any)
+      .signers([acceptor])
+      .rpc();
+
+  const pay = (version: number) =>
+    program.methods
+      .brandPayCampaign(version)
+      .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([brand])
+      .rpc();
+
+  beforeEach(async () => {
+    influencer = Keypair.generate();
+    brand = Keypair.generate();
+    oracle = Keypair.generate();
+
+    await Promise.all(
+      [influencer, brand, oracle].map(kp =>
+        provider.connection.requestAirdrop(kp.publicKey, 2 * LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig, "confirmed"))
+      )
+    );
+    oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+
+    const usdcMint = await createMint(provider.connection, brand, brand.publicKey, null, 6);
+    await bondOracle(program, provider, oracle, oracleEntry, usdcMint, brand);
+    brandUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, brand.publicKey).then(acc => acc.address);
+
+    [campaignPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign"), influencer.publicKey.toBuffer(), brand.publicKey.toBuffer(), Buffer.from(campaignName)],
+      program.programId
+    );
+    campaignUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, campaignPda, true).then(acc => acc.address);
+    await mintTo(provider.connection, brand, usdcMint, brandUsdcAccount, brand, totalAmount.toNumber());
+
+    await program.methods
+      .createCampaign(campaignName, "test-nickname", "Brand", "#terms", new anchor.BN(1000), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), totalAmount, deadline())
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey, oracle: oracleEntry, systemProgram: SystemProgram.programId })
+      .signers([influencer])
+      .rpc();
+  });
+
+  it("Scenario: An accepted counter-proposal is funded", async () => {
+    await propose(brand, 2000, usdc(800));
+    try {
+      await pay(await termsVersion(program, campaignPda));
+      expect.fail("Campanha com proposta pendente não deveria ser financiada");
+    } catch (err) {
+      expect(err.toString()).to.include("A terms proposal is pending acceptance");
+    }
+    console.log("   ✓ Proposta da marca pendente: financiamento bloqueado");
+
+    await propose(influencer, 1500, usdc(900));
+    let campaign = await program.account.campaign.fetch(campaignPda);
+    const counterVersion = campaign.pendingTerms.version;
+    expect(counterVersion).to.equal(2);
+    expect(campaign.pendingTerms.proposer.toBase58()).to.equal(influencer.publicKey.toBase58());
+
+    await accept(brand, counterVersion);
+    campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.pendingTerms).to.be.null;
+    expect(campaign.amountUsdc.toString()).to.equal(usdc(900).toString());
+    expect(baseMetric(campaign, "likes").target.toNumber()).to.equal(1500);
+    console.log("   ✓ Contraproposta do influenciador aceita pela marca");
+
+    await pay(counterVersion);
+    const vaultBalance = (await getAccount(provider.connection, campaignUsdcAccount)).amount;
+    expect(vaultBalance.toString()).to.equal(usdc(900).toString());
+    console.log("   ✓ Marca financiou 900 USDC dos termos aceitos");
+  });
+
+  it("Scenario: Only the other party accepts a proposal", async () => {
+    await propose(brand, 2000, usdc(800));
+    try {
+      await accept(brand, await termsVersion(program, campaignPda));
+      expect.fail("A marca não deveria aceitar a própria proposta");
+    } catch (err) {
+      expect(err.toString()).to.include("Terms must be accepted by the other party");
+    }
+  });
+
+  it("Scenario: Rejects acceptance of a superseded version", async () => {
+    await propose(brand, 2000, usdc(800));
+    const brandVersion = await termsVersion(program, campaignPda);
+    await propose(influencer, 1500, usdc(900));
+
+    try {
+      await accept(brand, brandVersion);
+      expect.fail("Versão substituída não deveria ser aceita");
+    } catch (err) {
+      expect(err.toString()).to.include("Campaign terms changed since the reviewed version");
+    }
+  });
+
+  it("Scenario: Acceptance keeps the extra targets", async () => {
+    await program.methods
+      .setMetricTargets([
+        { kind: { likes: {} }, platform: null, target: new anchor.BN(1000), weight: new anchor.BN(1000) },
+        { kind: { saves: {} }, platform: { instagram: {} }, target: new anchor.BN(200), weight: new anchor.BN(1) },
+      ])
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey })
+      .signers([influencer])
+      .rpc();
+    await propose(brand, 2000, usdc(800));
+    await accept(influencer, await termsVersion(program, campaignPda));
+
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    expect(baseMetric(campaign, "likes").target.toNumber()).to.equal(2000);
+    expect(baseMetric(campaign, "likes").weight.toNumber()).to.equal(2000);
+    expect(campaign.metrics.length).to.equal(5);
+    expect(campaign.metrics[4].kind).to.deep.equal({ saves: {} });
+    expect(campaign.metrics[4].target.toNumber()).to.equal(200);
+    console.log("   ✓ Proposta aceita: 2000 likes e meta de salvamentos mantida");
+  });
+});
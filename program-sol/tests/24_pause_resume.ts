@@ -0,0 +1,192 @@
+/**
+ * Testes Solengage - Especificação BDD
+ *
+ * Feature: Pausar e Retomar Campanhas
+ *
+ * Cenário: Campanha pausada rejeita métricas e o prazo é estendido na retomada
+ * Given uma campanha ativa com até 3 segundos de pausa acordados
+ * When a marca pausa a campanha
+ * Then o oráculo não consegue atualizar métricas ("Campaign is not active")
+ * When a marca retoma a campanha
+ * Then o prazo é estendido pelo tempo pausado e o oráculo volta a reportar
+ *
+ * Cenário: Extensão do prazo é limitada ao tempo de pausa acordado
+ * Given a campanha ficou pausada por mais de 3 segundos
+ * When o influenciador retoma a campanha
+ * Then o prazo é estendido em exatamente 3 segundos
+ *
+ * Cenário: Influenciador não retoma antes de esgotar o tempo de pausa
+ * When o influenciador tenta retomar logo após a pausa
+ * Then a transação falha com "Pause budget has not been used up yet"
+ *
+ * Cenário: Campanha sem política de pausa não pode ser pausada
+ * Given uma campanha ativa sem tempo de pausa acordado
+ * When a marca tenta pausar
+ * Then a transação falha com "Campaign has no pause time left"
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Solengage } from "../target/types/solengage";
+import { bondOracle, registerOracle } from "./utils/oracle_registry";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { getAccount, getOrCreateAssociatedTokenAccount, createMint, TOKEN_PROGRAM_ID, mintTo } from "@solana/spl-token";
+import { expect } from "chai";
+import { nextReport } from "./utils/reports";
+import { termsVersion } from "./utils/terms";
+
+const sleep = (ms: number) => new Promise(resolve => setTimeout(resolve, ms));
+
+describe("Solengage - 24 Pause and Resume", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.solengage as Program<Solengage>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+
+  let influencer: Keypair, brand: Keypair, oracle: Keypair;
+  let oracleEntry: PublicKey;
+  let brandUsdcAccount: PublicKey, influencerUsdcAccount: PublicKey, campaignUsdcAccount: PublicKey;
+  let campaignPda: PublicKey;
+
+  const campaignName = "Pause Resume Test";
+  const totalAmount = new anchor.BN(1000 * 1_000_000); // 1000 USDC
+  const maxPause = 3;
+
+  const setup = async (maxPauseDuration: number) => {
+    await program.methods
+      .createCampaign(campaignName, "test-nickname", "Brand", "#pause", new anchor.BN(1000), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), totalAmount, new anchor.BN(Date.now() / 1000 + 86400))
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey, oracle: oracleEntry, systemProgram: SystemProgram.programId })
+      .signers([influencer])
+      .rpc();
+
+    if (maxPauseDuration > 0) {
+      await program.methods
+        .setPausePolicy(new anchor.BN(maxPauseDuration))
+        .accounts({ campaign: campaignPda, influencer: influencer.publicKey } as any)
+        .signers([influencer])
+        .rpc();
+    }
+
+    await program.methods
+      .brandPayCampaign(await termsVersion(program, campaignPda))
+      .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([brand])
+      .rpc();
+  };
+
+  const pause = () =>
+    program.methods
+      .pauseCampaign()
+      .accounts({ campaign: campaignPda, brand: brand.publicKey } as any)
+      .signers([brand])
+      .rpc();
+
+  const resume = (authority: Keypair) =>
+    program.methods
+      .resumeCampaign()
+      .accounts({ campaign: campaignPda, authority: authority.publicKey } as any)
+      .signers([authority])
+      .rpc();
+
+  const updateMetrics = (likes: number) =>
+    program.methods
+      .updateCampaignMetrics(new anchor.BN(likes), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), ...nextReport())
+      .accounts({ campaign: campaignPda, oracle: oracleEntry, oracleAuthority: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([oracle])
+      .rpc();
+
+  beforeEach(async () => {
+    influencer = Keypair.generate();
+    brand = Keypair.generate();
+    oracle = Keypair.generate();
+
+    await Promise.all(
+      [influencer, brand, oracle].map(kp =>
+        provider.connection.requestAirdrop(kp.publicKey, 2 * LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig, "confirmed"))
+      )
+    );
+    oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+
+    const usdcMint = await createMint(provider.connection, brand, brand.publicKey, null, 6);
+    await bondOracle(program, provider, oracle, oracleEntry, usdcMint, brand);
+    brandUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, brand.publicKey).then(acc => acc.address);
+    influencerUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, influencer, usdcMint, influencer.publicKey).then(acc => acc.address);
+
+    [campaignPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign"), influencer.publicKey.toBuffer(), brand.publicKey.toBuffer(), Buffer.from(campaignName)],
+      program.programId
+    );
+    campaignUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, campaignPda, true).then(acc => acc.address);
+    await mintTo(provider.connection, brand, usdcMint, brandUsdcAccount, brand, totalAmount.toNumber());
+  });
+
+  it("Scenario: Paused campaigns reject metrics and the deadline is extended on resume", async () => {
+    await setup(maxPause);
+    const deadlineBefore = (await program.account.campaign.fetch(campaignPda)).deadline.toNumber();
+
+    await pause();
+    let campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.status).to.deep.equal({ paused: {} });
+
+    try {
+      await updateMetrics(500);
+      expect.fail("Campanha pausada não deveria aceitar métricas");
+    } catch (err) {
+      expect(err.toString()).to.include("Campaign is not active");
+    }
+    console.log("   ✓ Métricas rejeitadas durante a pausa");
+
+    await sleep(1000);
+    await resume(brand);
+    campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.status).to.deep.equal({ active: {} });
+    const extension = campaign.deadline.toNumber() - deadlineBefore;
+    expect(extension).to.be.at.least(1).and.at.most(maxPause);
+    expect(campaign.totalPaused.toNumber()).to.equal(extension);
+
+    await updateMetrics(500);
+    const influencerBalance = (await getAccount(provider.connection, influencerUsdcAccount)).amount;
+    expect(influencerBalance.toString()).to.equal(totalAmount.divn(2).toString());
+    console.log(`   ✓ Retomada: prazo estendido em ${extension}s e métricas aceitas`);
+  });
+
+  it("Scenario: The extension is capped by the agreed pause time", async () => {
+    await setup(maxPause);
+    const deadlineBefore = (await program.account.campaign.fetch(campaignPda)).deadline.toNumber();
+
+    await pause();
+    await sleep((maxPause + 2) * 1000);
+    await resume(influencer);
+
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.deadline.toNumber() - deadlineBefore).to.equal(maxPause);
+    console.log("   ✓ Prazo estendido apenas pelo tempo de pausa acordado");
+
+    try {
+      await pause();
+      expect.fail("Tempo de pausa esgotado não deveria permitir nova pausa");
+    } catch (err) {
+      expect(err.toString()).to.include("Campaign has no pause time left");
+    }
+  });
+
+  it("Scenario: The influencer cannot resume before the pause time is used up", async () => {
+    await setup(maxPause);
+    await pause();
+    try {
+      await resume(influencer);
+      expect.fail("Influenciador não deveria retomar antes do fim da pausa");
+    } catch (err) {
+      expect(err.toString()).to.include("Pause budget has not been used up yet");
+    }
+  });
+
+  it("Scenario: Campaigns without a pause policy cannot be paused", async () => {
+    await setup(0);
+    try {
+      await pause();
+      expect.fail("Campanha sem política de pausa não deveria ser pausada");
+    } catch (err) {
+      expect(err.toString()).to.include("Campaign has no pause time left");
+    }
+  });
+});
@@ -0,0 +1,183 @@
+/**
+ * Testes Solengage - Especificação BDD
+ *
+ * Feature: Aditivos de Campanha
+ *
+ * Cenário: Metas reduzidas e prazo estendido pagam os marcos atingidos
+ * Given uma campanha ativa de 1000 USDC com meta de 1000 likes e 300 likes reportados (300 USDC pagos)
+ * When marca e influenciador reduzem a meta para 500 likes e estendem o prazo em 7 dias
+ * Then o progresso passa a 60% e o influenciador recebe mais 300 USDC (total 600)
+ * And o prazo é estendido
+ *
+ * Cenário: Aporte no orçamento paga apenas a diferença dos marcos já atingidos
+ * Given 500 likes reportados (500 USDC pagos)
+ * When a marca aporta mais 1000 USDC via aditivo
+ * Then o influenciador recebe 500 USDC (50% de 2000 menos o já pago)
+ * When o oráculo reporta 600 likes
+ * Then o influenciador recebe apenas mais 200 USDC (total 1200)
+ *
+ * Cenário: Aditivo exige a assinatura das duas partes
+ * When a marca envia o aditivo sem a assinatura do influenciador
+ * Then a transação falha por assinatura ausente
+ *
+ * Cenário: Metas maiores não desfazem marcos pagos
+ * Given 500 likes reportados (5 marcos pagos)
+ * When as partes elevam a meta para 2000 likes
+ * Then a transação falha com "Amended targets cannot undo milestones already paid"
+ *
+ * Cenário: Aditivo que leva a 100% conclui a campanha sem a conta da marca
+ * Given 300 likes reportados (300 USDC pagos)
+ * When as partes reduzem a meta para 300 likes sem informar a conta USDC da marca
+ * Then a campanha fica `Completed` com `completed_at` registrado e 1000 USDC pagos
+ * And um aporte sem a conta da marca falha com "Brand token account is required to top up the budget"
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Solengage } from "../target/types/solengage";
+import { bondOracle, registerOracle } from "./utils/oracle_registry";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { getAccount, getOrCreateAssociatedTokenAccount, createMint, TOKEN_PROGRAM_ID, mintTo } from "@solana/spl-token";
+import { expect } from "chai";
+import { nextReport } from "./utils/reports";
+import { termsVersion } from "./utils/terms";
+
+describe("Solengage - 25 Campaign Amendments", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.solengage as Program<Solengage>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+
+  let influencer: Keypair, brand: Keypair, oracle: Keypair;
+  let oracleEntry: PublicKey;
+  let brandUsdcAccount: PublicKey, influencerUsdcAccount: PublicKey, campaignUsdcAccount: PublicKey;
+  let campaignPda: PublicKey;
+
+  const campaignName = "Campaign Amendments Test";
+  const usdc = (amount: number) => new anchor.BN(amount * 1_000_000);
+  const totalAmount = usdc(1000);
+  const likesTarget = (value: number) => [{ kind: { likes: {} }, platform: null, target: new anchor.BN(value), weight: new anchor.BN(1) }];
+
+  const amend = (deadline: anchor.BN | null, targets: any[] | null, topUp: anchor.BN, signers: Keypair[] = [brand, influencer], withBrandAccount = true) =>
+    program.methods
+      .amendCampaign(deadline, targets, topUp)
+      .accounts({
+        campaign: campaignPda, brand: brand.publicKey, influencer: influencer.publicKey, brandUsdcAccount: withBrandAccount ? brandUsdcAccount : null,
+        campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID,
+      } as any)
+      .signers(signers)
+      .rpc();
+
+  const updateMetrics = (likes: number) =>
+    program.methods
+      .updateCampaignMetrics(new anchor.BN(likes), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), ...nextReport())
+      .accounts({ campaign: campaignPda, oracle: oracleEntry, oracleAuthority: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([oracle])
+      .rpc();
+
+  const influencerBalance = async () => (await getAccount(provider.connection, influencerUsdcAccount)).amount.toString();
+
+  beforeEach(async () => {
+    influencer = Keypair.generate();
+    brand = Keypair.generate();
+    oracle = Keypair.generate();
+
+    await Promise.all(
+      [influencer, brand, oracle].map(kp =>
+        provider.connection.requestAirdrop(kp.publicKey, 2 * LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig, "confirmed"))
+      )
+    );
+    oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+
+    const usdcMint = await createMint(provider.connection, brand, brand.publicKey, null, 6);
+    await bondOracle(program, provider, oracle, oracleEntry, usdcMint, brand);
+    brandUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, brand.publicKey).then(acc => acc.address);
+    influencerUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, influencer, usdcMint, influencer.publicKey).then(acc => acc.address);
+
+    [campaignPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign"), influencer.publicKey.toBuffer(), brand.publicKey.toBuffer(), Buffer.from(campaignName)],
+      program.programId
+    );
+    campaignUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, campaignPda, true).then(acc => acc.address);
+    await mintTo(provider.connection, brand, usdcMint, brandUsdcAccount, brand, usdc(2000).toNumber());
+
+    await program.methods
+      .createCampaign(campaignName, "test-nickname", "Brand", "#amend", new anchor.BN(1000), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), totalAmount, new anchor.BN(Date.now() / 1000 + 86400))
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey, oracle: oracleEntry, systemProgram: SystemProgram.programId })
+      .signers([influencer])
+      .rpc();
+
+    await program.methods
+      .brandPayCampaign(await termsVersion(program, campaignPda))
+      .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([brand])
+      .rpc();
+  });
+
+  it("Scenario: Lowered targets and an extended deadline pay the reached milestones", async () => {
+    await updateMetrics(300);
+    expect(await influencerBalance()).to.equal(usdc(300).toString());
+
+    const deadlineBefore = (await program.account.campaign.fetch(campaignPda)).deadline;
+    const newDeadline = deadlineBefore.addn(7 * 86400);
+    await amend(newDeadline, likesTarget(500), new anchor.BN(0));
+
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.deadline.toString()).to.equal(newDeadline.toString());
+    expect(campaign.paidAmount.toString()).to.equal(usdc(600).toString());
+    expect(campaign.paymentMilestones.slice(0, 6).every((paid: boolean) => paid)).to.be.true;
+    expect(await influencerBalance()).to.equal(usdc(600).toString());
+    console.log("   ✓ Meta reduzida para 500 likes: 60% pago e prazo estendido");
+  });
+
+  it("Scenario: A budget top-up only pays the difference of reached milestones", async () => {
+    await updateMetrics(500);
+    await amend(null, null, usdc(1000));
+
+    let campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.amountUsdc.toString()).to.equal(usdc(2000).toString());
+    expect(await influencerBalance()).to.equal(usdc(1000).toString());
+    console.log("   ✓ Aporte de 1000 USDC: diferença de 500 USDC paga");
+
+    await updateMetrics(600);
+    campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.paidAmount.toString()).to.equal(usdc(1200).toString());
+    expect(await influencerBalance()).to.equal(usdc(1200).toString());
+    console.log("   ✓ Marco de 60% paga apenas mais 200 USDC");
+  });
+
+  it("Scenario: Amendments require both signatures", async () => {
+    try {
+      await amend(null, likesTarget(500), new anchor.BN(0), [brand]);
+      expect.fail("Aditivo sem a assinatura do influenciador deveria falhar");
+    } catch (err) {
+      expect(err.toString()).to.match(/Signature verification failed|Missing signature/);
+    }
+  });
+
+  it("Scenario: Raised targets cannot undo paid milestones", async () => {
+    await updateMetrics(500);
+    try {
+      await amend(null, likesTarget(2000), new anchor.BN(0));
+      expect.fail("Metas maiores não deveriam desfazer marcos pagos");
+    } catch (err) {
+      expect(err.toString()).to.include("Amended targets cannot undo milestones already paid");
+    }
+  });
+
+  it("Scenario: An amendment reaching 100% completes the campaign without the brand account", async () => {
+    await updateMetrics(300);
+    try {
+      await amend(null, null, usdc(100), [brand, influencer], false);
+      expect.fail("Aporte sem a conta da marca deveria falhar");
+    } catch (err) {
+      expect(err.toString()).to.include("Brand token account is required to top up the budget");
+    }
+
+    await amend(null, likesTarget(300), new anchor.BN(0), [brand, influencer], false);
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.status).to.deep.equal({ completed: {} });
+    expect(campaign.completedAt.toNumber()).to.be.greaterThan(0);
+    expect(await influencerBalance()).to.equal(totalAmount.toString());
+    console.log("   ✓ Meta reduzida para 300 likes: campanha concluída e 100% pago");
+  });
+});
@@ -0,0 +1,175 @@
+/**
+ * Testes Solengage - Especificação BDD
+ *
+ * Feature: Disputas
+ *
+ * Cenário: Disputa congela pagamentos e o árbitro divide o saldo
+ * Given uma campanha ativa de 1000 USDC com 300 likes reportados (300 USDC pagos)
+ * When o influenciador abre uma disputa
+ * Then o oráculo não consegue atualizar métricas ("Campaign is not active")
+ * When o árbitro decide 25% do saldo restante para o influenciador
+ * Then o influenciador recebe mais 175 USDC, a marca recebe 525 USDC e a campanha é cancelada
+ *
+ * Cenário: Campanha em disputa não pode ser cancelada
+ * Given a marca abriu uma disputa
+ * When a marca tenta cancelar a campanha
+ * Then a transação falha com "Campaign is under dispute"
+ *
+ * Cenário: Somente o árbitro resolve a disputa
+ * When a marca tenta resolver a própria disputa
+ * Then a transação falha com "Only the registry arbiter can resolve challenged reports"
+ *
+ * Cenário: Terceiros não abrem disputas
+ * When uma conta que não é parte da campanha abre uma disputa
+ * Then a transação falha com "Only the influencer or the brand of this campaign can do this"
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Solengage } from "../target/types/solengage";
+import { bondOracle, ensureOracleRegistry, registerOracle } from "./utils/oracle_registry";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { getAccount, getOrCreateAssociatedTokenAccount, createMint, TOKEN_PROGRAM_ID, mintTo } from "@solana/spl-token";
+import { expect } from "chai";
+import { nextReport } from "./utils/reports";
+import { termsVersion } from "./utils/terms";
+
+describe("Solengage - 26 Disputes", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.solengage as Program<Solengage>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+
+  let influencer: Keypair, brand: Keypair, oracle: Keypair, arbiter: Keypair;
+  let oracleRegistry: PublicKey, oracleEntry: PublicKey;
+  let brandUsdcAccount: PublicKey, influencerUsdcAccount: PublicKey, campaignUsdcAccount: PublicKey;
+  let campaignPda: PublicKey;
+
+  const campaignName = "Disputes Test";
+  const usdc = (amount: number) => new anchor.BN(amount * 1_000_000);
+  const totalAmount = usdc(1000);
+
+  const openDispute = (party: Keypair) =>
+    program.methods
+      .openDispute()
+      .accounts({ campaign: campaignPda, party: party.publicKey } as any)
+      .signers([party])
+      .rpc();
+
+  const resolveDispute = (signer: Keypair, influencerBps: number) =>
+    program.methods
+      .resolveDispute(influencerBps)
+      .accounts({ campaign: campaignPda, oracleRegistry, arbiter: signer.publicKey, brandUsdcAccount, influencerUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID } as any)
+      .signers([signer])
+      .rpc();
+
+  const updateMetrics = (likes: number) =>
+    program.methods
+      .updateCampaignMetrics(new anchor.BN(likes), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), ...nextReport())
+      .accounts({ campaign: campaignPda, oracle: oracleEntry, oracleAuthority: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([oracle])
+      .rpc();
+
+  const balance = async (account: PublicKey) => (await getAccount(provider.connection, account)).amount.toString();
+
+  beforeEach(async () => {
+    influencer = Keypair.generate();
+    brand = Keypair.generate();
+    oracle = Keypair.generate();
+    arbiter = Keypair.generate();
+
+    await Promise.all(
+      [influencer, brand, oracle, arbiter].map(kp =>
+        provider.connection.requestAirdrop(kp.publicKey, 2 * LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig, "confirmed"))
+      )
+    );
+    oracleRegistry = await ensureOracleRegistry(program, provider);
+    oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+    await program.methods
+      .setArbiter(arbiter.publicKey)
+      .accounts({ oracleRegistry, admin: provider.wallet.publicKey } as any)
+      .rpc();
+
+    const usdcMint = await createMint(provider.connection, brand, brand.publicKey, null, 6);
+    await bondOracle(program, provider, oracle, oracleEntry, usdcMint, brand);
+    brandUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, brand.publicKey).then(acc => acc.address);
+    influencerUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, influencer, usdcMint, influencer.publicKey).then(acc => acc.address);
+
+    [campaignPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign"), influencer.publicKey.toBuffer(), brand.publicKey.toBuffer(), Buffer.from(campaignName)],
+      program.programId
+    );
+    campaignUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, campaignPda, true).then(acc => acc.address);
+    await mintTo(provider.connection, brand, usdcMint, brandUsdcAccount, brand, totalAmount.toNumber());
+
+    await program.methods
+      .createCampaign(campaignName, "test-nickname", "Brand", "#dispute", new anchor.BN(1000), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), totalAmount, new anchor.BN(Date.now() / 1000 + 86400))
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey, oracle: oracleEntry, systemProgram: SystemProgram.programId })
+      .signers([influencer])
+      .rpc();
+
+    await program.methods
+      .brandPayCampaign(await termsVersion(program, campaignPda))
+      .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([brand])
+      .rpc();
+  });
+
+  it("Scenario: A dispute freezes payouts and the arbiter splits the balance", async () => {
+    await updateMetrics(300);
+    await openDispute(influencer);
+
+    let campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.status).to.deep.equal({ disputed: {} });
+    expect(campaign.disputedBy.toBase58()).to.equal(influencer.publicKey.toBase58());
+
+    try {
+      await updateMetrics(500);
+      expect.fail("Campanha em disputa não deveria aceitar métricas");
+    } catch (err) {
+      expect(err.toString()).to.include("Campaign is not active");
+    }
+    console.log("   ✓ Disputa aberta: métricas e pagamentos congelados");
+
+    await resolveDispute(arbiter, 2500);
+    campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.status).to.deep.equal({ cancelled: {} });
+    expect(campaign.paidAmount.toString()).to.equal(usdc(475).toString());
+    expect(await balance(influencerUsdcAccount)).to.equal(usdc(475).toString());
+    expect(await balance(brandUsdcAccount)).to.equal(usdc(525).toString());
+    expect(await balance(campaignUsdcAccount)).to.equal("0");
+    console.log("   ✓ Árbitro dividiu o saldo: 175 USDC ao influenciador, 525 USDC à marca");
+  });
+
+  it("Scenario: Disputed campaigns cannot be cancelled", async () => {
+    await openDispute(brand);
+    try {
+      await program.methods
+        .cancelCampaign()
+        .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID } as any)
+        .signers([brand])
+        .rpc();
+      expect.fail("Campanha em disputa não deveria ser cancelada");
+    } catch (err) {
+      expect(err.toString()).to.include("Campaign is under dispute");
+    }
+  });
+
+  it("Scenario: Only the arbiter resolves a dispute", async () => {
+    await openDispute(brand);
+    try {
+      await resolveDispute(brand, 0);
+      expect.fail("A marca não deveria resolver a disputa");
+    } catch (err) {
+      expect(err.toString()).to.include("Only the registry arbiter can resolve challenged reports");
+    }
+  });
+
+  it("Scenario: Outsiders cannot open disputes", async () => {
+    try {
+      await openDispute(oracle);
+      expect.fail("Terceiros não deveriam abrir disputas");
+    } catch (err) {
+      expect(err.toString()).to.include("Only the influencer or the brand of this campaign can do this");
+    }
+  });
+});
@@ -0,0 +1,163 @@
+/**
+ * Testes Solengage - Especificação BDD
+ *
+ * Feature: Kill Fee
+ *
+ * Cenário: Cancelamento de campanha ativa paga a kill fee ao influenciador
+ * Given uma campanha de 1000 USDC com kill fee fixa de 20% e 300 likes reportados (300 USDC pagos)
+ * When a marca cancela a campanha
+ * Then o influenciador recebe 140 USDC (20% dos 700 não pagos), total 440 USDC
+ * And a marca recebe os 560 USDC restantes
+ *
+ * Cenário: Kill fee não é devida depois do prazo final
+ * Given uma kill fee de 10% na ativação e 50% no prazo final
+ * When a marca cancela depois do prazo, sem informar a conta do influenciador
+ * Then o influenciador não recebe nada e a marca recebe os 1000 USDC
+ *
+ * Cenário: Kill fee devida exige a conta do influenciador
+ * When a marca cancela sem informar a conta USDC do influenciador
+ * Then a transação falha com "Influencer token account is required to pay the kill fee"
+ *
+ * Cenário: Kill fee decrescente é rejeitada
+ * When o influenciador define 50% na ativação e 10% no prazo final
+ * Then a transação falha com "Kill fee must be at most 10000 basis points and must not decrease"
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Solengage } from "../target/types/solengage";
+import { bondOracle, registerOracle } from "./utils/oracle_registry";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { getAccount, getOrCreateAssociatedTokenAccount, createMint, TOKEN_PROGRAM_ID, mintTo } from "@solana/spl-token";
+import { expect } from "chai";
+import { nextReport } from "./utils/reports";
+import { termsVersion } from "./utils/terms";
+
+const sleep = (ms: number) => new Promise(resolve => setTimeout(resolve, ms));
+
+describe("Solengage - 27 Kill Fee", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.solengage as Program<Solengage>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+
+  let influencer: Keypair, brand: Keypair, oracle: Keypair;
+  let oracleEntry: PublicKey;
+  let brandUsdcAccount: PublicKey, influencerUsdcAccount: PublicKey, campaignUsdcAccount: PublicKey;
+  let campaignPda: PublicKey;
+
+  const campaignName = "Kill Fee Test";
+  const usdc = (amount: number) => new anchor.BN(amount * 1_000_000);
+  const totalAmount = usdc(1000);
+
+  const setup = async (killFeeBps: number, killFeeMaxBps: number, duration = 86400) => {
+    await program.methods
+      .createCampaign(campaignName, "test-nickname", "Brand", "#killfee", new anchor.BN(1000), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), totalAmount, new anchor.BN(Math.floor(Date.now() / 1000) + duration))
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey, oracle: oracleEntry, systemProgram: SystemProgram.programId })
+      .signers([influencer])
+      .rpc();
+
+    await setKillFee(killFeeBps, killFeeMaxBps);
+
+    await program.methods
+      .brandPayCampaign(await termsVersion(program, campaignPda))
+      .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([brand])
+      .rpc();
+  };
+
+  const setKillFee = (killFeeBps: number, killFeeMaxBps: number) =>
+    program.methods
+      .setKillFee(killFeeBps, killFeeMaxBps)
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey } as any)
+      .signers([influencer])
+      .rpc();
+
+  const cancel = (withInfluencerAccount = true) =>
+    program.methods
+      .cancelCampaign()
+      .accounts({
+        campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount,
+        influencerUsdcAccount: withInfluencerAccount ? influencerUsdcAccount : null, tokenProgram: TOKEN_PROGRAM_ID,
+      } as any)
+      .signers([brand])
+      .rpc();
+
+  const balance = async (account: PublicKey) => (await getAccount(provider.connection, account)).amount.toString();
+
+  beforeEach(async () => {
+    influencer = Keypair.generate();
+    brand = Keypair.generate();
+    oracle = Keypair.generate();
+
+    await Promise.all(
+      [influencer, brand, oracle].map(kp =>
+        provider.connection.requestAirdrop(kp.publicKey, 2 * LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig, "confirmed"))
+      )
+    );
+    oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+
+    const usdcMint = await createMint(provider.connection, brand, brand.publicKey, null, 6);
+    await bondOracle(program, provider, oracle, oracleEntry, usdcMint, brand);
+    brandUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, brand.publicKey).then(acc => acc.address);
+    influencerUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, influencer, usdcMint, influencer.publicKey).then(acc => acc.address);
+
+    [campaignPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign"), influencer.publicKey.toBuffer(), brand.publicKey.toBuffer(), Buffer.from(campaignName)],
+      program.programId
+    );
+    campaignUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, campaignPda, true).then(acc => acc.address);
+    await mintTo(provider.connection, brand, usdcMint, brandUsdcAccount, brand, totalAmount.toNumber());
+  });
+
+  it("Scenario: Cancelling an Active campaign pays the kill fee to the influencer", async () => {
+    await setup(2000, 2000);
+    await program.methods
+      .updateCampaignMetrics(new anchor.BN(300), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), ...nextReport())
+      .accounts({ campaign: campaignPda, oracle: oracleEntry, oracleAuthority: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([oracle])
+      .rpc();
+
+    await cancel();
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.status).to.deep.equal({ cancelled: {} });
+    expect(campaign.paidAmount.toString()).to.equal(usdc(440).toString());
+    expect(await balance(influencerUsdcAccount)).to.equal(usdc(440).toString());
+    expect(await balance(brandUsdcAccount)).to.equal(usdc(560).toString());
+    console.log("   ✓ Kill fee de 20%: 140 USDC ao influenciador, 560 USDC à marca");
+  });
+
+  it("Scenario: No kill fee is owed after the deadline", async () => {
+    await setup(1000, 5000, 5);
+    await sleep(7000);
+
+    await cancel(false);
+    expect(await balance(influencerUsdcAccount)).to.equal("0");
+    expect(await balance(brandUsdcAccount)).to.equal(totalAmount.toString());
+    console.log("   ✓ Cancelamento após o prazo: sem kill fee, orçamento devolvido à marca");
+  });
+
+  it("Scenario: An owed kill fee requires the influencer account", async () => {
+    await setup(2000, 2000);
+    try {
+      await cancel(false);
+      expect.fail("Cancelamento sem a conta do influenciador deveria falhar");
+    } catch (err) {
+      expect(err.toString()).to.include("Influencer token account is required to pay the kill fee");
+    }
+  });
+
+  it("Scenario: A decreasing kill fee is rejected", async () => {
+    await program.methods
+      .createCampaign(campaignName, "test-nickname", "Brand", "#killfee", new anchor.BN(1000), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), totalAmount, new anchor.BN(Date.now() / 1000 + 86400))
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey, oracle: oracleEntry, systemProgram: SystemProgram.programId })
+      .signers([influencer])
+      .rpc();
+
+    try {
+      await setKillFee(5000, 1000);
+      expect.fail("Kill fee decrescente deveria ser rejeitada");
+    } catch (err) {
+      expect(err.toString()).to.include("Kill fee must be at most 10000 basis points and must not decrease");
+    }
+  });
+});
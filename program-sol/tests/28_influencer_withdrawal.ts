@@ -0,0 +1,264 @@
+/**
+ * Testes Solengage - Especificação BDD
+ *
+ * Feature: Desistência do Influenciador
+ *
+ * Cenário: Desistência devolve o saldo não pago e registra o resultado
+ * Given uma campanha ativa de 1000 USDC com 300 likes reportados (300 USDC pagos)
+ * When o influenciador desiste da campanha
+ * Then a marca recebe os 700 USDC não pagos e a campanha está em Withdrawn
+ * And o registro de desistência guarda 30% de progresso, 300 USDC pagos e 700 USDC devolvidos
+ *
+ * Cenário: Relatório em janela de contestação exige renúncia total
+ * Given uma campanha com janela de contestação aberta e um relatório pendente de 500 likes
+ * When o influenciador desiste sem renunciar aos ganhos pendentes
+ * Then a transação falha com "A report is pending its challenge window"
+ * When o influenciador desiste renunciando a 10000 basis points
+ * Then a marca recebe os 1000 USDC e o registro indica 500 USDC renunciados
+ *
+ * Cenário: Ganhos pendentes são divididos pela fração renunciada
+ * Given uma campanha com um relatório pendente de 500 likes e a janela de contestação encerrada
+ * When o influenciador desiste renunciando a mais de 10000 basis points
+ * Then a transação falha com "Forfeit must be between 0 and 10000 basis points"
+ * When o influenciador desiste renunciando a 40% dos ganhos pendentes
+ * Then o influenciador recebe 300 USDC e a marca recebe 700 USDC
+ * And o registro indica 300 USDC pagos, 700 USDC devolvidos e 200 USDC renunciados
+ *
+ * Cenário: Campanha encerrada pelo influenciador não pode ser cancelada
+ * Given uma campanha da qual o influenciador desistiu
+ * When a marca tenta cancelar a campanha
+ * Then a transação falha com "Campaign is not active"
+ *
+ * Cenário: Influenciador fecha a campanha encerrada e seus posts
+ * Given uma campanha com um post da qual o influenciador desistiu
+ * When o influenciador fecha a desistência sem informar o post
+ * Then a transação falha com "All campaign posts and the oracle quorum must be passed"
+ * When o influenciador fecha a desistência informando o post
+ * Then a campanha e o post são fechados
+ * And o registro de desistência permanece
+ *
+ * Cenário: Somente o influenciador desiste
+ * When a marca tenta desistir em nome do influenciador
+ * Then a transação falha com "You are not the influencer of this campaign"
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Solengage } from "../target/types/solengage";
+import { bondOracle, registerOracle } from "./utils/oracle_registry";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { getAccount, getOrCreateAssociatedTokenAccount, createMint, TOKEN_PROGRAM_ID, mintTo } from "@solana/spl-token";
+import { createHash } from "crypto";
+import { expect } from "chai";
+import { nextReport } from "./utils/reports";
+import { termsVersion } from "./utils/terms";
+
+const urlHash = (url: string) => Array.from(createHash("sha256").update(url).digest());
+
+const sleep = (ms: number) => new Promise(resolve => setTimeout(resolve, ms));
+
+describe("Solengage - 28 Influencer Withdrawal", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.solengage as Program<Solengage>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+
+  let influencer: Keypair, brand: Keypair, oracle: Keypair;
+  let oracleEntry: PublicKey;
+  let brandUsdcAccount: PublicKey, influencerUsdcAccount: PublicKey, campaignUsdcAccount: PublicKey;
+  let campaignPda: PublicKey, withdrawalRecord: PublicKey;
+
+  const campaignName = "Influencer Withdrawal Test";
+  const usdc = (amount: number) => new anchor.BN(amount * 1_000_000);
+  const totalAmount = usdc(1000);
+
+  const setup = async (challengeWindow: number) => {
+    await program.methods
+      .createCampaign(campaignName, "test-nickname", "Brand", "#withdraw", new anchor.BN(1000), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), totalAmount, new anchor.BN(Date.now() / 1000 + 86400))
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey, oracle: oracleEntry, systemProgram: SystemProgram.programId })
+      .signers([influencer])
+      .rpc();
+
+    if (challengeWindow > 0) {
+      await program.methods
+        .setChallengeWindow(new anchor.BN(challengeWindow))
+        .accounts({ campaign: campaignPda, influencer: influencer.publicKey })
+        .signers([influencer])
+        .rpc();
+    }
+
+    await program.methods
+      .brandPayCampaign(await termsVersion(program, campaignPda))
+      .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([brand])
+      .rpc();
+  };
+
+  const withdraw = (signer: Keypair, forfeitBps: number) =>
+    program.methods
+      .influencerWithdraw(forfeitBps)
+      .accounts({ campaign: campaignPda, withdrawalRecord, influencer: signer.publicKey, brandUsdcAccount, influencerUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId } as any)
+      .signers([signer])
+      .rpc();
+
+  const updateMetrics = (likes: number) =>
+    program.methods
+      .updateCampaignMetrics(new anchor.BN(likes), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), ...nextReport())
+      .accounts({ campaign: campaignPda, oracle: oracleEntry, oracleAuthority: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([oracle])
+      .rpc();
+
+  const balance = async (account: PublicKey) => (await getAccount(provider.connection, account)).amount.toString();
+
+  beforeEach(async () => {
+    influencer = Keypair.generate();
+    brand = Keypair.generate();
+    oracle = Keypair.generate();
+
+    await Promise.all(
+      [influencer, brand, oracle].map(kp =>
+        provider.connection.requestAirdrop(kp.publicKey, 2 * LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig, "confirmed"))
+      )
+    );
+    oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+
+    const usdcMint = await createMint(provider.connection, brand, brand.publicKey, null, 6);
+    await bondOracle(program, provider, oracle, oracleEntry, usdcMint, brand);
+    brandUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, brand.publicKey).then(acc => acc.address);
+    influencerUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, influencer, usdcMint, influencer.publicKey).then(acc => acc.address);
+
+    [campaignPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign"), influencer.publicKey.toBuffer(), brand.publicKey.toBuffer(), Buffer.from(campaignName)],
+      program.programId
+    );
+    [withdrawalRecord] = PublicKey.findProgramAddressSync([Buffer.from("withdrawal"), campaignPda.toBuffer()], program.programId);
+    campaignUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, campaignPda, true).then(acc => acc.address);
+    await mintTo(provider.connection, brand, usdcMint, brandUsdcAccount, brand, totalAmount.toNumber());
+  });
+
+  it("Scenario: Withdrawal refunds the unpaid balance and records the outcome", async () => {
+    await setup(0);
+    await updateMetrics(300);
+    await withdraw(influencer, 0);
+
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.status).to.deep.equal({ withdrawn: {} });
+    expect(await balance(brandUsdcAccount)).to.equal(usdc(700).toString());
+    expect(await balance(influencerUsdcAccount)).to.equal(usdc(300).toString());
+
+    const record = await program.account.withdrawalRecord.fetch(withdrawalRecord);
+    expect(record.influencer.toBase58()).to.equal(influencer.publicKey.toBase58());
+    expect(record.progress).to.equal(30);
+    expect(record.paidAmount.toString()).to.equal(usdc(300).toString());
+    expect(record.refundedAmount.toString()).to.equal(usdc(700).toString());
+    expect(record.forfeitedAmount.toNumber()).to.equal(0);
+    console.log("   ✓ Desistência: 700 USDC devolvidos e registro criado");
+  });
+
+  it("Scenario: A report inside its challenge window must be forfeited in full", async () => {
+    await setup(3600);
+    await updateMetrics(500);
+
+    try {
+      await withdraw(influencer, 0);
+      expect.fail("Desistência na janela de contestação deveria exigir renúncia total");
+    } catch (err) {
+      expect(err.toString()).to.include("A report is pending its challenge window");
+    }
+
+    await withdraw(influencer, 10_000);
+    expect(await balance(brandUsdcAccount)).to.equal(totalAmount.toString());
+    expect(await balance(influencerUsdcAccount)).to.equal("0");
+
+    const record = await program.account.withdrawalRecord.fetch(withdrawalRecord);
+    expect(record.forfeitedAmount.toString()).to.equal(usdc(500).toString());
+    console.log("   ✓ Ganhos em contestação renunciados integralmente");
+  });
+
+  it("Scenario: Pending earnings are split by the forfeited share", async () => {
+    await setup(2);
+    await updateMetrics(500);
+    await sleep(3000);
+
+    try {
+      await withdraw(influencer, 10_001);
+      expect.fail("Renúncia acima de 10000 basis points deveria falhar");
+    } catch (err) {
+      expect(err.toString()).to.include("Forfeit must be between 0 and 10000 basis points");
+    }
+
+    await withdraw(influencer, 4_000);
+    expect(await balance(influencerUsdcAccount)).to.equal(usdc(300).toString());
+    expect(await balance(brandUsdcAccount)).to.equal(usdc(700).toString());
+
+    const record = await program.account.withdrawalRecord.fetch(withdrawalRecord);
+    expect(record.paidAmount.toString()).to.equal(usdc(300).toString());
+    expect(record.refundedAmount.toString()).to.equal(usdc(700).toString());
+    expect(record.forfeitedAmount.toString()).to.equal(usdc(200).toString());
+    console.log("   ✓ Ganhos pendentes divididos: 300 USDC pagos, 200 USDC renunciados");
+  });
+
+  it("Scenario: A withdrawn campaign cannot be cancelled", async () => {
+    await setup(0);
+    await updateMetrics(300);
+    await withdraw(influencer, 0);
+
+    try {
+      await program.methods
+        .cancelCampaign()
+        .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID } as any)
+        .signers([brand])
+        .rpc();
+      expect.fail("Uma campanha encerrada não deveria ser cancelada");
+    } catch (err) {
+      expect(err.toString()).to.include("Campaign is not active");
+    }
+    expect(await balance(brandUsdcAccount)).to.equal(usdc(700).toString());
+  });
+
+  it("Scenario: The influencer closes the withdrawn campaign and its posts", async () => {
+    await setup(0);
+    const url = "https://instagram.com/p/withdrawn";
+    const [campaignPost] = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign_post"), campaignPda.toBuffer(), Buffer.from(urlHash(url))],
+      program.programId
+    );
+    await program.methods
+      .addCampaignPost(urlHash(url), { instagram: {} })
+      .accounts({ campaign: campaignPda, campaignPost, influencer: influencer.publicKey, systemProgram: SystemProgram.programId } as any)
+      .signers([influencer])
+      .rpc();
+    await withdraw(influencer, 0);
+
+    const close = (posts: PublicKey[]) =>
+      program.methods
+        .closeWithdrawal()
+        .accounts({ campaign: campaignPda, influencer: influencer.publicKey } as any)
+        .remainingAccounts(posts.map(pubkey => ({ pubkey, isWritable: true, isSigner: false })))
+        .signers([influencer])
+        .rpc();
+
+    try {
+      await close([]);
+      expect.fail("O fechamento deveria exigir os posts da campanha");
+    } catch (err) {
+      expect(err.toString()).to.include("All campaign posts and the oracle quorum must be passed");
+    }
+
+    await close([campaignPost]);
+    expect(await provider.connection.getAccountInfo(campaignPda)).to.be.null;
+    expect(await provider.connection.getAccountInfo(campaignPost)).to.be.null;
+    const record = await program.account.withdrawalRecord.fetch(withdrawalRecord);
+    expect(record.campaign.toBase58()).to.equal(campaignPda.toBase58());
+    console.log("   ✓ Campanha e post fechados, registro de desistência mantido");
+  });
+
+  it("Scenario: Only the influencer withdraws", async () => {
+    await setup(0);
+    try {
+      await withdraw(brand, 0);
+      expect.fail("A marca não deveria desistir em nome do influenciador");
+    } catch (err) {
+      expect(err.toString()).to.include("You are not the influencer of this campaign");
+    }
+  });
+});
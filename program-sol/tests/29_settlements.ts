@@ -0,0 +1,181 @@
+/**
+ * Testes Solengage - Especificação BDD
+ *
+ * Feature: Encerramento por Acordo
+ *
+ * Cenário: Acordo divide o saldo não pago e fecha a campanha
+ * Given uma campanha ativa de 1000 USDC com 300 likes reportados (300 USDC pagos)
+ * When marca e influenciador acordam 200 USDC ao influenciador
+ * Then o influenciador recebe 200 USDC (total 500) e a marca 500 USDC
+ * And a conta da campanha é fechada
+ *
+ * Cenário: Acordo encerra uma disputa
+ * Given a marca abriu uma disputa
+ * When as partes acordam 1000 USDC ao influenciador
+ * Then o influenciador recebe todo o orçamento e a campanha é fechada
+ *
+ * Cenário: Acordo fecha os posts da campanha
+ * Given uma campanha ativa com um post registrado
+ * When as partes acordam sem informar o post
+ * Then a transação falha com "All campaign posts and the oracle quorum must be passed"
+ * When as partes acordam informando o post
+ * Then a campanha e o post são fechados
+ *
+ * Cenário: Valor acordado não excede o saldo não pago
+ * Given 300 USDC já pagos
+ * When as partes acordam 800 USDC ao influenciador
+ * Then a transação falha com "Settlement amount exceeds the unpaid campaign budget"
+ *
+ * Cenário: Acordo exige a assinatura das duas partes
+ * When o influenciador envia o acordo sem a assinatura da marca
+ * Then a transação falha por assinatura ausente
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Solengage } from "../target/types/solengage";
+import { bondOracle, registerOracle } from "./utils/oracle_registry";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { getAccount, getOrCreateAssociatedTokenAccount, createMint, TOKEN_PROGRAM_ID, mintTo } from "@solana/spl-token";
+import { createHash } from "crypto";
+import { expect } from "chai";
+import { nextReport } from "./utils/reports";
+import { termsVersion } from "./utils/terms";
+
+const urlHash = (url: string) => Array.from(createHash("sha256").update(url).digest());
+
+describe("Solengage - 29 Settlements", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.solengage as Program<Solengage>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+
+  let influencer: Keypair, brand: Keypair, oracle: Keypair;
+  let oracleEntry: PublicKey;
+  let brandUsdcAccount: PublicKey, influencerUsdcAccount: PublicKey, campaignUsdcAccount: PublicKey;
+  let campaignPda: PublicKey;
+
+  const campaignName = "Settlements Test";
+  const usdc = (amount: number) => new anchor.BN(amount * 1_000_000);
+  const totalAmount = usdc(1000);
+
+  const settle = (influencerAmount: anchor.BN, signers: Keypair[] = [brand, influencer], posts: PublicKey[] = []) =>
+    program.methods
+      .settleCampaign(influencerAmount)
+      .accounts({ campaign: campaignPda, brand: brand.publicKey, influencer: influencer.publicKey, brandUsdcAccount, influencerUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID } as any)
+      .remainingAccounts(posts.map(pubkey => ({ pubkey, isWritable: true, isSigner: false })))
+      .signers(signers)
+      .rpc();
+
+  const updateMetrics = (likes: number) =>
+    program.methods
+      .updateCampaignMetrics(new anchor.BN(likes), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), ...nextReport())
+      .accounts({ campaign: campaignPda, oracle: oracleEntry, oracleAuthority: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([oracle])
+      .rpc();
+
+  const balance = async (account: PublicKey) => (await getAccount(provider.connection, account)).amount.toString();
+
+  beforeEach(async () => {
+    influencer = Keypair.generate();
+    brand = Keypair.generate();
+    oracle = Keypair.generate();
+
+    await Promise.all(
+      [influencer, brand, oracle].map(kp =>
+        provider.connection.requestAirdrop(kp.publicKey, 2 * LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig, "confirmed"))
+      )
+    );
+    oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+
+    const usdcMint = await createMint(provider.connection, brand, brand.publicKey, null, 6);
+    await bondOracle(program, provider, oracle, oracleEntry, usdcMint, brand);
+    brandUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, brand.publicKey).then(acc => acc.address);
+    influencerUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, influencer, usdcMint, influencer.publicKey).then(acc => acc.address);
+
+    [campaignPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign"), influencer.publicKey.toBuffer(), brand.publicKey.toBuffer(), Buffer.from(campaignName)],
+      program.programId
+    );
+    campaignUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, campaignPda, true).then(acc => acc.address);
+    await mintTo(provider.connection, brand, usdcMint, brandUsdcAccount, brand, totalAmount.toNumber());
+
+    await program.methods
+      .createCampaign(campaignName, "test-nickname", "Brand", "#settle", new anchor.BN(1000), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), totalAmount, new anchor.BN(Date.now() / 1000 + 86400))
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey, oracle: oracleEntry, systemProgram: SystemProgram.programId })
+      .signers([influencer])
+      .rpc();
+
+    await program.methods
+      .brandPayCampaign(await termsVersion(program, campaignPda))
+      .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([brand])
+      .rpc();
+  });
+
+  it("Scenario: A settlement splits the unpaid balance and closes the campaign", async () => {
+    await updateMetrics(300);
+    await settle(usdc(200));
+
+    expect(await balance(influencerUsdcAccount)).to.equal(usdc(500).toString());
+    expect(await balance(brandUsdcAccount)).to.equal(usdc(500).toString());
+    expect(await program.account.campaign.fetchNullable(campaignPda)).to.be.null;
+    console.log("   ✓ Acordo: 200 USDC ao influenciador, 500 USDC à marca e conta fechada");
+  });
+
+  it("Scenario: A settlement ends a dispute", async () => {
+    await program.methods
+      .openDispute()
+      .accounts({ campaign: campaignPda, party: brand.publicKey } as any)
+      .signers([brand])
+      .rpc();
+
+    await settle(totalAmount);
+    expect(await balance(influencerUsdcAccount)).to.equal(totalAmount.toString());
+    expect(await program.account.campaign.fetchNullable(campaignPda)).to.be.null;
+    console.log("   ✓ Disputa encerrada por acordo");
+  });
+
+  it("Scenario: A settlement closes the campaign posts", async () => {
+    const url = "https://instagram.com/p/settled";
+    const [campaignPost] = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign_post"), campaignPda.toBuffer(), Buffer.from(urlHash(url))],
+      program.programId
+    );
+    await program.methods
+      .addCampaignPost(urlHash(url), { instagram: {} })
+      .accounts({ campaign: campaignPda, campaignPost, influencer: influencer.publicKey, systemProgram: SystemProgram.programId } as any)
+      .signers([influencer])
+      .rpc();
+
+    try {
+      await settle(usdc(100));
+      expect.fail("O acordo deveria exigir os posts da campanha");
+    } catch (err) {
+      expect(err.toString()).to.include("All campaign posts and the oracle quorum must be passed");
+    }
+
+    await settle(usdc(100), [brand, influencer], [campaignPost]);
+    expect(await program.account.campaign.fetchNullable(campaignPda)).to.be.null;
+    expect(await provider.connection.getAccountInfo(campaignPost)).to.be.null;
+    console.log("   ✓ Acordo fechou a campanha e o post");
+  });
+
+  it("Scenario: The agreed amount cannot exceed the unpaid budget", async () => {
+    await updateMetrics(300);
+    try {
+      await settle(usdc(800));
+      expect.fail("Valor acima do saldo não pago deveria ser rejeitado");
+    } catch (err) {
+      expect(err.toString()).to.include("Settlement amount exceeds the unpaid campaign budget");
+    }
+  });
+
+  it("Scenario: Settlements require both signatures", async () => {
+    try {
+      await settle(usdc(100), [influencer]);
+      expect.fail("Acordo sem a assinatura da marca deveria falhar");
+    } catch (err) {
+      expect(err.toString()).to.match(/Signature verification failed|Missing signature/);
+    }
+  });
+});
@@ -0,0 +1,180 @@
+/**
+ * Testes Solengage - Especificação BDD
+ *
+ * Feature: Revisão de Conteúdo
+ *
+ * Cenário: Métricas só contam depois da aprovação do conteúdo
+ * Given uma campanha ativa com 1 hora de prazo para revisão de conteúdo
+ * When o oráculo reporta métricas antes do envio do conteúdo
+ * Then a transação falha com "Campaign content has not been approved yet"
+ * When o influenciador envia o conteúdo e a marca pede alterações
+ * Then o conteúdo fica em ChangesRequested e as métricas continuam bloqueadas
+ * When o influenciador reenvia e a marca aprova
+ * Then o oráculo reporta 500 likes e o influenciador recebe 50%
+ *
+ * Cenário: Conteúdo não revisado no prazo é aprovado automaticamente
+ * Given uma campanha com 2 segundos de prazo para revisão
+ * When o influenciador envia o conteúdo e o prazo termina sem revisão
+ * Then o oráculo consegue reportar métricas
+ * And a marca não pode mais recusar o conteúdo ("The content review window has closed")
+ *
+ * Cenário: Campanha sem etapa de conteúdo não recebe envios
+ * Given uma campanha ativa sem prazo de revisão
+ * When o influenciador envia o conteúdo
+ * Then a transação falha com "Campaign has no content review stage"
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Solengage } from "../target/types/solengage";
+import { bondOracle, registerOracle } from "./utils/oracle_registry";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { getAccount, getOrCreateAssociatedTokenAccount, createMint, TOKEN_PROGRAM_ID, mintTo } from "@solana/spl-token";
+import { expect } from "chai";
+import { createHash } from "crypto";
+import { nextReport } from "./utils/reports";
+import { termsVersion } from "./utils/terms";
+
+const sleep = (ms: number) => new Promise(resolve => setTimeout(resolve, ms));
+const urlHash = (url: string) => Array.from(createHash("sha256").update(url).digest());
+
+describe("Solengage - 30 Content Review", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.solengage as Program<Solengage>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+
+  let influencer: Keypair, brand: Keypair, oracle: Keypair;
+  let oracleEntry: PublicKey;
+  let brandUsdcAccount: PublicKey, influencerUsdcAccount: PublicKey, campaignUsdcAccount: PublicKey;
+  let campaignPda: PublicKey;
+
+  const campaignName = "Content Review Test";
+  const totalAmount = new anchor.BN(1000 * 1_000_000); // 1000 USDC
+
+  const setup = async (reviewWindow: number) => {
+    await program.methods
+      .createCampaign(campaignName, "test-nickname", "Brand", "#content", new anchor.BN(1000), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), totalAmount, new anchor.BN(Date.now() / 1000 + 86400))
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey, brand: brand.publicKey, oracle: oracleEntry, systemProgram: SystemProgram.programId })
+      .signers([influencer])
+      .rpc();
+
+    if (reviewWindow > 0) {
+      await program.methods
+        .setContentReview(new anchor.BN(reviewWindow))
+        .accounts({ campaign: campaignPda, influencer: influencer.publicKey } as any)
+        .signers([influencer])
+        .rpc();
+    }
+
+    await program.methods
+      .brandPayCampaign(await termsVersion(program, campaignPda))
+      .accounts({ campaign: campaignPda, brand: brand.publicKey, brandUsdcAccount, campaignUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([brand])
+      .rpc();
+  };
+
+  const submitContent = (url: string) =>
+    program.methods
+      .submitContent(urlHash(url), { instagram: {} }, url.split("/").pop())
+      .accounts({ campaign: campaignPda, influencer: influencer.publicKey } as any)
+      .signers([influencer])
+      .rpc();
+
+  const reviewContent = (approve: boolean) =>
+    program.methods
+      .reviewContent(approve)
+      .accounts({ campaign: campaignPda, brand: brand.publicKey } as any)
+      .signers([brand])
+      .rpc();
+
+  const updateMetrics = (likes: number) =>
+    program.methods
+      .updateCampaignMetrics(new anchor.BN(likes), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0), ...nextReport())
+      .accounts({ campaign: campaignPda, oracle: oracleEntry, oracleAuthority: oracle.publicKey, campaignUsdcAccount, influencerUsdcAccount, tokenProgram: TOKEN_PROGRAM_ID })
+      .signers([oracle])
+      .rpc();
+
+  const expectMetricsBlocked = async () => {
+    try {
+      await updateMetrics(500);
+      expect.fail("Métricas não deveriam contar antes da aprovação do conteúdo");
+    } catch (err) {
+      expect(err.toString()).to.include("Campaign content has not been approved yet");
+    }
+  };
+
+  beforeEach(async () => {
+    influencer = Keypair.generate();
+    brand = Keypair.generate();
+    oracle = Keypair.generate();
+
+    await Promise.all(
+      [influencer, brand, oracle].map(kp =>
+        provider.connection.requestAirdrop(kp.publicKey, 2 * LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig, "confirmed"))
+      )
+    );
+    oracleEntry = await registerOracle(program, provider, oracle.publicKey);
+
+    const usdcMint = await createMint(provider.connection, brand, brand.publicKey, null, 6);
+    await bondOracle(program, provider, oracle, oracleEntry, usdcMint, brand);
+    brandUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, brand.publicKey).then(acc => acc.address);
+    influencerUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, influencer, usdcMint, influencer.publicKey).then(acc => acc.address);
+
+    [campaignPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign"), influencer.publicKey.toBuffer(), brand.publicKey.toBuffer(), Buffer.from(campaignName)],
+      program.programId
+    );
+    campaignUsdcAccount = await getOrCreateAssociatedTokenAccount(provider.connection, brand, usdcMint, campaignPda, true).then(acc => acc.address);
+    await mintTo(provider.connection, brand, usdcMint, brandUsdcAccount, brand, totalAmount.toNumber());
+  });
+
+  it("Scenario: Metrics only count after the content is approved", async () => {
+    await setup(3600);
+    await expectMetricsBlocked();
+    console.log("   ✓ Métricas bloqueadas antes do envio do conteúdo");
+
+    await submitContent("https://instagram.com/p/draft-post");
+    await reviewContent(false);
+    let campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.content.status).to.deep.equal({ changesRequested: {} });
+    await expectMetricsBlocked();
+    console.log("   ✓ Marca pediu alterações: métricas continuam bloqueadas");
+
+    await submitContent("https://instagram.com/p/final-post");
+    await reviewContent(true);
+    campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.content.status).to.deep.equal({ approved: {} });
+    expect(campaign.content.postId).to.equal("final-post");
+
+    await updateMetrics(500);
+    const influencerBalance = (await getAccount(provider.connection, influencerUsdcAccount)).amount;
+    expect(influencerBalance.toString()).to.equal(totalAmount.divn(2).toString());
+    console.log("   ✓ Conteúdo aprovado: métricas aceitas e 50% pago");
+  });
+
+  it("Scenario: Content left unreviewed is approved when the window elapses", async () => {
+    await setup(2);
+    await submitContent("https://instagram.com/p/auto-approved");
+    await sleep(3000);
+
+    await updateMetrics(500);
+    console.log("   ✓ Prazo de revisão encerrado: métricas aceitas");
+
+    try {
+      await reviewContent(false);
+      expect.fail("A marca não deveria recusar conteúdo após o prazo");
+    } catch (err) {
+      expect(err.toString()).to.include("The content review window has closed");
+    }
+  });
+
+  it("Scenario: Campaigns without a content stage reject submissions", async () => {
+    await setup(0);
+    try {
+      await submitContent("https://instagram.com/p/no-review");
+      expect.fail("Campanha sem revisão de conteúdo não deveria aceitar envios");
+    } catch (err) {
+      expect(err.toString()).to.include("Campaign has no content review stage");
+    }
+  });
+});
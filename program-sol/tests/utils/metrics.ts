@@ -0,0 +1,12 @@
+/**
+ * Utilitários de teste - Entradas de Métricas
+ *
+ * As metas e valores da campanha ficam em `campaign.metrics`; as quatro primeiras
+ * entradas são sempre os contadores gerais (likes, comments, views, shares).
+ */
+
+const BASE_METRIC_INDEX = { likes: 0, comments: 1, views: 2, shares: 3 };
+
+export function baseMetric(campaign: any, kind: keyof typeof BASE_METRIC_INDEX) {
+  return campaign.metrics[BASE_METRIC_INDEX[kind]];
+}
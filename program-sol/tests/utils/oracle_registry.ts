@@ -0,0 +1,95 @@
+/**
+ * Utilitários de teste - Registro de Oráculos
+ *
+ * Campanhas referenciam uma entrada do registro global de oráculos em vez de uma
+ * chave solta. Este helper inicializa o registro (uma única vez por validador,
+ * com a carteira do provider como admin/upgrade authority) e registra oráculos.
+ * Campanhas só são financiadas com o oráculo caucionado no mint da campanha, por
+ * isso `bondOracle` trava `MIN_ORACLE_BOND` nesse mint antes da ativação.
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Solengage } from "../../target/types/solengage";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { getOrCreateAssociatedTokenAccount, mintTo, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+
+const BPF_UPGRADEABLE_LOADER_ID = new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111");
+
+/** Espelho de `MIN_ORACLE_BOND` (100 USDC). */
+export const MIN_ORACLE_BOND = 100 * 1_000_000;
+
+export function findOracleRegistryPda(program: Program<Solengage>): PublicKey {
+  return PublicKey.findProgramAddressSync([Buffer.from("oracle_registry")], program.programId)[0];
+}
+
+export function findOracleEntryPda(program: Program<Solengage>, id: anchor.BN): PublicKey {
+  return PublicKey.findProgramAddressSync([Buffer.from("oracle"), id.toArrayLike(Buffer, "le", 8)], program.programId)[0];
+}
+
+export async function ensureOracleRegistry(program: Program<Solengage>, provider: anchor.AnchorProvider): Promise<PublicKey> {
+  const oracleRegistry = findOracleRegistryPda(program);
+  const existing = await program.account.oracleRegistry.fetchNullable(oracleRegistry);
+  if (!existing) {
+    const [programData] = PublicKey.findProgramAddressSync([program.programId.toBuffer()], BPF_UPGRADEABLE_LOADER_ID);
+    await program.methods
+      .initializeOracleRegistry()
+      .accounts({ oracleRegistry, admin: provider.wallet.publicKey, program: program.programId, programData, systemProgram: SystemProgram.programId } as any)
+      .rpc();
+  }
+  return oracleRegistry;
+}
+
+/** Registra `authority` como oráculo e retorna o endereço da sua `OracleEntry`. */
+export async function registerOracle(program: Program<Solengage>, provider: anchor.AnchorProvider, authority: PublicKey): Promise<PublicKey> {
+  const oracleRegistry = await ensureOracleRegistry(program, provider);
+  const { oracleCount } = await program.account.oracleRegistry.fetch(oracleRegistry);
+  const oracleEntry = findOracleEntryPda(program, oracleCount);
+
+  await program.methods
+    .registerOracle(authority)
+    .accounts({ oracleRegistry, oracleEntry, admin: provider.wallet.publicKey, systemProgram: SystemProgram.programId } as any)
+    .rpc();
+
+  return oracleEntry;
+}
+
+export function findOracleBondPdas(program: Program<Solengage>, oracleEntry: PublicKey): { oracleBond: PublicKey; bondVault: PublicKey } {
+  const [oracleBond] = PublicKey.findProgramAddressSync([Buffer.from("oracle_bond"), oracleEntry.toBuffer()], program.programId);
+  const [bondVault] = PublicKey.findProgramAddressSync([Buffer.from("oracle_bond_vault"), oracleEntry.toBuffer()], program.programId);
+  return { oracleBond, bondVault };
+}
+
+/**
+ * Trava `amount` de `mint` na caução do oráculo (inicializando-a) e retorna seus endereços.
+ * `mintAuthority` emite os tokens para o oráculo e paga a conta de tokens dele.
+ */
+export async function bondOracle(
+  program: Program<Solengage>,
+  provider: anchor.AnchorProvider,
+  oracle: Keypair,
+  oracleEntry: PublicKey,
+  mint: PublicKey,
+  mintAuthority: Keypair,
+  amount: number = MIN_ORACLE_BOND
+): Promise<{ oracleBond: PublicKey; bondVault: PublicKey }> {
+  const { oracleBond, bondVault } = findOracleBondPdas(program, oracleEntry);
+  if ((await provider.connection.getBalance(oracle.publicKey)) < LAMPORTS_PER_SOL) {
+    await provider.connection.requestAirdrop(oracle.publicKey, LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig, "confirmed"));
+  }
+  const authorityTokenAccount = await getOrCreateAssociatedTokenAccount(provider.connection, mintAuthority, mint, oracle.publicKey).then(acc => acc.address);
+  await mintTo(provider.connection, mintAuthority, mint, authorityTokenAccount, mintAuthority, amount);
+
+  await program.methods
+    .initializeOracleBond()
+    .accounts({ oracle: oracleEntry, oracleBond, bondVault, mint, oracleAuthority: oracle.publicKey, tokenProgram: TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId } as any)
+    .signers([oracle])
+    .rpc();
+  await program.methods
+    .depositOracleBond(new anchor.BN(amount))
+    .accounts({ oracle: oracleEntry, oracleBond, bondVault, authorityTokenAccount, oracleAuthority: oracle.publicKey, tokenProgram: TOKEN_PROGRAM_ID } as any)
+    .signers([oracle])
+    .rpc();
+
+  return { oracleBond, bondVault };
+}
@@ -0,0 +1,16 @@
+/**
+ * Utilitários de teste - Sequência de Relatórios
+ *
+ * Todo relatório de métricas carrega um número de sequência estritamente crescente
+ * e o horário de observação. Um contador global atende qualquer campanha, e a
+ * observação fica um minuto no passado para tolerar diferenças de relógio do validador.
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+
+let sequence = 0;
+
+export function nextReport(): [anchor.BN, anchor.BN] {
+  sequence += 1;
+  return [new anchor.BN(sequence), new anchor.BN(Math.floor(Date.now() / 1000) - 60)];
+}
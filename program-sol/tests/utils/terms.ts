@@ -0,0 +1,14 @@
+/**
+ * Utilitários de teste - Versão dos Termos
+ *
+ * `brand_pay_campaign` recebe a versão dos termos revisada pela marca, que precisa
+ * coincidir com `campaign.terms_version`. Este helper lê a versão atual da campanha.
+ */
+
+import { Program } from "@coral-xyz/anchor";
+import { Solengage } from "../../target/types/solengage";
+import { PublicKey } from "@solana/web3.js";
+
+export async function termsVersion(program: Program<Solengage>, campaign: PublicKey): Promise<number> {
+  return (await program.account.campaign.fetch(campaign)).termsVersion;
+}